@@ -2,38 +2,86 @@ use super::*;
 
 pub struct Analyzer<'a> {
   document: &'a Document,
+  offline: bool,
 }
 
 impl<'a> Analyzer<'a> {
   #[must_use]
   pub fn analyze(&self) -> Vec<Diagnostic> {
-    let context = RuleContext::new(self.document);
+    self.profile().0
+  }
+
+  /// Returns whether `rule` is configured to run. Rules that are off, either
+  /// explicitly or by default, are skipped rather than run and filtered, since
+  /// opt-in rules may query the network.
+  fn enabled(&self, rule: &dyn Rule) -> bool {
+    self
+      .document
+      .config
+      .rule_config(rule.id())
+      .level()
+      .or(rule.default_level())
+      != Some(RuleLevel::Off)
+  }
+
+  #[must_use]
+  pub fn new(document: &'a Document) -> Self {
+    Self {
+      document,
+      offline: false,
+    }
+  }
+
+  /// Skips rules that query package indexes or fetch URLs.
+  #[must_use]
+  pub fn offline(self, offline: bool) -> Self {
+    Self { offline, ..self }
+  }
+
+  /// Analyzes the document like `analyze`, additionally returning how long
+  /// each enabled rule took to run, slowest first.
+  #[must_use]
+  pub fn profile(&self) -> (Vec<Diagnostic>, Vec<(&'static str, Duration)>) {
+    let context = RuleContext::new(self.document).offline(self.offline);
 
     let config = &self.document.config;
 
-    let mut diagnostics = inventory::iter::<&dyn Rule>
-      .into_iter()
-      .copied()
-      .par_bridge()
-      .flat_map(|rule| {
-        let rule_config = config.rule_config(rule.id());
+    let (diagnostics, mut timings): (Vec<Vec<Diagnostic>>, Vec<_>) =
+      inventory::iter::<&dyn Rule>
+        .into_iter()
+        .copied()
+        .filter(|rule| self.enabled(*rule))
+        .par_bridge()
+        .map(|rule| {
+          let rule_config = config.rule_config(rule.id());
 
-        rule
-          .run(&context)
-          .into_iter()
-          .filter_map(move |diagnostic| {
-            rule_config
-              .severity(diagnostic.severity, rule.default_level())
-              .map(|severity| Diagnostic {
-                display: rule.message().to_string(),
-                id: rule.id().to_string(),
-                severity,
-                ..diagnostic
-              })
-          })
-          .collect::<Vec<Diagnostic>>()
-      })
-      .collect::<Vec<_>>();
+          let start = Instant::now();
+
+          let diagnostics = rule.run(&context);
+
+          let elapsed = start.elapsed();
+
+          let diagnostics = diagnostics
+            .into_iter()
+            .filter_map(|diagnostic| {
+              rule_config
+                .severity(diagnostic.severity, rule.default_level())
+                .map(|severity| Diagnostic {
+                  display: rule.message().to_string(),
+                  id: rule.id().to_string(),
+                  severity,
+                  ..diagnostic
+                })
+            })
+            .collect::<Vec<Diagnostic>>();
+
+          (diagnostics, (rule.id(), elapsed))
+        })
+        .unzip();
+
+    let mut diagnostics = diagnostics.into_iter().flatten().collect();
+
+    Self::suppress(self.document, &mut diagnostics);
 
     diagnostics.sort_by(|a, b| {
       a.range
@@ -44,12 +92,64 @@ impl<'a> Analyzer<'a> {
         .then_with(|| a.message.cmp(&b.message))
     });
 
-    diagnostics
+    timings.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    (diagnostics, timings)
   }
 
-  #[must_use]
-  pub fn new(document: &'a Document) -> Self {
-    Self { document }
+  fn suppress(document: &Document, diagnostics: &mut Vec<Diagnostic>) {
+    let suppressions = Suppression::parse_all(document);
+
+    if suppressions.is_empty() {
+      return;
+    }
+
+    let severity = document.config.rule_config(UnusedIgnoreRule.id()).severity(
+      lsp::DiagnosticSeverity::INFORMATION,
+      UnusedIgnoreRule.default_level(),
+    );
+
+    let mut used = vec![HashSet::new(); suppressions.len()];
+
+    diagnostics.retain(|diagnostic| {
+      let mut suppressed = false;
+
+      for (suppression, used) in suppressions.iter().zip(used.iter_mut()) {
+        if suppression.matches(diagnostic) {
+          used.insert(diagnostic.id.clone());
+          suppressed = true;
+        }
+      }
+
+      !suppressed
+    });
+
+    let Some(severity) = severity else {
+      return;
+    };
+
+    for (suppression, used) in suppressions.iter().zip(used) {
+      let messages = if suppression.ids.is_empty() {
+        if used.is_empty() {
+          vec!["unused `pyproject: ignore` comment".to_string()]
+        } else {
+          Vec::new()
+        }
+      } else {
+        suppression
+          .ids
+          .iter()
+          .filter(|id| !used.contains(*id))
+          .map(|id| format!("unused `pyproject: ignore` for rule `{id}`"))
+          .collect()
+      };
+
+      diagnostics.extend(messages.into_iter().map(|message| Diagnostic {
+        display: UnusedIgnoreRule.message().to_string(),
+        id: UnusedIgnoreRule.id().to_string(),
+        ..Diagnostic::new(message, suppression.range, severity)
+      }));
+    }
   }
 }
 
@@ -70,6 +170,7 @@ mod tests {
   struct Test {
     document: Document,
     messages: Vec<(Message<'static>, lsp::DiagnosticSeverity)>,
+    offline: bool,
     tempdir: Option<TempDir>,
   }
 
@@ -97,16 +198,27 @@ mod tests {
       Self {
         document: Document::from(content),
         messages: Vec::new(),
+        offline: false,
         tempdir: None,
       }
     }
 
+    fn offline(self) -> Self {
+      Self {
+        offline: true,
+        ..self
+      }
+    }
+
     fn run(self) {
       let Test {
-        document, messages, ..
+        document,
+        messages,
+        offline,
+        ..
       } = self;
 
-      let analyzer = Analyzer::new(&document);
+      let analyzer = Analyzer::new(&document).offline(offline);
 
       let diagnostics = analyzer.analyze();
 
@@ -158,6 +270,7 @@ mod tests {
       Self {
         document: Document::from(params),
         messages: Vec::new(),
+        offline: false,
         tempdir: Some(tempdir),
       }
     }
@@ -190,6 +303,114 @@ mod tests {
       "#
     })
     .write_file("backend/build.py", "")
+    .warning(Message {
+      range: (1, 12, 1, 30),
+      text: "`build-system.requires` package name `Setuptools` should be normalized (use `setuptools`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_version_accepts_lower_bound() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools>=61", "wheel"]
+      build-backend = "setuptools.build_meta"
+
+      [tool.pyproject.rules]
+      build-system-backend-version = "warning"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_version_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools"]
+      build-backend = "setuptools.build_meta"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_version_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["wheel", "poetry-core<2"]
+      build-backend = "poetry.core.masonry.api"
+
+      [tool.pyproject.rules]
+      build-system-backend-version = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 21, 1, 36),
+      text: "`build-system.requires` entry `poetry-core` should specify a minimum version for build backend `poetry.core.masonry.api`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_package_accepts_matching_package() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["poetry-core>=2"]
+      build-backend = "poetry.core.masonry.api"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_package_must_be_required() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["poetry>=1.8"]
+      build-backend = "poetry.core.masonry.api"
+      "#
+    })
+    .warning(Message {
+      range: (2, 16, 2, 41),
+      text: "`build-system.build-backend` `poetry.core.masonry.api` is provided by `poetry-core`, which `build-system.requires` does not list (found `poetry`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_package_must_be_required_for_flit() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools"]
+      build-backend = "flit_core.buildapi"
+      "#
+    })
+    .warning(Message {
+      range: (2, 16, 2, 36),
+      text: "`build-system.build-backend` `flit_core.buildapi` is provided by `flit-core`, which `build-system.requires` does not list",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_backend_package_skips_in_tree_backends() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["wheel"]
+      build-backend = "setuptools.build_meta"
+      backend-path = ["backend"]
+      "#
+    })
+    .write_file("backend/setuptools/build_meta.py", "")
     .run();
   }
 
@@ -300,6 +521,22 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn build_system_build_backend_must_be_known_when_close() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["hatchling"]
+      build-backend = "hatchling.biuld"
+      "#
+    })
+    .warning(Message {
+      range: (2, 16, 2, 33),
+      text: "`build-system.build-backend` `hatchling.biuld` is not a known build backend (did you mean `hatchling.build`?)",
+    })
+    .run();
+  }
+
   #[test]
   fn build_system_build_backend_must_be_string() {
     Test::new(indoc! {
@@ -330,6 +567,62 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn build_system_packages_accepts_project_module() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["flit-core>=3.9"]
+      build-backend = "flit_core.buildapi"
+
+      [project]
+      name = "Demo-Tool"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      build-system-packages = "warning"
+      "#
+    })
+    .write_file("demo_tool.py", "")
+    .run();
+  }
+
+  #[test]
+  fn build_system_packages_is_opt_in() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["hatchling"]
+      build-backend = "hatchling.build"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_packages_warns_when_enabled() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["hatchling"]
+      build-backend = "hatchling.build"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      build-system-packages = "warning"
+      "#
+    })
+    .write_file("docs/index.md", "")
+    .warning(Message {
+      range: (0, 0, 0, 14),
+      text: "`[build-system]` is configured, but no package or module was found under the project root or `src/`; the built distribution may be empty",
+    })
+    .run();
+  }
+
   #[test]
   fn build_system_rejects_unknown_keys() {
     Test::new(indoc! {
@@ -380,6 +673,60 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn build_system_requires_must_not_be_empty_with_backend() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = []
+      build-backend = "setuptools.build_meta"
+      "#
+    })
+    .error(Message {
+      range: (2, 16, 2, 39),
+      text: "`build-system.requires` must not be empty when `build-system.build-backend` is set, since the backend cannot be installed",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_requires_package_names_should_be_normalized() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = [
+        "Flit_Core>=3.4",
+        "hatchling @ https://example.com/hatchling-1.0.tar.gz",
+        "tomli; python_version < '3.11'",
+      ]
+      "#
+    })
+    .warning(Message {
+      range: (2, 2, 2, 18),
+      text: "`build-system.requires` package name `Flit_Core` should be normalized (use `flit-core`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn build_system_requires_rule_is_configurable() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["Setuptools>=45"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      build-system-requires = "off"
+      "#
+    })
+    .run();
+  }
+
   #[test]
   fn build_system_requires_requires() {
     Test::new(indoc! {
@@ -395,6 +742,39 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn build_system_requires_setuptools_must_read_project_metadata() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools>=45", "wheel"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      "#
+    })
+    .warning(Message {
+      range: (1, 12, 1, 28),
+      text: "`build-system.requires` item `setuptools>=45` allows setuptools older than 61, which cannot read `[project]` metadata; require `setuptools>=61`",
+    })
+    .run();
+
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools~=68.0"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
   #[test]
   fn top_level_unknown_scalar_keys_are_rejected() {
     Test::new("requires = []")
@@ -463,6 +843,13 @@ mod tests {
       test = []
       "#
     })
+    .diagnostic(
+      Message {
+        range: (10, 0, 10, 4),
+        text: "`dependency-groups.test` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
@@ -485,6 +872,13 @@ mod tests {
       test = []
       "
     })
+    .diagnostic(
+      Message {
+        range: (1, 0, 1, 4),
+        text: "`dependency-groups.test` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
@@ -513,27 +907,47 @@ mod tests {
   }
 
   #[test]
-  fn dependency_groups_include_group_is_defined() {
+  fn dependency_groups_empty_groups_are_noted() {
     Test::new(indoc! {
       r#"
       [dependency-groups]
+      test = []
       lint = ["ruff"]
-      test = [{ include-group = "lint" }]
+      dev = [{ include-group = "lint" }]
       "#
     })
+    .diagnostic(
+      Message {
+        range: (1, 0, 1, 4),
+        text: "`dependency-groups.test` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
   #[test]
-  fn dependency_groups_include_group_must_be_only_key() {
+  fn dependency_groups_include_group_is_defined() {
     Test::new(indoc! {
       r#"
       [dependency-groups]
-      test = [{ include-group = "lint", extra = true }]
+      lint = ["ruff"]
+      test = [{ include-group = "lint" }]
       "#
     })
-    .error(Message {
-      range: (1, 26, 1, 32),
+    .run();
+  }
+
+  #[test]
+  fn dependency_groups_include_group_must_be_only_key() {
+    Test::new(indoc! {
+      r#"
+      [dependency-groups]
+      test = [{ include-group = "lint", extra = true }]
+      "#
+    })
+    .error(Message {
+      range: (1, 26, 1, 32),
       text: "`include-group` objects must contain only the `include-group` key",
     })
     .run();
@@ -581,6 +995,106 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn dependency_groups_overlap_allows_distinct_packages() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      test = ["pytest", "tool @ git+https://github.com/example/tool.git"]
+
+      [dependency-groups]
+      test = ["coverage", "tool @ git+https://github.com/example/tool.git"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn dependency_groups_overlap_warns_on_shared_package() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      test = ["pytest"]
+
+      [dependency-groups]
+      test = ["pytest"]
+      "#
+    })
+    .warning(Message {
+      range: (8, 8, 8, 16),
+      text: "`pytest` appears in both `project.optional-dependencies.test` and `dependency-groups.test`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn dependency_groups_overlap_skips_summarized_extras() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      test = ["pytest", "coverage"]
+
+      [dependency-groups]
+      test = ["pytest", "coverage"]
+      lint = ["coverage"]
+
+      [tool.pyproject.rules]
+      project-optional-dependencies-groups-overlap = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (5, 0, 5, 4),
+        text: "`project.optional-dependencies.test` shares 2 of its 2 packages with dependency group `test`; consider keeping a single list to avoid drift",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .warning(Message {
+      range: (9, 8, 9, 18),
+      text: "`coverage` appears in both `project.optional-dependencies.test` and `dependency-groups.lint`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn dependency_groups_overlap_warns_across_group_names() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      test = ["pytest>=8", "coverage"]
+
+      [dependency-groups]
+      test = ["PyTest>=7"]
+      dev = ["ruff", "coverage[toml]"]
+      "#
+    })
+    .warning(Message {
+      range: (8, 8, 8, 19),
+      text: "`pytest` appears in both `project.optional-dependencies.test` and `dependency-groups.test`",
+    })
+    .warning(Message {
+      range: (9, 15, 9, 31),
+      text: "`coverage` appears in both `project.optional-dependencies.test` and `dependency-groups.dev`",
+    })
+    .run();
+  }
+
   #[test]
   fn dependency_groups_must_be_a_table() {
     Test::new("dependency-groups = []")
@@ -624,14 +1138,42 @@ mod tests {
       "foo-" = []
       "#
     })
+    .diagnostic(
+      Message {
+        range: (1, 0, 1, 14),
+        text: "`dependency-groups.valid.name_1` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (2, 0, 2, 6),
+        text: "`dependency-groups.-foo` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .error(Message {
       range: (2, 0, 2, 6),
       text: "`dependency-groups` group name `-foo` must be a valid non-normalized name",
     })
+    .diagnostic(
+      Message {
+        range: (3, 0, 3, 6),
+        text: "`dependency-groups.foo!` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .error(Message {
       range: (3, 0, 3, 6),
       text: "`dependency-groups` group name `foo!` must be a valid non-normalized name",
     })
+    .diagnostic(
+      Message {
+        range: (4, 0, 4, 6),
+        text: "`dependency-groups.foo-` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .error(Message {
       range: (4, 0, 4, 6),
       text: "`dependency-groups` group name `foo-` must be a valid non-normalized name",
@@ -648,6 +1190,20 @@ mod tests {
       foo_bar = []
       "
     })
+    .diagnostic(
+      Message {
+        range: (1, 0, 1, 7),
+        text: "`dependency-groups.foo-bar` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (2, 0, 2, 7),
+        text: "`dependency-groups.foo_bar` is empty; add requirements or an `include-group`, or remove the group",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .error(Message {
       range: (2, 0, 2, 7),
       text: "`dependency-groups` contains duplicate group names after normalization: `foo-bar` and `foo_bar`",
@@ -693,6 +1249,28 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn dependency_group_items_must_not_be_nested_arrays_or_tables() {
+    Test::new(indoc! {
+      r#"
+      [dependency-groups]
+      test = [["pytest"]]
+
+      [[dependency-groups.docs]]
+      include-group = "test"
+      "#
+    })
+    .error(Message {
+      range: (1, 8, 1, 18),
+      text: "`dependency-groups.test[0]` must be a PEP 508 dependency string or an `include-group` object",
+    })
+    .error(Message {
+      range: (3, 0, 3, 26),
+      text: "`dependency-groups.docs[0]` must be a PEP 508 dependency string or an `include-group` object",
+    })
+    .run();
+  }
+
   #[test]
   fn dependency_group_include_objects_must_have_the_include_group_key() {
     Test::new(indoc! {
@@ -722,7 +1300,20 @@ mod tests {
     })
     .error(Message {
       range: (1, 26, 1, 32),
-      text: "cyclic dependency group include: test -> test",
+      text: "dependency group `test` must not include itself",
+    })
+    .run();
+
+    Test::new(indoc! {
+      r#"
+      [dependency-groups]
+      a = [{ include-group = "b" }]
+      b = [{ include-group = "a" }]
+      "#
+    })
+    .error(Message {
+      range: (2, 23, 2, 26),
+      text: "cyclic dependency group include: a -> b -> a",
     })
     .run();
 
@@ -754,6 +1345,20 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn dependency_groups_allow_shared_includes() {
+    Test::new(indoc! {
+      r#"
+      [dependency-groups]
+      base = ["foo"]
+      lint = [{ include-group = "base" }]
+      test = [{ include-group = "base" }]
+      dev = [{ include-group = "lint" }, { include-group = "test" }]
+      "#
+    })
+    .run();
+  }
+
   #[test]
   fn dependency_groups_report_independent_errors() {
     Test::new(indoc! {
@@ -790,6 +1395,62 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn dependency_url_hash_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dependencies = ["pkg @ https://example.com/pkg-1.0.tar.gz"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn dependency_url_hash_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [build-system]
+      requires = ["backend @ https://example.com/backend-1.0.tar.gz"]
+      build-backend = "backend"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dependencies = [
+        "pkg @ https://example.com/pkg-1.0.tar.gz",
+        "pinned @ https://example.com/pinned-1.0.tar.gz#sha256=0123456789abcdef",
+        "vcs @ git+https://github.com/example/vcs@0123456789abcdef0123456789abcdef01234567",
+        "requests>=2",
+      ]
+
+      [tool.pyproject.rules]
+      dependency-url-hash = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 12, 1, 62),
+      text: "`build-system.requires` entry `backend` is installed from a URL without a hash; add a `#sha256=` fragment or depend on a release from an index instead",
+    })
+    .warning(Message {
+      range: (8, 2, 8, 44),
+      text: "`project.dependencies` entry `pkg` is installed from a URL without a hash; add a `#sha256=` fragment or depend on a release from an index instead",
+    })
+    .run();
+  }
+
+  #[test]
+  fn document_bom_is_reported() {
+    Test::new("\u{feff}[project]\nname = \"demo\"\nversion = \"1.0.0\"\n")
+      .warning(Message {
+        range: (0, 0, 0, 0),
+        text: "remove the UTF-8 byte order mark from the start of the file",
+      })
+      .run();
+  }
+
   #[test]
   fn flit_urls_entries_must_be_valid_urls() {
     Test::new(indoc! {
@@ -884,6 +1545,54 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn profile_runs_opt_in_rules_when_enabled() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-dependency-extras = "warning"
+      "#
+    });
+
+    let (_, timings) = Analyzer::new(&document).profile();
+
+    assert!(
+      timings
+        .iter()
+        .any(|(id, _)| *id == "project-dependency-extras")
+    );
+  }
+
+  #[test]
+  fn profile_times_each_enabled_rule() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name = "off"
+      "#
+    });
+
+    let (diagnostics, timings) = Analyzer::new(&document).profile();
+
+    assert!(diagnostics.is_empty());
+
+    let ids = timings.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+
+    assert!(ids.contains(&"project-version"));
+    assert!(!ids.contains(&"project-name"));
+    assert!(!ids.contains(&"project-dependency-extras"));
+
+    assert!(timings.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+  }
+
   #[test]
   fn project_authors_email_must_be_valid_address() {
     Test::new(indoc! {
@@ -1077,1909 +1786,3916 @@ mod tests {
   }
 
   #[test]
-  fn project_dependencies_items_must_be_strings() {
+  fn project_classifiers_python_accepts_consistent_classifiers() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = [1]
+      requires-python = ">=3.11"
+      classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3 :: Only",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 17),
-      text: "`project.dependencies` items must be strings",
-    })
     .run();
   }
 
   #[test]
-  fn project_dependencies_must_be_array_of_strings() {
+  fn project_classifiers_python_minor_versions_require_major_version() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = "requests"
+      classifiers = [
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 15, 3, 25),
-      text: "`project.dependencies` must be an array of PEP 508 strings",
+    .warning(Message {
+      range: (4, 2, 4, 42),
+      text: "`Programming Language :: Python :: 3.12` is listed without `Programming Language :: Python :: 3`",
     })
     .run();
   }
 
   #[test]
-  fn project_dependencies_rejects_invalid_specifier() {
+  fn project_classifiers_python_must_agree_with_each_other() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["requests >= "]
+      classifiers = [
+        "Programming Language :: Python :: 2.7",
+        "Programming Language :: Python :: 3 :: Only",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 30),
-      text: "`project.dependencies` item `requests >= ` is not a valid PEP 508 dependency: unexpected end of version specifier, expected version",
+    .warning(Message {
+      range: (4, 2, 4, 41),
+      text: "`Programming Language :: Python :: 2.7` contradicts `Programming Language :: Python :: 3 :: Only`",
     })
     .run();
   }
 
   #[test]
-  fn project_dependencies_require_normalized_names() {
+  fn project_classifiers_python_must_agree_with_requires_python() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["Requests>=1.0"]
+      requires-python = ">=3.8.1"
+      classifiers = [
+        "Programming Language :: Python :: 2",
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.7",
+        "Programming Language :: Python :: 3.8",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 31),
-      text: "`project.dependencies` package name `Requests` must be normalized (use `requests`)",
+    .warning(Message {
+      range: (3, 18, 3, 27),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .warning(Message {
+      range: (5, 2, 5, 39),
+      text: "`Programming Language :: Python :: 2` contradicts `project.requires-python` `>=3.8.1`, which excludes Python 2",
+    })
+    .warning(Message {
+      range: (7, 2, 7, 41),
+      text: "`Programming Language :: Python :: 3.7` contradicts `project.requires-python` `>=3.8.1`, which excludes Python 3.7",
     })
     .run();
   }
 
   #[test]
-  fn project_dependencies_version_bounds_opt_in() {
+  fn project_classifiers_python_skips_dynamic_requires_python() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["requests>=1.0"]
+      dynamic = ["requires-python"]
+      classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.8",
+      ]
       "#
     })
     .run();
   }
 
   #[test]
-  fn project_dependencies_warn_on_insecure_and_unbounded() {
+  fn project_classifiers_order_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["pycrypto"]
+      classifiers = [
+        "Programming Language :: Python",
+        "Development Status :: 4 - Beta",
+      ]
       "#
     })
-    .warning(Message {
-      range: (3, 16, 3, 26),
-      text: "`project.dependencies` includes deprecated/insecure package `pycrypto`: package is unmaintained and insecure; consider `pycryptodome`",
-    })
     .run();
   }
 
   #[test]
-  fn project_dependencies_warn_on_insecure_and_unbounded_when_enabled() {
+  fn project_classifiers_order_warns_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["pycrypto"]
+      classifiers = [
+        "Programming Language :: Python",
+        "Development Status :: 4 - Beta",
+        "Programming Language :: Python :: 3",
+      ]
 
       [tool.pyproject.rules]
-      project-dependencies-version-bounds = "warning"
+      project-classifiers-order = "warning"
       "#
     })
     .warning(Message {
-      range: (3, 16, 3, 26),
-      text: "`project.dependencies` entry `pycrypto` does not pin a version; add a version range with an upper bound to avoid future breaking changes",
-    })
-    .warning(Message {
-      range: (3, 16, 3, 26),
-      text: "`project.dependencies` includes deprecated/insecure package `pycrypto`: package is unmaintained and insecure; consider `pycryptodome`",
+      range: (3, 14, 7, 1),
+      text: "`project.classifiers` should be grouped by category in PyPI order (`Development Status`, `Environment`, `Framework`, `Intended Audience`, `License`, `Natural Language`, `Operating System`, `Programming Language`, `Topic`, `Typing`)",
     })
     .run();
   }
 
   #[test]
-  fn project_dependencies_warn_without_upper_bound_when_enabled() {
+  fn project_dependencies_count_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dependencies = ["requests>=1.0"]
+      dependencies = ["a", "b", "c"]
 
-      [tool.pyproject.rules]
-      project-dependencies-version-bounds = "warning"
+      [tool.pyproject.rules.project-dependencies-count]
+      options = { max = 2 }
       "#
     })
-    .warning(Message {
-      range: (3, 16, 3, 31),
-      text: "`project.dependencies` entry `requests` does not specify an upper version bound; consider adding an upper constraint to avoid future breaking changes",
-    })
     .run();
   }
 
   #[test]
-  fn project_description_must_be_a_string() {
+  fn project_dependencies_count_warns_above_max() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      description = ["not a string"]
+      dependencies = ["a", "b", "c"]
+
+      [tool.pyproject.rules.project-dependencies-count]
+      level = "warning"
+      options = { max = 2 }
       "#
     })
-    .error(Message {
-      range: (3, 14, 3, 30),
-      text: "`project.description` must be a string",
+    .warning(Message {
+      range: (3, 0, 3, 12),
+      text: "`project.dependencies` declares 3 dependencies, more than the maximum of 2; consider moving some to `project.optional-dependencies` or `dependency-groups`",
     })
     .run();
   }
 
   #[test]
-  fn project_dynamic_allows_current_project_fields() {
+  fn project_dependencies_dev_tools_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = [
-        "classifiers",
-        "import-names",
-        "import-namespaces",
-        "license-files",
-        "requires-python",
-      ]
+      dependencies = ["pytest>=8"]
       "#
     })
     .run();
   }
 
   #[test]
-  fn project_dynamic_items_must_be_strings() {
+  fn project_dependencies_dev_tools_notes_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = [1]
+      dependencies = ["requests>=2", "pre-commit>=3", "ruff"]
+
+      [tool.pyproject.rules]
+      project-dependencies-dev-tools = "info"
       "#
     })
-    .error(Message {
-      range: (3, 11, 3, 12),
-      text: "`project.dynamic` items must be strings",
-    })
+    .diagnostic(
+      Message {
+        range: (3, 31, 3, 46),
+        text: "`project.dependencies` includes development tool `pre-commit`; consider moving it to `[dependency-groups]` or `[project.optional-dependencies]`",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (3, 48, 3, 54),
+        text: "`project.dependencies` includes development tool `ruff`; consider moving it to `[dependency-groups]` or `[project.optional-dependencies]`",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
   #[test]
-  fn project_dynamic_must_be_array_of_strings() {
+  fn project_dependencies_direct_references_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = "version"
+      dependencies = ["demo-utils @ https://example.com/demo_utils-1.0.tar.gz"]
       "#
     })
-    .error(Message {
-      range: (3, 10, 3, 19),
-      text: "`project.dynamic` must be an array of strings",
-    })
     .run();
   }
 
   #[test]
-  fn project_dynamic_must_not_conflict_with_static_values() {
+  fn project_dependencies_direct_references_warn_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = ["version", "description"]
-      description = "demo package"
+      dependencies = [
+        "requests==2.32.3",
+        "lint @ git+https://github.com/example/lint.git",
+        "local @ file:///opt/local",
+        "archive @ https://example.com/archive-1.0.tar.gz",
+      ]
+
+      [tool.pyproject.rules]
+      project-dependencies-direct-references = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 11, 3, 20),
-      text: "`project.dynamic` field `version` must not also be provided statically",
+    .warning(Message {
+      range: (5, 2, 5, 50),
+      text: "`project.dependencies` entry `lint` is a direct URL reference, which cannot be uploaded to PyPI",
     })
-    .error(Message {
-      range: (3, 22, 3, 35),
-      text: "`project.dynamic` field `description` must not also be provided statically",
+    .warning(Message {
+      range: (6, 2, 6, 29),
+      text: "`project.dependencies` entry `local` is a direct URL reference, which cannot be uploaded to PyPI",
+    })
+    .warning(Message {
+      range: (7, 2, 7, 52),
+      text: "`project.dependencies` entry `archive` is a direct URL reference, which cannot be uploaded to PyPI",
     })
     .run();
   }
 
   #[test]
-  fn project_dynamic_must_not_duplicate_fields() {
+  fn project_dependencies_duplicates_allow_different_markers() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      dynamic = ["version", "version"]
+      version = "1.0.0"
+      dependencies = [
+        "numpy>=1.26; python_version >= '3.12'",
+        "numpy>=1.21; python_version < '3.12'",
+      ]
       "#
     })
-    .error(Message {
-      range: (2, 22, 2, 31),
-      text: "`project.dynamic` contains duplicate field `version`",
-    })
     .run();
   }
 
   #[test]
-  fn project_dynamic_must_not_include_name() {
+  fn project_dependencies_duplicates_must_be_combined() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = ["name"]
+      dependencies = [
+        "requests>=2.0",
+        "Requests[security]<3",
+        "typing_extensions",
+        "typing-extensions>=4",
+      ]
       "#
     })
+    .warning(Message {
+      range: (5, 2, 5, 24),
+      text: "`project.dependencies` lists `requests` more than once; combine the constraints into a single entry",
+    })
     .error(Message {
-      range: (3, 11, 3, 17),
-      text: "`project.dynamic` must not include `name`",
+      range: (5, 2, 5, 24),
+      text: "`project.dependencies` package name `Requests` must be normalized (use `requests`)",
+    })
+    .error(Message {
+      range: (6, 2, 6, 21),
+      text: "`project.dependencies` package name `typing_extensions` must be normalized (use `typing-extensions`)",
+    })
+    .warning(Message {
+      range: (7, 2, 7, 24),
+      text: "`project.dependencies` lists `typing-extensions` more than once; combine the constraints into a single entry",
     })
     .run();
   }
 
   #[test]
-  fn project_dynamic_rejects_unsupported_fields() {
+  fn project_dependencies_items_must_be_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      dynamic = ["version", "foo"]
+      version = "1.0.0"
+      dependencies = [1]
       "#
     })
     .error(Message {
-      range: (2, 22, 2, 27),
-      text: "`project.dynamic` contains unsupported field `foo`",
+      range: (3, 16, 3, 17),
+      text: "`project.dependencies` items must be strings",
     })
     .run();
   }
 
   #[test]
-  fn project_entry_point_names_must_not_have_invalid_characters() {
+  fn project_dependencies_marker_quotes_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-
-      [project.scripts]
-      " bad" = "demo:main"
+      dependencies = [
+        "colorama; sys_platform == 'win32'",
+        'uvloop; sys_platform != "win32"',
+      ]
       "#
     })
-    .error(Message {
-      range: (5, 0, 5, 6),
-      text: "`project.scripts. bad` name must not start or end with whitespace",
-    })
     .run();
   }
 
   #[test]
-  fn project_entry_point_values_must_reference_importable_objects() {
+  fn project_dependencies_marker_quotes_warns_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
+      dependencies = [
+        "colorama; sys_platform == 'win32'",
+        'uvloop; sys_platform != "win32"',
+        "tomli; python_version < '3.11'",
+      ]
 
-      [project.scripts]
-      cli = "1demo:main"
+      [project.optional-dependencies]
+      test = ["pytest; python_version >= '3.9'"]
+
+      [tool.pyproject.rules]
+      project-dependencies-marker-quotes = "warning"
       "#
     })
-    .error(Message {
-      range: (5, 6, 5, 18),
-      text: "`project.scripts.cli` must reference an importable module path (e.g. `package.module`) optionally followed by `:qualname`",
+    .warning(Message {
+      range: (4, 2, 4, 37),
+      text: "`project.dependencies` environment marker uses single quotes while other entries use double quotes; prefer double quotes consistently",
+    })
+    .warning(Message {
+      range: (6, 2, 6, 34),
+      text: "`project.dependencies` environment marker uses single quotes while other entries use double quotes; prefer double quotes consistently",
     })
     .run();
   }
 
   #[test]
-  fn project_entry_points_group_names_must_match_pattern() {
+  fn project_dependencies_must_be_array_of_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-
-      [project.entry-points."bad group"]
-      cli = "demo:main"
+      dependencies = "requests"
       "#
     })
     .error(Message {
-      range: (4, 22, 4, 33),
-      text: "`project.entry-points` group names must match `^\\w+(\\.\\w+)*$`",
+      range: (3, 15, 3, 25),
+      text: "`project.dependencies` must be an array of PEP 508 strings",
     })
     .run();
   }
 
   #[test]
-  fn project_entry_points_rejects_console_scripts_group() {
+  fn project_dependencies_minimum_python_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-
-      [project.entry-points.console_scripts]
-      cli = "demo:main"
+      requires-python = ">=3.8"
+      dependencies = ["numpy>=2.0"]
       "#
     })
-    .error(Message {
-      range: (4, 22, 4, 37),
-      text: "`project.entry-points.console_scripts` is not allowed; use `[project.scripts]` instead",
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
     })
     .run();
   }
 
   #[test]
-  fn project_entry_points_rejects_nested_entry_point_tables() {
+  fn project_dependencies_prerelease_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-
-      [project.entry-points.my_group]
-      nested.table = "demo:main"
+      dependencies = ["requests>=2.0a1"]
       "#
     })
-    .error(Message {
-      range: (5, 0, 5, 6),
-      text: "`project.entry-points.my_group.nested` must be a string object reference; entry point groups cannot be nested",
-    })
     .run();
   }
 
   #[test]
-  fn project_entry_points_requires_table() {
+  fn project_dependencies_prerelease_skips_prerelease_projects() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      version = "1.0.0"
-      entry-points = "demo:main"
+      version = "1.0.0b1"
+      dependencies = ["requests>=2.0a1"]
+
+      [tool.pyproject.rules]
+      project-dependencies-prerelease = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 15, 3, 26),
-      text: "`project.entry-points` must be a table of entry point groups",
-    })
     .run();
   }
 
   #[test]
-  fn project_import_names_detects_duplicates_across_fields() {
+  fn project_dependencies_prerelease_warns_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["demo"]
-      import-namespaces = ["demo; private"]
+      dependencies = [
+        "requests>=2.0a1",
+        "pkg==2.0rc1",
+        "httpx>=0.27,!=0.28b1",
+        "rich<14.0.dev0",
+      ]
+
+      [tool.pyproject.rules]
+      project-dependencies-prerelease = "warning"
       "#
     })
-    .error(Message {
-      range: (4, 21, 4, 36),
-      text: "duplicated names are not allowed in `project.import-names`/`project.import-namespaces` (found `demo`)",
+    .warning(Message {
+      range: (4, 2, 4, 19),
+      text: "`project.dependencies` entry `requests` requires pre-release `2.0a1`; installers skip pre-releases by default, so confirm this is intentional",
+    })
+    .warning(Message {
+      range: (5, 2, 5, 15),
+      text: "`project.dependencies` entry `pkg` requires pre-release `2.0rc1`; installers skip pre-releases by default, so confirm this is intentional",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_items_must_be_strings() {
+  fn project_dependencies_python_markers_must_be_satisfiable() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = [1]
+      requires-python = ">=3.8"
+      dependencies = [
+        "importlib-metadata; python_version < '3.8'",
+        "tomli; python_version < '3.11'",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 17),
-      text: "`project.import-names` items must be strings",
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .warning(Message {
+      range: (5, 2, 5, 46),
+      text: "`project.dependencies` entry `importlib-metadata` has marker `python_version < '3.8'` that can never be satisfied under `project.requires-python` (`>=3.8`)",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_must_be_array_of_strings() {
+  fn project_dependencies_python_markers_handle_arbitrary_equality() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = "demo"
+      requires-python = "===3.12.1"
+      dependencies = [
+        "tomli; python_full_version != '3.12.1'",
+        "typing-extensions; python_version >= '3.12'",
+      ]
       "#
     })
-    .error(Message {
-      range: (3, 15, 3, 21),
-      text: "`project.import-names` must be an array of strings",
+    .warning(Message {
+      range: (5, 2, 5, 42),
+      text: "`project.dependencies` entry `tomli` has marker `python_full_version != '3.12.1'` that can never be satisfied under `project.requires-python` (`===3.12.1`)",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_require_parent_namespaces() {
+  fn project_dependencies_rejects_invalid_specifier() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["demo.core.utils"]
+      dependencies = ["requests >= "]
       "#
     })
     .error(Message {
-      range: (3, 16, 3, 33),
-      text: "`demo.core.utils` is missing parent namespace `demo`; all parents must be listed in `project.import-names`/`project.import-namespaces`",
+      range: (3, 16, 3, 30),
+      text: "`project.dependencies` item `requests >= ` is not a valid PEP 508 dependency: unexpected end of version specifier, expected version",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_accepts_valid_names() {
+  fn project_dependencies_require_normalized_names() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo", "foo.bar", "_foo", "\u00e9", "x", "private_a;private", "private_b; private", "private_c ;private", "private_d \t;\tprivate"]
-      import-namespaces = ["namespace", "namespace.child"]
+      dependencies = ["Requests>=1.0"]
       "#
     })
+    .error(Message {
+      range: (3, 16, 3, 31),
+      text: "`project.dependencies` package name `Requests` must be normalized (use `requests`)",
+    })
     .run();
   }
 
   #[test]
-  fn project_import_names_allows_empty_names() {
+  fn project_dependencies_requires_python_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = [""]
+      requires-python = ">=3.8"
+      dependencies = ["requests"]
       "#
     })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
     .run();
   }
 
   #[test]
-  fn project_import_names_allows_empty_arrays() {
+  fn project_dependencies_version_bounds_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = []
+      dependencies = ["requests>=1.0"]
       "#
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_detects_duplicates_with_private_suffixes() {
+  fn project_dependencies_warn_on_insecure_and_unbounded() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo", "foo; private"]
+      dependencies = ["pycrypto"]
       "#
     })
-    .error(Message {
-      range: (3, 23, 3, 37),
-      text: "duplicated names are not allowed in `project.import-names`/`project.import-namespaces` (found `foo`)",
+    .warning(Message {
+      range: (3, 16, 3, 26),
+      text: "`project.dependencies` includes deprecated/insecure package `pycrypto`: package is unmaintained and insecure; consider `pycryptodome`",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_rejects_invalid_identifiers() {
+  fn project_dependencies_warn_on_insecure_and_unbounded_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo..bar"]
+      dependencies = ["pycrypto"]
+
+      [tool.pyproject.rules]
+      project-dependencies-version-bounds = "warning"
       "#
     })
-    .error(Message {
+    .warning(Message {
       range: (3, 16, 3, 26),
-      text: "`project.import-names` item `foo..bar` must be a valid dotted Python identifier",
+      text: "`project.dependencies` entry `pycrypto` does not pin a version; add a version range with an upper bound to avoid future breaking changes",
+    })
+    .warning(Message {
+      range: (3, 16, 3, 26),
+      text: "`project.dependencies` includes deprecated/insecure package `pycrypto`: package is unmaintained and insecure; consider `pycryptodome`",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_rejects_keywords() {
+  fn project_dependencies_warn_without_upper_bound_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo.class"]
+      dependencies = ["requests>=1.0"]
+
+      [tool.pyproject.rules]
+      project-dependencies-version-bounds = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 27),
-      text: "`project.import-names` item `foo.class` contains Python keyword `class`",
+    .warning(Message {
+      range: (3, 16, 3, 31),
+      text: "`project.dependencies` entry `requests` does not specify an upper version bound; consider adding an upper constraint to avoid future breaking changes",
     })
     .run();
   }
 
   #[test]
-  fn project_import_names_rejects_invalid_suffixes() {
+  fn project_dependencies_yanked_allows_non_yanked_releases() {
+    PyPiClient::shared().set_mock_latest_version("yanked-allowed", "2.0.0");
+    PyPiClient::shared().set_mock_yanked("yanked-allowed", "1.0.0", None);
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo; public"]
+      dependencies = ["yanked-allowed>=1"]
       "#
     })
-    .error(Message {
-      range: (3, 16, 3, 29),
-      text: "`project.import-names` item `foo; public` has an invalid suffix; only `; private` is allowed",
-    })
     .run();
   }
 
   #[test]
-  fn project_import_names_accepts_present_parent_namespaces() {
+  fn project_dependencies_yanked_warns_on_pinned_release() {
+    PyPiClient::shared().set_mock_latest_version("yanked-pin", "2.0.0");
+    PyPiClient::shared().set_mock_yanked(
+      "yanked-pin",
+      "1.0.0",
+      Some("security"),
+    );
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-names = ["foo.bar"]
-      import-namespaces = ["foo; private"]
+      dependencies = ["yanked-pin==1.0.0"]
+
+      [tool.pyproject.rules]
+      project-dependency-updates = "off"
       "#
     })
+    .warning(Message {
+      range: (3, 16, 3, 35),
+      text: "`project.dependencies` entry `yanked-pin` pins yanked release `1.0.0` (reason: security); pick a non-yanked version",
+    })
     .run();
   }
 
   #[test]
-  fn project_import_namespaces_rejects_empty_arrays() {
+  fn project_dependencies_yanked_warns_when_only_yanked_releases_match() {
+    PyPiClient::shared().set_mock_latest_version("yanked-range", "1.0.0");
+    PyPiClient::shared().set_mock_latest_version("yanked-range", "2.0.0");
+    PyPiClient::shared().set_mock_yanked("yanked-range", "1.1.0", None);
+    PyPiClient::shared().set_mock_yanked("yanked-range", "1.2.0", None);
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-namespaces = []
-      "#
+      dependencies = ["yanked-range>=1.1,<2"]
+
+      [tool.pyproject.rules]
+      project-dependency-updates = "off"
+      "#
     })
-    .error(Message {
-      range: (3, 20, 3, 22),
-      text: "`project.import-namespaces` must not be an empty array",
+    .warning(Message {
+      range: (3, 16, 3, 38),
+      text: "`project.dependencies` entry `yanked-range` only allows yanked release `1.2.0`; pick a non-yanked version",
     })
     .run();
   }
 
   #[test]
-  fn project_import_namespaces_rejects_empty_names() {
+  fn project_dependency_extras_checks_pinned_release() {
+    PyPiClient::shared().set_mock_extras("extras-mock", None, &["sqs"]);
+
+    PyPiClient::shared().set_mock_extras(
+      "extras-mock",
+      Some("4.0.0"),
+      &["redis"],
+    );
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      import-namespaces = [""]
+      dependencies = ["extras-mock[reddis]==4.0.0"]
+      optional-dependencies = { queue = ["extras-mock[sqs]>=4"] }
+
+      [tool.pyproject.rules]
+      project-dependency-extras = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 21, 3, 23),
-      text: "`project.import-namespaces` item `` must be a valid dotted Python identifier",
+    .warning(Message {
+      range: (3, 16, 3, 44),
+      text: "`project.dependencies` entry `extras-mock` requests extra `reddis`, which the package does not provide (did you mean `redis`?)",
     })
     .run();
   }
 
   #[test]
-  fn project_keywords_items_must_be_strings() {
+  fn project_dependency_extras_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      keywords = [1, "two"]
+      dependencies = ["celery[reddis]>=5"]
       "#
     })
-    .error(Message {
-      range: (3, 12, 3, 13),
-      text: "`project.keywords` items must be strings",
-    })
     .run();
   }
 
   #[test]
-  fn project_keywords_must_be_an_array_of_strings() {
+  fn project_dependency_updates_skips_offline() {
+    PyPiClient::shared().set_mock_latest_version("offline-mock", "2.0.0");
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      keywords = "invalid"
+      dependencies = ["offline-mock>=1,<2"]
       "#
     })
-    .error(Message {
-      range: (3, 11, 3, 20),
-      text: "`project.keywords` must be an array of strings",
-    })
+    .offline()
     .run();
   }
 
   #[test]
-  fn project_keywords_must_not_contain_duplicates() {
+  fn project_dependency_updates_warns_on_excluded_latest_release() {
+    PyPiClient::shared().set_mock_latest_version("updates-mock", "2.0.0");
+
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      keywords = ["one", "two", "one"]
+      dependencies = ["updates-mock>=1,<2"]
       "#
     })
-    .error(Message {
-      range: (3, 26, 3, 31),
-      text: "`project.keywords` contains duplicate keyword `one`",
+    .warning(Message {
+      range: (3, 16, 3, 36),
+      text: "`project.dependencies` entry `updates-mock` excludes the latest release `2.0.0` (current constraint: `>=1, <2`)",
     })
     .run();
   }
 
   #[test]
-  fn project_license_classifiers_forbidden_when_license_set() {
+  fn project_description_must_be_a_string() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      classifiers = [
-        "License :: OSI Approved :: MIT License",
-        "Programming Language :: Python",
-      ]
+      description = ["not a string"]
       "#
     })
     .error(Message {
-      range: (4, 14, 7, 1),
-      text: "`project.classifiers` must not include license classifiers when `project.license` is set",
-    })
-    .warning(Message {
-      range: (5, 2, 5, 42),
-      text: "`project.classifiers` license classifiers are deprecated when `project.license` is present (use only `project.license`)",
+      range: (3, 14, 3, 30),
+      text: "`project.description` must be a string",
     })
     .run();
   }
 
   #[test]
-  fn project_license_classifiers_warn_without_license() {
+  fn project_dynamic_allows_current_project_fields() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      classifiers = ["License :: OSI Approved :: MIT License"]
+      dynamic = [
+        "classifiers",
+        "import-names",
+        "import-namespaces",
+        "license-files",
+        "requires-python",
+      ]
       "#
     })
-    .warning(Message {
-      range: (3, 15, 3, 55),
-      text: "`project.classifiers` license classifiers are deprecated; use `project.license` instead",
-    })
     .run();
   }
 
   #[test]
-  fn project_license_files_accepts_nested_license_path() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_backend_must_compute_fields() {
+    Test::new(indoc! {
       r#"
+      [build-system]
+      requires = ["flit-core>=3.4"]
+      build-backend = "flit_core.buildapi"
+
       [project]
       name = "demo"
-      version = "1.0.0"
-      license = "MIT"
-      license-files = ["licenses/LICENSE"]
+      dynamic = ["version", "classifiers"]
       "#
     })
-    .write_file("licenses/LICENSE", "MIT")
+    .warning(Message {
+      range: (6, 22, 6, 35),
+      text: "`project.dynamic` field `classifiers` cannot be computed by build backend `flit_core.buildapi` (`flit-core` supports only `description`, `version`)",
+    })
     .run();
   }
 
   #[test]
-  fn project_license_files_accepts_valid_match() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_backend_rejects_fields_without_dynamic_support() {
+    Test::new(indoc! {
       r#"
+      [build-system]
+      requires = ["uv-build>=0.8"]
+      build-backend = "uv_build"
+
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = ["LICENSE"]
+      dynamic = ["readme"]
       "#
     })
-    .write_file("LICENSE", "MIT")
+    .warning(Message {
+      range: (7, 11, 7, 19),
+      text: "`project.dynamic` field `readme` cannot be computed by build backend `uv_build` (`uv-build` does not support dynamic fields)",
+    })
     .run();
   }
 
   #[test]
-  fn project_license_files_items_must_be_strings() {
+  fn project_dynamic_backend_skips_extensible_backends() {
     Test::new(indoc! {
       r#"
+      [build-system]
+      requires = ["hatchling"]
+      build-backend = "hatchling.build"
+
       [project]
       name = "demo"
-      version = "1.0.0"
-      license = "MIT"
-      license-files = [1]
+      dynamic = ["version", "classifiers"]
       "#
     })
-    .error(Message {
-      range: (4, 17, 4, 18),
-      text: "`project.license-files` items must be strings",
-    })
     .run();
   }
 
   #[test]
-  fn project_license_files_must_be_array_of_strings() {
+  fn project_dynamic_items_must_be_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = "LICENSE*"
+      dynamic = [1]
       "#
     })
     .error(Message {
-      range: (4, 16, 4, 26),
-      text: "`project.license-files` must be an array of strings",
+      range: (3, 11, 3, 12),
+      text: "`project.dynamic` items must be strings",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_must_point_to_existing_utf8_files() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_must_be_array_of_strings() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = ["LICENSE"]
+      dynamic = "version"
       "#
     })
     .error(Message {
-      range: (4, 17, 4, 26),
-      text: "`project.license-files` pattern `LICENSE` did not match any files",
+      range: (3, 10, 3, 19),
+      text: "`project.dynamic` must be an array of strings",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_pattern_allows_empty_array() {
+  fn project_dynamic_must_not_conflict_with_static_readme_table() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = []
+      dynamic = ["readme"]
+      readme = { text = "demo package", content-type = "text/markdown" }
       "#
     })
+    .error(Message {
+      range: (3, 11, 3, 19),
+      text: "`project.dynamic` field `readme` must not also be provided statically",
+    })
     .run();
   }
 
   #[test]
-  fn project_license_files_pattern_must_match() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_must_not_conflict_with_static_scripts_tables() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = ["LICENSE*"]"#
+      dynamic = ["scripts", "gui-scripts", "entry-points"]
+
+      [project.scripts]
+      demo = "demo.cli:main"
+
+      [project.gui-scripts]
+      demo-gui = "demo.gui:main"
+
+      [project.entry-points."demo.plugins"]
+      builtin = "demo.plugins:builtin"
+      "#
     })
     .error(Message {
-      range: (4, 17, 4, 27),
-      text: "`project.license-files` pattern `LICENSE*` did not match any files",
+      range: (3, 11, 3, 20),
+      text: "`project.dynamic` field `scripts` must not also be provided statically",
+    })
+    .error(Message {
+      range: (3, 22, 3, 35),
+      text: "`project.dynamic` field `gui-scripts` must not also be provided statically",
+    })
+    .error(Message {
+      range: (3, 37, 3, 51),
+      text: "`project.dynamic` field `entry-points` must not also be provided statically",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_rejects_invalid_patterns() {
+  fn project_dynamic_must_not_conflict_with_static_values() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "MIT"
-      license-files = ["/LICENSE"]
+      dynamic = ["version", "description"]
+      description = "demo package"
       "#
     })
     .error(Message {
-      range: (4, 17, 4, 27),
-      text: "invalid `project.license-files` pattern `/LICENSE`: patterns must be relative; leading `/` is not allowed",
+      range: (3, 11, 3, 20),
+      text: "`project.dynamic` field `version` must not also be provided statically",
+    })
+    .error(Message {
+      range: (3, 22, 3, 35),
+      text: "`project.dynamic` field `description` must not also be provided statically",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_rejects_parent_segments() {
+  fn project_dynamic_must_not_duplicate_fields() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      version = "1.0.0"
-      license = "MIT"
-      license-files = ["..\\LICENSE"]
+      dynamic = ["version", "version"]
       "#
     })
     .error(Message {
-      range: (4, 17, 4, 30),
-      text: "invalid `project.license-files` pattern `..\\LICENSE`: path delimiter must be `/`, not `\\`",
+      range: (2, 22, 2, 31),
+      text: "`project.dynamic` contains duplicate field `version`",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_requires_string_license_when_present() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_must_not_include_name() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = "LICENSE" }
-      license-files = ["LICENSE"]
+      dynamic = ["name"]
       "#
     })
-    .write_file("LICENSE", "MIT")
     .error(Message {
-      range: (3, 10, 3, 30),
-      text: "`project.license` must be a string SPDX expression when `project.license-files` is present",
+      range: (3, 11, 3, 17),
+      text: "`project.dynamic` must not include `name`",
     })
     .run();
   }
 
   #[test]
-  fn project_license_files_supports_globstar_patterns() {
-    Test::with_tempdir(indoc! {
+  fn project_dynamic_rejects_unsupported_fields() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      version = "1.0.0"
-      license = "MIT"
-      license-files = ["licenses/**/LICENSE"]
+      dynamic = ["version", "foo"]
       "#
     })
-    .write_file("licenses/nested/deeper/LICENSE", "MIT")
+    .error(Message {
+      range: (2, 22, 2, 27),
+      text: "`project.dynamic` contains unsupported field `foo`",
+    })
     .run();
   }
 
   #[test]
-  fn project_license_must_be_string_or_table() {
+  fn project_entry_point_names_must_not_have_invalid_characters() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = []
+
+      [project.scripts]
+      " bad" = "demo:main"
       "#
     })
     .error(Message {
-      range: (3, 10, 3, 12),
-      text: "`project.license` must be a string or table",
+      range: (5, 0, 5, 6),
+      text: "`project.scripts. bad` name must not start or end with whitespace",
     })
     .run();
   }
 
   #[test]
-  fn project_license_must_be_valid_spdx_expression() {
+  fn project_entry_point_extras_must_be_declared() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "Apache-2.0 OR NotARealLicense"
+
+      [project.optional-dependencies]
+      cli = ["click"]
+
+      [project.scripts]
+      demo = "demo.cli:main [cli]"
+      other = "demo.cli:other [Missing]"
       "#
     })
-    .error(Message {
-      range: (3, 10, 3, 41),
-      text: "`project.license` must be a valid SPDX expression: unknown term",
+    .warning(Message {
+      range: (8, 7, 8, 28),
+      text: "`project.scripts.demo` uses extras in entry point definitions; extras are deprecated for entry points and may be ignored by consumers",
+    })
+    .warning(Message {
+      range: (9, 8, 9, 34),
+      text: "`project.scripts.other` references extra `Missing` that is not declared in `project.optional-dependencies`; installing with it won't pull in the intended dependencies",
+    })
+    .warning(Message {
+      range: (9, 8, 9, 34),
+      text: "`project.scripts.other` uses extras in entry point definitions; extras are deprecated for entry points and may be ignored by consumers",
     })
     .run();
   }
 
   #[test]
-  fn project_license_string_must_not_be_empty() {
+  fn project_entry_point_values_must_reference_importable_objects() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = ""
+
+      [project.scripts]
+      cli = "1demo:main"
       "#
     })
     .error(Message {
-      range: (3, 10, 3, 12),
-      text: "`project.license` must not be empty",
+      range: (5, 6, 5, 18),
+      text: "`project.scripts.cli` must reference an importable module path (e.g. `package.module`) optionally followed by `:qualname`",
     })
     .run();
   }
 
   #[test]
-  fn project_license_suggests_canonical_expression() {
+  fn project_entry_points_gui_scripts_accept_object_references() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "mit"
+
+      [project.gui-scripts]
+      demo-gui = "demo.gui:main"
+      demo-app = "demo.app"
       "#
     })
-    .error(Message {
-      range: (3, 10, 3, 15),
-      text: "`project.license` must be a valid SPDX expression: unknown term (did you mean `MIT`?)",
-    })
     .run();
   }
 
   #[test]
-  fn project_license_table_file_must_be_a_string() {
+  fn project_entry_points_gui_scripts_must_reference_importable_modules() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = 1 }
+
+      [project.gui-scripts]
+      demo-gui = "demo-gui:main"
+      demo-app = "demo.app:"
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 22),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    .error(Message {
+      range: (5, 11, 5, 26),
+      text: "`project.gui-scripts.demo-gui` must reference an importable module path (e.g. `package.module`) optionally followed by `:qualname`",
     })
     .error(Message {
-      range: (3, 19, 3, 20),
-      text: "`project.license.file` must be a string",
+      range: (6, 11, 6, 22),
+      text: "`project.gui-scripts.demo-app` object reference after `:` must be a dotted Python identifier",
     })
     .run();
   }
 
   #[test]
-  fn project_license_table_file_must_exist() {
+  fn project_entry_points_group_names_must_match_pattern() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = "LICENSE" }
+
+      [project.entry-points."bad group"]
+      cli = "demo:main"
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 30),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
-    })
     .error(Message {
-      range: (3, 19, 3, 28),
-      text: "file `LICENSE` for `project.license.file` does not exist",
+      range: (4, 22, 4, 33),
+      text: "`project.entry-points` group names must match `^\\w+(\\.\\w+)*$`",
     })
     .run();
   }
 
   #[test]
-  #[cfg(unix)]
-  fn project_license_table_file_path_must_be_relative_unix() {
+  fn project_entry_points_rejects_console_scripts_group() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = "/LICENSE" }
-      "#
-    })
-    .warning(Message {
-      range: (3, 10, 3, 31),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
-    })
-    .error(Message {
-      range: (3, 19, 3, 29),
-      text: "file `/LICENSE` for `project.license.file` does not exist",
+
+      [project.entry-points.console_scripts]
+      cli = "demo:main"
+      "#
     })
     .error(Message {
-      range: (3, 19, 3, 29),
-      text: "file path for `project.license.file` must be relative",
+      range: (4, 22, 4, 37),
+      text: "`project.entry-points.console_scripts` is not allowed; use `[project.scripts]` instead",
     })
     .run();
   }
 
   #[test]
-  #[cfg(windows)]
-  fn project_license_table_file_path_must_be_relative_windows() {
+  fn project_entry_points_rejects_nested_entry_point_tables() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = "/LICENSE" }
+
+      [project.entry-points.my_group]
+      nested.table = "demo:main"
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 31),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
-    })
     .error(Message {
-      range: (3, 19, 3, 29),
-      text: "file `/LICENSE` for `project.license.file` does not exist",
+      range: (5, 0, 5, 6),
+      text: "`project.entry-points.my_group.nested` must be a string object reference; entry point groups cannot be nested",
     })
     .run();
   }
 
   #[test]
-  fn project_license_table_must_not_mix_file_and_text() {
-    Test::with_tempdir(indoc! {
+  fn project_entry_points_requires_table() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { file = "LICENSE", text = "Apache" }
+      entry-points = "demo:main"
       "#
     })
-    .write_file("LICENSE", "MIT")
     .error(Message {
-      range: (3, 10, 3, 47),
-      text: "`project.license` must specify only one of `file` or `text`",
-    })
-    .warning(Message {
-      range: (3, 10, 3, 47),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+      range: (3, 15, 3, 26),
+      text: "`project.entry-points` must be a table of entry point groups",
     })
     .run();
   }
 
   #[test]
-  fn project_license_table_requires_file_or_text() {
+  fn project_entry_points_script_names_must_be_command_names() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { }
+
+      [project.scripts]
+      "bin/demo" = "demo:main"
+
+      [project.gui-scripts]
+      "demo\u0007" = "demo:gui"
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 13),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    .error(Message {
+      range: (5, 0, 5, 10),
+      text: "`project.scripts.bin/demo` name must not contain path separators; it is installed as a command",
     })
     .error(Message {
-      range: (3, 10, 3, 13),
-      text: "missing required key `project.license.file` or `project.license.text`",
+      range: (8, 0, 8, 12),
+      text: "`project.gui-scripts.demo\\u{7}` name must not contain control characters; it is installed as a command",
     })
     .run();
   }
 
   #[test]
-  fn project_license_table_text_must_be_a_string() {
+  fn project_import_names_detects_duplicates_across_fields() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = { text = 1 }
+      import-names = ["demo"]
+      import-namespaces = ["demo; private"]
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 22),
-      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
-    })
     .error(Message {
-      range: (3, 19, 3, 20),
-      text: "`project.license.text` must be a string",
+      range: (4, 21, 4, 36),
+      text: "duplicated names are not allowed in `project.import-names`/`project.import-namespaces` (found `demo`)",
     })
     .run();
   }
 
   #[test]
-  fn project_license_warns_on_deprecated_identifier() {
+  fn project_import_names_items_must_be_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      license = "wxWindows"
+      import-names = [1]
       "#
     })
-    .warning(Message {
-      range: (3, 10, 3, 21),
-      text: "license identifier `wxWindows` in `project.license` is deprecated",
+    .error(Message {
+      range: (3, 16, 3, 17),
+      text: "`project.import-names` items must be strings",
     })
     .run();
   }
 
   #[test]
-  fn project_maintainers_must_be_array_of_inline_tables() {
+  fn project_import_names_must_be_array_of_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      maintainers = 123
+      import-names = "demo"
       "#
     })
     .error(Message {
-      range: (3, 14, 3, 17),
-      text: "`project.maintainers` must be an array of inline tables",
+      range: (3, 15, 3, 21),
+      text: "`project.import-names` must be an array of strings",
     })
     .run();
   }
 
   #[test]
-  fn project_name_is_required() {
+  fn project_import_names_require_parent_namespaces() {
     Test::new(indoc! {
       r#"
       [project]
+      name = "demo"
       version = "1.0.0"
+      import-names = ["demo.core.utils"]
       "#
     })
     .error(Message {
-      range: (0, 0, 0, 9),
-      text: "missing required key `project.name`",
+      range: (3, 16, 3, 33),
+      text: "`demo.core.utils` is missing parent namespace `demo`; all parents must be listed in `project.import-names`/`project.import-namespaces`",
     })
     .run();
   }
 
   #[test]
-  fn project_name_must_be_a_string() {
+  fn project_import_names_accepts_valid_names() {
     Test::new(indoc! {
       r#"
       [project]
-      name = 123
+      name = "demo"
       version = "1.0.0"
+      import-names = ["foo", "foo.bar", "_foo", "\u00e9", "x", "private_a;private", "private_b; private", "private_c ;private", "private_d \t;\tprivate"]
+      import-namespaces = ["namespace", "namespace.child"]
       "#
     })
-    .error(Message {
-      range: (1, 7, 1, 10),
-      text: "`project.name` must be a string",
-    })
     .run();
   }
 
   #[test]
-  fn project_name_must_be_a_valid_distribution_name() {
+  fn project_import_names_allows_empty_names() {
     Test::new(indoc! {
       r#"
       [project]
-      name = "my!package"
+      name = "demo"
       version = "1.0.0"
+      import-names = [""]
       "#
     })
-    .error(Message {
-      range: (1, 7, 1, 19),
-      text: "`project.name` must be a valid distribution name",
-    })
     .run();
   }
 
   #[test]
-  fn project_name_must_not_be_empty() {
+  fn project_import_names_allows_empty_arrays() {
     Test::new(indoc! {
       r#"
       [project]
-      name = ""
+      name = "demo"
       version = "1.0.0"
+      import-names = []
       "#
     })
-    .error(Message {
-      range: (1, 7, 1, 9),
-      text: "`project.name` must not be empty",
-    })
     .run();
   }
 
   #[test]
-  fn project_name_normalization_is_opt_in() {
+  fn project_import_names_detects_duplicates_with_private_suffixes() {
     Test::new(indoc! {
       r#"
       [project]
-      name = "My_Package"
+      name = "demo"
       version = "1.0.0"
+      import-names = ["foo", "foo; private"]
       "#
     })
+    .error(Message {
+      range: (3, 23, 3, 37),
+      text: "duplicated names are not allowed in `project.import-names`/`project.import-namespaces` (found `foo`)",
+    })
     .run();
   }
 
   #[test]
-  fn project_name_normalization_warns_when_enabled() {
+  fn project_import_names_rejects_invalid_identifiers() {
     Test::new(indoc! {
       r#"
       [project]
-      name = "My_Package"
+      name = "demo"
       version = "1.0.0"
-
-      [tool.pyproject.rules]
-      project-name-normalization = "warning"
+      import-names = ["foo..bar"]
       "#
     })
-    .warning(Message {
-      range: (1, 7, 1, 19),
-      text: "`project.name` is not normalized (use `my-package`)",
+    .error(Message {
+      range: (3, 16, 3, 26),
+      text: "`project.import-names` item `foo..bar` must be a valid dotted Python identifier",
     })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_empty_array_valid() {
+  fn project_import_names_rejects_keywords() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = []
+      import-names = ["foo.class"]
       "#
     })
+    .error(Message {
+      range: (3, 16, 3, 27),
+      text: "`project.import-names` item `foo.class` contains Python keyword `class`",
+    })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_items_must_be_strings() {
+  fn project_import_names_rejects_invalid_suffixes() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = [1]
+      import-names = ["foo; public"]
       "#
     })
     .error(Message {
-      range: (4, 8, 4, 9),
-      text: "`project.optional-dependencies.test[0]` must be a string",
+      range: (3, 16, 3, 29),
+      text: "`project.import-names` item `foo; public` has an invalid suffix; only `; private` is allowed",
     })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_multiple_errors() {
+  fn project_import_names_accepts_present_parent_namespaces() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      "invalid!" = ["Requests>=1.0"]
-      test = ["invalid spec >= "]
+      import-names = ["foo.bar"]
+      import-namespaces = ["foo; private"]
       "#
     })
-    .error(Message {
-      range: (4, 0, 4, 10),
-      text: "`project.optional-dependencies.invalid!` key `invalid!` must be a valid PEP 508 extra name",
-    })
-    .error(Message {
-      range: (5, 8, 5, 26),
-      text: "`project.optional-dependencies.test[0]` item `invalid spec >= ` is not a valid PEP 508 dependency: expected one of `@`, `(`, `<`, `=`, `>`, `~`, `!`, `;`, found `s`",
-    })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_must_be_table() {
+  fn project_import_namespaces_rejects_empty_arrays() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = "not an array"
+      import-namespaces = []
       "#
     })
     .error(Message {
-      range: (4, 7, 4, 21),
-      text: "`project.optional-dependencies.test` must be an array of PEP 508 strings",
+      range: (3, 20, 3, 22),
+      text: "`project.import-namespaces` must not be an empty array",
     })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_must_be_table_when_string() {
+  fn project_import_namespaces_rejects_empty_names() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      optional-dependencies = "not a table"
+      import-namespaces = [""]
       "#
     })
     .error(Message {
-      range: (3, 24, 3, 37),
-      text: "`project.optional-dependencies` must be a table",
+      range: (3, 21, 3, 23),
+      text: "`project.import-namespaces` item `` must be a valid dotted Python identifier",
     })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_rejects_invalid_extra_name() {
+  fn project_keywords_case_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      "invalid-extra-name!" = ["requests"]
+      keywords = ["Packaging"]
       "#
     })
-    .error(Message {
-      range: (4, 0, 4, 21),
-      text: "`project.optional-dependencies.invalid-extra-name!` key `invalid-extra-name!` must be a valid PEP 508 extra name",
-    })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_rejects_invalid_specifier() {
+  fn project_keywords_case_warns_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = ["requests >= "]
+      keywords = ["packaging", "TOML", "Linter"]
+
+      [tool.pyproject.rules]
+      project-keywords-case = "warning"
       "#
     })
-    .error(Message {
-      range: (4, 8, 4, 22),
-      text: "`project.optional-dependencies.test[0]` item `requests >= ` is not a valid PEP 508 dependency: unexpected end of version specifier, expected version",
+    .warning(Message {
+      range: (3, 25, 3, 31),
+      text: "`project.keywords` entry `TOML` should be lowercase",
+    })
+    .warning(Message {
+      range: (3, 33, 3, 41),
+      text: "`project.keywords` entry `Linter` should be lowercase",
     })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_require_normalized_names() {
+  fn project_keywords_classifiers_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = ["Requests>=1.0"]
+      keywords = ["python3"]
+      classifiers = ["Programming Language :: Python :: 3"]
       "#
     })
-    .error(Message {
-      range: (4, 8, 4, 23),
-      text: "`project.optional-dependencies.test[0]` package name `Requests` must be normalized (use `requests`)",
-    })
     .run();
   }
 
   #[test]
-  fn project_optional_dependencies_valid_configuration() {
+  fn project_keywords_classifiers_reports_when_enabled() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      [project.optional-dependencies]
-      test = ["pytest>=7.0.0", "pytest-cov"]
-      dev = ["black", "mypy>=1.0.0"]
-      "#
-    })
+      license = "MIT"
+      keywords = ["python3", "mit", "django", "parsing", "3"]
+      classifiers = ["Programming Language :: Python :: 3"]
+
+      [tool.pyproject.rules]
+      project-keywords-classifiers = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (4, 12, 4, 21),
+        text: "`project.keywords` entry `python3` is already expressed by classifier `Programming Language :: Python :: 3`; rely on the classifier instead",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (4, 23, 4, 28),
+        text: "`project.keywords` entry `mit` is already expressed by `project.license` (`MIT`); rely on the license instead",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (4, 30, 4, 38),
+        text: "`project.keywords` entry `django` can be expressed as classifier `Framework :: Django`; prefer the classifier instead",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
   #[test]
-  fn project_readme_rejects_unknown_keys() {
+  fn project_keywords_items_must_be_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { text = "hi", content-type = "text/markdown", extra = "nope" }
+      keywords = [1, "two"]
       "#
     })
     .error(Message {
-      range: (3, 56, 3, 61),
-      text: "`project.readme` only supports `file`, `text`, and `content-type` keys",
+      range: (3, 12, 3, 13),
+      text: "`project.keywords` items must be strings",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_string_must_point_to_existing_file() {
+  fn project_keywords_must_be_an_array_of_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = "README.md"
+      keywords = "invalid"
       "#
     })
     .error(Message {
-      range: (3, 9, 3, 20),
-      text: "file `README.md` for `project.readme` does not exist",
+      range: (3, 11, 3, 20),
+      text: "`project.keywords` must be an array of strings",
     })
     .run();
   }
 
   #[test]
-  #[cfg(unix)]
-  fn project_readme_string_path_must_be_relative_unix() {
+  fn project_keywords_must_not_contain_duplicates() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = "/README.md"
+      keywords = ["one", "two", "one"]
       "#
     })
     .error(Message {
-      range: (3, 9, 3, 21),
-      text: "file `/README.md` for `project.readme` does not exist",
-    })
-    .error(Message {
-      range: (3, 9, 3, 21),
-      text: "file path for `project.readme` must be relative",
+      range: (3, 26, 3, 31),
+      text: "`project.keywords` contains duplicate keyword `one`",
     })
     .run();
   }
 
   #[test]
-  #[cfg(windows)]
-  fn project_readme_string_path_must_be_relative_windows() {
+  fn project_license_classifiers_forbidden_when_license_set() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = "/README.md"
+      license = "MIT"
+      classifiers = [
+        "License :: OSI Approved :: MIT License",
+        "Programming Language :: Python",
+      ]
       "#
     })
     .error(Message {
-      range: (3, 9, 3, 21),
-      text: "file `/README.md` for `project.readme` does not exist",
+      range: (3, 10, 3, 15),
+      text: "license is declared through an SPDX `project.license` string and `License ::` classifiers; use only an SPDX `project.license` string with `project.license-files` as described in PEP 639",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_string_requires_known_extension() {
-    Test::with_tempdir(indoc! {
+  fn project_license_classifiers_mismatch_license_expression() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = "README.txt"
+      license = "Apache-2.0"
+      classifiers = [
+        "License :: OSI Approved :: Apache Software License",
+        "License :: OSI Approved :: MIT License",
+      ]
       "#
     })
-    .write_file("README.txt", "# readme")
     .error(Message {
-      range: (3, 9, 3, 21),
-      text: "`project.readme` must point to a `.md` or `.rst` file when specified as a string",
+      range: (3, 10, 3, 22),
+      text: "license is declared through an SPDX `project.license` string and `License ::` classifiers; use only an SPDX `project.license` string with `project.license-files` as described in PEP 639 (`License :: OSI Approved :: MIT License` disagrees with `Apache-2.0`)",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_table_accepts_text_plain() {
+  fn project_license_classifiers_warn_without_license() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { text = "inline", content-type = "text/plain" }
+      classifiers = ["License :: OSI Approved :: MIT License"]
       "#
     })
     .warning(Message {
-      range: (3, 43, 3, 55),
-      text: "`project.readme.content-type` is `text/plain`; consider `text/markdown` or `text/x-rst` for better rendering on package indexes",
+      range: (3, 15, 3, 55),
+      text: "`project.classifiers` license classifiers are deprecated; use `project.license` instead",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_table_file_must_exist() {
+  fn project_license_dual_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { file = "README.md", content-type = "text/markdown" }
+      license = "MIT OR Apache-2.0"
       "#
     })
-    .error(Message {
-      range: (3, 18, 3, 29),
-      text: "file `README.md` for `project.readme` does not exist",
+    .run();
+  }
+
+  #[test]
+  fn project_license_dual_notes_choice_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "MIT OR Apache-2.0"
+
+      [tool.pyproject.rules]
+      project-license-dual = "info"
+      "#
     })
+    .diagnostic(
+      Message {
+        range: (3, 10, 3, 29),
+        text: "`project.license` lets users choose between `MIT`, `Apache-2.0`; confirm the dual license is intentional",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
   #[test]
-  fn project_readme_table_must_not_mix_file_and_text() {
+  fn project_license_files_accepts_nested_license_path() {
     Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { file = "README.md", text = "inline", content-type = "text/markdown" }
+      license = "MIT"
+      license-files = ["licenses/LICENSE"]
       "#
     })
-    .write_file("README.md", "# readme")
-    .error(Message {
-      range: (3, 9, 3, 80),
-      text: "`project.readme` must specify only one of `file` or `text`",
-    })
+    .write_file("licenses/LICENSE", "MIT")
     .run();
   }
 
   #[test]
-  fn project_readme_table_requires_content_type() {
+  fn project_license_files_accepts_valid_match() {
     Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { file = "README.md" }
+      license = "MIT"
+      license-files = ["LICENSE"]
       "#
     })
-    .write_file("README.md", "# readme")
-    .error(Message {
-      range: (3, 9, 3, 31),
-      text: "missing required key `project.readme.content-type`",
-    })
+    .write_file("LICENSE", "MIT")
     .run();
   }
 
   #[test]
-  fn project_readme_table_requires_file_or_text() {
+  fn project_license_files_items_must_be_strings() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { content-type = "text/markdown" }
+      license = "MIT"
+      license-files = [1]
       "#
     })
     .error(Message {
-      range: (3, 9, 3, 43),
-      text: "missing required key `project.readme.file` or `project.readme.text`",
+      range: (4, 17, 4, 18),
+      text: "`project.license-files` items must be strings",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_table_requires_supported_content_type() {
-    Test::with_tempdir(indoc! {
+  fn project_license_files_must_be_array_of_strings() {
+    Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { file = "README.md", content-type = "text/html" }
+      license = "MIT"
+      license-files = "LICENSE*"
       "#
     })
-    .write_file("README.md", "# readme")
     .error(Message {
-      range: (3, 46, 3, 57),
-      text: "`project.readme.content-type` must be one of `text/markdown`, `text/x-rst`, or `text/plain`",
+      range: (4, 16, 4, 26),
+      text: "`project.license-files` must be an array of strings",
     })
     .run();
   }
 
   #[test]
-  fn project_readme_table_text_must_be_a_string() {
-    Test::new(indoc! {
+  fn project_license_files_must_point_to_existing_utf8_files() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      readme = { text = 1, content-type = "text/markdown" }
+      license = "MIT"
+      license-files = ["LICENSE"]
       "#
     })
     .error(Message {
-      range: (3, 18, 3, 19),
-      text: "`project.readme.text` must be a string",
+      range: (4, 17, 4, 26),
+      text: "`project.license-files` pattern `LICENSE` did not match any files",
     })
     .run();
   }
 
   #[test]
-  fn project_reports_unknown_keys() {
-    Test::new(indoc! {
+  fn project_license_files_notes_overlapping_patterns() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      custom = "value"
+      license = "MIT"
+      license-files = ["LICENSE.txt", "LICENSE*", "NOTICE"]
       "#
     })
-    .error(Message {
-      range: (3, 0, 3, 6),
-      text: "`project.custom` is not defined by PEP 621; move custom settings under `[tool]` or another accepted PEP section",
-    })
+    .write_file("LICENSE.txt", "MIT")
+    .write_file("LICENSE-APACHE", "Apache")
+    .write_file("NOTICE", "Notice")
+    .diagnostic(
+      Message {
+        range: (4, 17, 4, 30),
+        text: "`project.license-files` pattern `LICENSE.txt` matches files already matched by `LICENSE*`; consider removing the duplicate pattern",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
     .run();
   }
 
   #[test]
-  fn project_requires_python_allows_dynamic() {
+  fn project_license_files_pattern_allows_empty_array() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      dynamic = ["requires-python"]
+      license = "MIT"
+      license-files = []
       "#
     })
     .run();
   }
 
   #[test]
-  fn project_requires_python_allows_upper_bound_or_exact() {
-    Test::new(indoc! {
+  fn project_license_files_pattern_must_match() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = ">=3.10, <4"
-      "#
+      license = "MIT"
+      license-files = ["LICENSE*"]"#
+    })
+    .error(Message {
+      range: (4, 17, 4, 27),
+      text: "`project.license-files` pattern `LICENSE*` did not match any files",
     })
     .run();
   }
 
   #[test]
-  fn project_requires_python_must_be_a_string() {
+  fn project_license_files_rejects_invalid_patterns() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = 3.11
+      license = "MIT"
+      license-files = ["/LICENSE"]
       "#
     })
     .error(Message {
-      range: (3, 18, 3, 22),
-      text: "`project.requires-python` must be a string",
+      range: (4, 17, 4, 27),
+      text: "invalid `project.license-files` pattern `/LICENSE`: patterns must be relative; leading `/` is not allowed",
     })
     .run();
   }
 
   #[test]
-  fn project_requires_python_must_be_valid_pep_440() {
+  fn project_license_files_rejects_parent_segments() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = "=>3.12"
+      license = "MIT"
+      license-files = ["..\\LICENSE"]
       "#
     })
     .error(Message {
-      range: (3, 18, 3, 26),
-      text: "`project.requires-python` must be a valid PEP 440 version specifier: Failed to parse version: no such comparison operator \"=>\", must be one of ~= == != <= >= < > ===:\n=>3.12\n^^^^^^\n",
+      range: (4, 17, 4, 30),
+      text: "invalid `project.license-files` pattern `..\\LICENSE`: path delimiter must be `/`, not `\\`",
     })
     .run();
   }
 
   #[test]
-  fn project_requires_python_must_not_be_empty() {
-    Test::new(indoc! {
+  fn project_license_files_requires_string_license_when_present() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = ""
+      license = { file = "LICENSE" }
+      license-files = ["LICENSE"]
       "#
     })
+    .write_file("LICENSE", "MIT")
     .error(Message {
-      range: (3, 18, 3, 20),
-      text: "`project.requires-python` must not be empty",
+      range: (3, 10, 3, 30),
+      text: "license is declared through a `project.license` table and `project.license-files`; use only an SPDX `project.license` string with `project.license-files` as described in PEP 639",
     })
     .run();
   }
 
   #[test]
-  fn project_requires_python_upper_bound_is_opt_in() {
-    Test::new(indoc! {
+  fn project_license_files_supports_globstar_patterns() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = ">=3.8"
+      license = "MIT"
+      license-files = ["licenses/**/LICENSE"]
       "#
     })
+    .write_file("licenses/nested/deeper/LICENSE", "MIT")
     .run();
   }
 
   #[test]
-  fn project_requires_python_warns_without_upper_bound_when_enabled() {
+  fn project_license_intent_is_opt_in() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      requires-python = ">=3.8"
+      license = "GPL-3.0-only"
 
-      [tool.pyproject.rules]
-      project-requires-python-bounds = "warning"
+      [tool.pyproject.license-policy]
+      intent = "permissive"
       "#
     })
-    .warning(Message {
-      range: (3, 18, 3, 25),
-      text: "`project.requires-python` does not specify an upper bound; consider adding one to avoid unsupported future Python versions",
-    })
     .run();
   }
 
   #[test]
-  fn project_urls_entries_must_be_strings() {
+  fn project_license_intent_warns_on_copyleft_when_permissive() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      urls = { homepage = 123 }
+      license = "GPL-3.0-only"
+
+      [tool.pyproject.license-policy]
+      intent = "permissive"
+
+      [tool.pyproject.rules]
+      project-license-intent = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 20, 3, 23),
-      text: "`project.urls` entry `homepage` must be a string URL",
+    .warning(Message {
+      range: (3, 10, 3, 24),
+      text: "`project.license` requires copyleft license `GPL-3.0-only`, but `tool.pyproject.license-policy` declares the project as permissive",
     })
     .run();
   }
 
   #[test]
-  fn project_urls_entries_must_be_valid_urls() {
+  fn project_license_intent_warns_on_permissive_when_copyleft() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      urls = { homepage = "example.com" }
+      license = "MIT"
+
+      [tool.pyproject.license-policy]
+      intent = "copyleft"
+
+      [tool.pyproject.rules]
+      project-license-intent = "warning"
       "#
     })
-    .error(Message {
-      range: (3, 20, 3, 33),
-      text: "`project.urls` entry `homepage` must be a valid URL: relative URL without a base",
+    .warning(Message {
+      range: (3, 10, 3, 15),
+      text: "`project.license` `MIT` contains no copyleft license, but `tool.pyproject.license-policy` declares the project as copyleft",
     })
     .run();
   }
 
   #[test]
-  fn project_urls_entries_must_not_be_empty() {
+  fn project_license_must_be_string_or_table() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      urls = { homepage = "" }
+      license = []
       "#
     })
     .error(Message {
-      range: (3, 20, 3, 22),
-      text: "`project.urls` entry `homepage` must not be empty",
+      range: (3, 10, 3, 12),
+      text: "`project.license` must be a string or table",
     })
     .run();
   }
 
   #[test]
-  fn project_urls_entries_must_use_http_or_https() {
+  fn project_license_must_be_valid_spdx_expression() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      urls = { homepage = "ftp://example.com" }
+      license = "Apache-2.0 OR NotARealLicense"
       "#
     })
     .error(Message {
-      range: (3, 20, 3, 39),
-      text: "`project.urls` entry `homepage` must use an `http` or `https` URL",
+      range: (3, 10, 3, 41),
+      text: "`project.license` must be a valid SPDX expression: unknown term",
     })
     .run();
   }
 
   #[test]
-  fn project_urls_labels_must_not_exceed_limit() {
-    Test::new(indoc! {
+  fn project_license_mechanisms_accepts_pep_639_metadata() {
+    Test::with_tempdir(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-
-      [project.urls]
-      this-label-is-way-too-long-to-be-valid = "https://example.com"
+      license = "MIT"
+      license-files = ["LICENSE"]
       "#
     })
-    .error(Message {
-      range: (5, 0, 5, 38),
-      text: "`project.urls` label `this-label-is-way-too-long-to-be-valid` must be 32 characters or fewer",
-    })
+    .write_file("LICENSE", "MIT")
     .run();
   }
 
   #[test]
-  fn project_urls_must_be_a_table() {
+  fn project_license_mechanisms_summarizes_legacy_mix() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
       version = "1.0.0"
-      urls = "https://example.com"
+      license = { text = "MIT" }
+      classifiers = ["License :: OSI Approved :: MIT License"]
       "#
     })
-    .error(Message {
-      range: (3, 7, 3, 28),
-      text: "`project.urls` must be a table of string URLs",
+    .warning(Message {
+      range: (3, 10, 3, 26),
+      text: "license is declared through a `project.license` table and `License ::` classifiers; use only an SPDX `project.license` string with `project.license-files` as described in PEP 639",
     })
     .run();
   }
 
   #[test]
-  fn project_version_is_required_unless_dynamic() {
+  fn project_license_policy_rejects_denied_license() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
+      version = "1.0.0"
+      license = "MIT AND GPL-3.0-only"
+
+      [tool.pyproject.license-policy]
+      deny = ["GPL-3.0-only"]
       "#
     })
     .error(Message {
-      range: (0, 0, 0, 9),
-      text: "missing required key `project.version`",
+      range: (3, 10, 3, 32),
+      text: "`project.license` uses license `GPL-3.0-only`, which is denied by `tool.pyproject.license-policy`",
     })
     .run();
+  }
 
+  #[test]
+  fn project_license_policy_accepts_allowed_alternative() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      dynamic = ["version"]
+      version = "1.0.0"
+      license = "MIT OR GPL-3.0-only"
+
+      [tool.pyproject.license-policy]
+      allow = ["MIT"]
+      deny = ["GPL-3.0-only"]
       "#
     })
     .run();
   }
 
   #[test]
-  fn project_version_must_be_a_string() {
+  fn project_license_policy_rejects_unsatisfiable_alternatives() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      version = 1
+      version = "1.0.0"
+      license = "Apache-2.0 OR GPL-3.0-only"
+
+      [tool.pyproject.license-policy]
+      allow = ["MIT"]
       "#
     })
     .error(Message {
-      range: (2, 10, 2, 11),
-      text: "`project.version` must be a string",
+      range: (3, 10, 3, 38),
+      text: "`project.license` uses license `Apache-2.0`, which is not allowed by `tool.pyproject.license-policy`",
+    })
+    .error(Message {
+      range: (3, 10, 3, 38),
+      text: "`project.license` uses license `GPL-3.0-only`, which is not allowed by `tool.pyproject.license-policy`",
     })
     .run();
   }
 
   #[test]
-  fn project_version_must_be_pep_440_compliant() {
+  fn project_license_policy_requires_allowed_license() {
     Test::new(indoc! {
       r#"
       [project]
       name = "demo"
-      version = "foo"
+      version = "1.0.0"
+      license = "Apache-2.0 AND MIT"
+
+      [tool.pyproject.license-policy]
+      allow = ["mit"]
       "#
     })
     .error(Message {
-      range: (2, 10, 2, 15),
-      text: "expected version to start with a number, but no leading ASCII digits were found",
+      range: (3, 10, 3, 30),
+      text: "`project.license` uses license `Apache-2.0`, which is not allowed by `tool.pyproject.license-policy`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_refs_accept_named_license_files() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "MIT AND LicenseRef-Demo"
+      license-files = ["LICENSES/*"]
+      "#
+    })
+    .write_file("LICENSES/MIT.txt", "MIT")
+    .write_file("LICENSES/LicenseRef-Demo.txt", "Demo")
+    .run();
+  }
+
+  #[test]
+  fn project_license_refs_require_license_files() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "LicenseRef-Demo"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (3, 10, 3, 27),
+        text: "`project.license` uses custom license `LicenseRef-Demo`; add its text to `project.license-files` so it ships with the distribution",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_license_refs_should_have_matching_file() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "LicenseRef-Demo"
+      license-files = ["LICENSE"]
+      "#
+    })
+    .write_file("LICENSE", "Demo")
+    .diagnostic(
+      Message {
+        range: (3, 10, 3, 27),
+        text: "`project.license` uses custom license `LicenseRef-Demo`, but no file matched by `project.license-files` is named after it (e.g. `LICENSES/LicenseRef-Demo.txt`)",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_license_string_must_not_be_empty() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = ""
+      "#
+    })
+    .error(Message {
+      range: (3, 10, 3, 12),
+      text: "`project.license` must not be empty",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_suggests_canonical_expression() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "mit"
+      "#
+    })
+    .error(Message {
+      range: (3, 10, 3, 15),
+      text: "`project.license` must be a valid SPDX expression: unknown term (did you mean `MIT`?)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_file_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { file = 1 }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 22),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 19, 3, 20),
+      text: "`project.license.file` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_file_must_exist() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { file = "LICENSE" }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 30),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 19, 3, 28),
+      text: "file `LICENSE` for `project.license.file` does not exist",
+    })
+    .run();
+  }
+
+  #[test]
+  #[cfg(unix)]
+  fn project_license_table_file_path_must_be_relative_unix() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { file = "/LICENSE" }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 31),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 19, 3, 29),
+      text: "file `/LICENSE` for `project.license.file` does not exist",
+    })
+    .error(Message {
+      range: (3, 19, 3, 29),
+      text: "file path for `project.license.file` must be relative",
+    })
+    .run();
+  }
+
+  #[test]
+  #[cfg(windows)]
+  fn project_license_table_file_path_must_be_relative_windows() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { file = "/LICENSE" }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 31),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 19, 3, 29),
+      text: "file `/LICENSE` for `project.license.file` does not exist",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_must_not_mix_file_and_text() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { file = "LICENSE", text = "Apache" }
+      "#
+    })
+    .write_file("LICENSE", "MIT")
+    .error(Message {
+      range: (3, 10, 3, 47),
+      text: "`project.license` must specify only one of `file` or `text`",
+    })
+    .warning(Message {
+      range: (3, 10, 3, 47),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_rejects_unknown_keys() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { text = "MIT", foo = 1 }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 35),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 26, 3, 29),
+      text: "`project.license` only supports `file` and `text` keys",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_requires_file_or_text() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 13),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 10, 3, 13),
+      text: "missing required key `project.license.file` or `project.license.text`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_table_text_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = { text = 1 }
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 22),
+      text: "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
+    })
+    .error(Message {
+      range: (3, 19, 3, 20),
+      text: "`project.license.text` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_license_warns_on_deprecated_identifier() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "wxWindows"
+      "#
+    })
+    .warning(Message {
+      range: (3, 10, 3, 21),
+      text: "license identifier `wxWindows` in `project.license` is deprecated",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_maintainers_must_be_array_of_inline_tables() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      maintainers = 123
+      "#
+    })
+    .error(Message {
+      range: (3, 14, 3, 17),
+      text: "`project.maintainers` must be an array of inline tables",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_availability_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "requests"
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_is_required() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      version = "1.0.0"
+      "#
+    })
+    .error(Message {
+      range: (0, 0, 0, 9),
+      text: "missing required key `project.name`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = 123
+      version = "1.0.0"
+      "#
+    })
+    .error(Message {
+      range: (1, 7, 1, 10),
+      text: "`project.name` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_must_be_a_valid_distribution_name() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "my!package"
+      version = "1.0.0"
+      "#
+    })
+    .error(Message {
+      range: (1, 7, 1, 19),
+      text: "`project.name` must be a valid distribution name",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_must_not_exceed_length_limit() {
+    Test::new(&format!(
+      "[project]\nname = \"{}\"\nversion = \"1.0.0\"\n",
+      "a".repeat(215)
+    ))
+    .error(Message {
+      range: (1, 7, 1, 224),
+      text: "`project.name` must be at most 214 characters once normalized, but is 215; PyPI rejects longer names",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_must_not_start_or_end_with_separators() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "_private-"
+      version = "1.0.0"
+      "#
+    })
+    .error(Message {
+      range: (1, 7, 1, 18),
+      text: "`project.name` must start and end with a letter or digit; PyPI rejects names beginning or ending with `.`, `-`, or `_`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_must_not_be_empty() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = ""
+      version = "1.0.0"
+      "#
+    })
+    .error(Message {
+      range: (1, 7, 1, 9),
+      text: "`project.name` must not be empty",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_normalization_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "My_Package"
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_normalization_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "My_Package"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name-normalization = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 7, 1, 19),
+      text: "`project.name` is not normalized (use `my-package`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_typosquat_ignores_popular_names() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "requests"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name-typosquat = "warning"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_typosquat_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "numpyy"
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_typosquat_warns_on_extra_character() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "numpyy"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name-typosquat = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 7, 1, 15),
+      text: "`project.name` `numpyy` is one edit away from popular package `numpy`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_name_typosquat_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "Reqeusts"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name-typosquat = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 7, 1, 17),
+      text: "`project.name` `reqeusts` is one edit away from popular package `requests`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_empty_array_valid() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = []
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_groups_overlap_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      dev = ["pytest", "ruff"]
+
+      [dependency-groups]
+      dev = ["pytest", "ruff"]
+
+      [tool.pyproject.rules]
+      dependency-groups-overlap = "off"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_groups_overlap_notes_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      test = ["pytest>=8", "pytest-cov", "hypothesis"]
+      docs = ["sphinx"]
+
+      [dependency-groups]
+      tests = ["pytest", "pytest-cov", "hypothesis", "coverage"]
+      docs = ["mkdocs"]
+
+      [tool.pyproject.rules]
+      project-optional-dependencies-groups-overlap = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (5, 0, 5, 4),
+        text: "`project.optional-dependencies.test` shares 3 of its 3 packages with dependency group `tests`; consider keeping a single list to avoid drift",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_items_must_be_strings() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = [1]
+      "#
+    })
+    .error(Message {
+      range: (4, 8, 4, 9),
+      text: "`project.optional-dependencies.test[0]` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_multiple_errors() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      "invalid!" = ["Requests>=1.0"]
+      test = ["invalid spec >= "]
+      "#
+    })
+    .error(Message {
+      range: (4, 0, 4, 10),
+      text: "`project.optional-dependencies.invalid!` key `invalid!` must be a valid PEP 508 extra name",
+    })
+    .error(Message {
+      range: (5, 8, 5, 26),
+      text: "`project.optional-dependencies.test[0]` item `invalid spec >= ` is not a valid PEP 508 dependency: expected one of `@`, `(`, `<`, `=`, `>`, `~`, `!`, `;`, found `s`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_must_be_table() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = "not an array"
+      "#
+    })
+    .error(Message {
+      range: (4, 7, 4, 21),
+      text: "`project.optional-dependencies.test` must be an array of PEP 508 strings",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_must_be_table_when_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      optional-dependencies = "not a table"
+      "#
+    })
+    .error(Message {
+      range: (3, 24, 3, 37),
+      text: "`project.optional-dependencies` must be a table",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_rejects_invalid_extra_name() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      "invalid-extra-name!" = ["requests"]
+      "#
+    })
+    .error(Message {
+      range: (4, 0, 4, 21),
+      text: "`project.optional-dependencies.invalid-extra-name!` key `invalid-extra-name!` must be a valid PEP 508 extra name",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_rejects_invalid_specifier() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = ["requests >= "]
+      "#
+    })
+    .error(Message {
+      range: (4, 8, 4, 22),
+      text: "`project.optional-dependencies.test[0]` item `requests >= ` is not a valid PEP 508 dependency: unexpected end of version specifier, expected version",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_require_normalized_extra_names() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      "Test_Utils" = ["pytest"]
+      docs = ["sphinx"]
+      "#
+    })
+    .warning(Message {
+      range: (5, 0, 5, 12),
+      text: "`project.optional-dependencies.Test_Utils` key `Test_Utils` should be normalized per PEP 685 (use `test-utils`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_require_normalized_names() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = ["Requests>=1.0"]
+      "#
+    })
+    .error(Message {
+      range: (4, 8, 4, 23),
+      text: "`project.optional-dependencies.test[0]` package name `Requests` must be normalized (use `requests`)",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_unused_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      legacy = ["six"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_unused_notes_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "Demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      cli = ["click"]
+      docs = ["sphinx"]
+      test = ["pytest"]
+      legacy = ["six", "demo[legacy]"]
+      all = ["demo[docs,test]"]
+
+      [project.scripts]
+      demo = "demo.cli:main [cli]"
+
+      [tool.pyproject.rules]
+      project-optional-dependencies-unused = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (8, 0, 8, 6),
+        text: "`project.optional-dependencies.legacy` is not referenced by any entry point or self-dependency in this file; remove it if it is no longer used",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (9, 0, 9, 3),
+        text: "`project.optional-dependencies.all` is not referenced by any entry point or self-dependency in this file; remove it if it is no longer used",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .warning(Message {
+      range: (12, 7, 12, 28),
+      text: "`project.scripts.demo` uses extras in entry point definitions; extras are deprecated for entry points and may be ignored by consumers",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_optional_dependencies_valid_configuration() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      [project.optional-dependencies]
+      test = ["pytest>=7.0.0", "pytest-cov"]
+      dev = ["black", "mypy>=1.0.0"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_people_placeholder_email_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      authors = [{ name = "Jane", email = "jane@example.com" }]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_people_placeholder_email_notes_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      authors = [{ name = "Jane", email = "jane@Example.COM" }]
+      maintainers = [
+        { email = "dev@company.io" },
+        { email = "root@localhost" },
+      ]
+
+      [tool.pyproject.rules]
+      project-people-placeholder-email = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (3, 36, 3, 54),
+        text: "`project.authors` email uses placeholder domain `example.com`; replace it with a real contact address",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (6, 12, 6, 28),
+        text: "`project.maintainers` email uses placeholder domain `localhost`; replace it with a real contact address",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_publish_readiness_accepts_complete_metadata() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      description = "A demo package"
+      dynamic = ["readme"]
+      license = "MIT"
+      authors = [{ name = "Demo" }]
+      urls = { "Source Code" = "https://example.com/demo" }
+
+      [tool.pyproject.rules]
+      project-publish-readiness = "warning"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_publish_readiness_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_publish_readiness_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      classifiers = ["License :: OSI Approved :: MIT License"]
+
+      [tool.pyproject.rules]
+      project-publish-readiness = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (0, 0, 0, 9),
+      text: "`[project]` should declare `project.authors` or `project.maintainers` before publishing to PyPI",
+    })
+    .warning(Message {
+      range: (0, 0, 0, 9),
+      text: "`[project]` should declare `project.description` before publishing to PyPI",
+    })
+    .warning(Message {
+      range: (0, 0, 0, 9),
+      text: "`[project]` should declare `project.readme` before publishing to PyPI",
+    })
+    .warning(Message {
+      range: (0, 0, 0, 9),
+      text: "`[project]` should declare a `Homepage` or `Repository` entry in `project.urls` before publishing to PyPI",
+    })
+    .warning(Message {
+      range: (3, 15, 3, 55),
+      text: "`project.classifiers` license classifiers are deprecated; use `project.license` instead",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_rejects_unknown_keys() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { text = "hi", content-type = "text/markdown", extra = "nope" }
+      "#
+    })
+    .error(Message {
+      range: (3, 56, 3, 61),
+      text: "`project.readme` only supports `file`, `text`, and `content-type` keys",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_string_must_point_to_existing_file() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = "README.md"
+      "#
+    })
+    .error(Message {
+      range: (3, 9, 3, 20),
+      text: "file `README.md` for `project.readme` does not exist",
+    })
+    .run();
+  }
+
+  #[test]
+  #[cfg(unix)]
+  fn project_readme_string_path_must_be_relative_unix() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = "/README.md"
+      "#
+    })
+    .error(Message {
+      range: (3, 9, 3, 21),
+      text: "file `/README.md` for `project.readme` does not exist",
+    })
+    .error(Message {
+      range: (3, 9, 3, 21),
+      text: "file path for `project.readme` must be relative",
+    })
+    .run();
+  }
+
+  #[test]
+  #[cfg(windows)]
+  fn project_readme_string_path_must_be_relative_windows() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = "/README.md"
+      "#
+    })
+    .error(Message {
+      range: (3, 9, 3, 21),
+      text: "file `/README.md` for `project.readme` does not exist",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_string_requires_known_extension() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = "README.txt"
+      "#
+    })
+    .write_file("README.txt", "# readme")
+    .error(Message {
+      range: (3, 9, 3, 21),
+      text: "`project.readme` must point to a `.md` or `.rst` file when specified as a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_accepts_text_plain() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { text = "inline", content-type = "text/plain" }
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (3, 43, 3, 55),
+        text: "`project.readme.content-type` is `text/plain`; consider `text/markdown` or `text/x-rst` for better rendering on package indexes",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_text_plain_suggests_markdown() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { text = "Demo\n\n## Usage\n", content-type = "text/plain" }
+      "#
+    })
+    .warning(Message {
+      range: (3, 55, 3, 67),
+      text: "`project.readme.content-type` is `text/plain`, but the readme looks like Markdown; use `text/markdown`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_text_plain_suggests_rst_for_file() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { file = "README.txt", content-type = "text/plain" }
+      "#
+    })
+    .write_file("README.txt", "Demo\n====\n\n.. note:: Hello.\n")
+    .warning(Message {
+      range: (3, 47, 3, 59),
+      text: "`project.readme.content-type` is `text/plain`, but the readme looks like reStructuredText; use `text/x-rst`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_file_must_exist() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { file = "README.md", content-type = "text/markdown" }
+      "#
+    })
+    .error(Message {
+      range: (3, 18, 3, 29),
+      text: "file `README.md` for `project.readme` does not exist",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_must_not_mix_file_and_text() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { file = "README.md", text = "inline", content-type = "text/markdown" }
+      "#
+    })
+    .write_file("README.md", "# readme")
+    .error(Message {
+      range: (3, 9, 3, 80),
+      text: "`project.readme` must specify only one of `file` or `text`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_requires_content_type() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { file = "README.md" }
+      "#
+    })
+    .write_file("README.md", "# readme")
+    .error(Message {
+      range: (3, 9, 3, 31),
+      text: "missing required key `project.readme.content-type`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_requires_file_or_text() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { content-type = "text/markdown" }
+      "#
+    })
+    .error(Message {
+      range: (3, 9, 3, 43),
+      text: "missing required key `project.readme.file` or `project.readme.text`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_requires_supported_content_type() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { file = "README.md", content-type = "text/html" }
+      "#
+    })
+    .write_file("README.md", "# readme")
+    .error(Message {
+      range: (3, 46, 3, 57),
+      text: "`project.readme.content-type` must be one of `text/markdown`, `text/x-rst`, or `text/plain`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_text_must_not_be_empty() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { text = "  ", content-type = "text/markdown" }
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 22),
+      text: "`project.readme.text` should not be empty; the project description would render blank on package indexes",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_readme_table_text_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      readme = { text = 1, content-type = "text/markdown" }
+      "#
+    })
+    .error(Message {
+      range: (3, 18, 3, 19),
+      text: "`project.readme.text` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_reports_unknown_keys() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      custom = "value"
+      "#
+    })
+    .error(Message {
+      range: (3, 0, 3, 6),
+      text: "`project.custom` is not defined by PEP 621; move custom settings under `[tool]` or another accepted PEP section",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_allows_dynamic() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dynamic = ["requires-python"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_allows_upper_bound_or_exact() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.10, <4"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_eol_accepts_supported_floor() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.10"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_eol_ignores_missing_lower_bound() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = "<4"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_eol_warns_on_end_of_life_floor() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.8"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_exclusions_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.8,!=3.9.*"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 33),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_exclusions_notes_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.8,!=3.9.*,!=3.10.1,!=3.11.0.*"
+
+      [tool.pyproject.rules]
+      project-requires-python-exclusions = "info"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 53),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .diagnostic(
+      Message {
+        range: (3, 18, 3, 53),
+        text: "`project.requires-python` excludes every Python 3.9 release with `!=3.9.*`; confirm this exclusion is intentional",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = 3.11
+      "#
+    })
+    .error(Message {
+      range: (3, 18, 3, 22),
+      text: "`project.requires-python` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_must_be_valid_pep_440() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = "=>3.12"
+      "#
+    })
+    .error(Message {
+      range: (3, 18, 3, 26),
+      text: "`project.requires-python` must be a valid PEP 440 version specifier: Failed to parse version: no such comparison operator \"=>\", must be one of ~= == != <= >= < > ===:\n=>3.12\n^^^^^^\n",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_must_not_be_empty() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ""
+      "#
+    })
+    .error(Message {
+      range: (3, 18, 3, 20),
+      text: "`project.requires-python` must not be empty",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_upper_bound_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.8"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_requires_python_warns_without_upper_bound_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.8"
+
+      [tool.pyproject.rules]
+      project-requires-python-bounds = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.8, which is end-of-life; consider raising the lower bound",
+    })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` does not specify an upper bound; consider adding one to avoid unsupported future Python versions",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_entries_must_be_strings() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      urls = { homepage = 123 }
+      "#
+    })
+    .error(Message {
+      range: (3, 20, 3, 23),
+      text: "`project.urls` entry `homepage` must be a string URL",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_entries_must_be_valid_urls() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      urls = { homepage = "example.com" }
+      "#
+    })
+    .error(Message {
+      range: (3, 20, 3, 33),
+      text: "`project.urls` entry `homepage` must be a valid URL: relative URL without a base",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_entries_must_not_be_empty() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      urls = { homepage = "" }
+      "#
+    })
+    .error(Message {
+      range: (3, 20, 3, 22),
+      text: "`project.urls` entry `homepage` must not be empty",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_entries_must_use_http_or_https() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      urls = { homepage = "ftp://example.com" }
+      "#
+    })
+    .error(Message {
+      range: (3, 20, 3, 39),
+      text: "`project.urls` entry `homepage` must use an `http` or `https` URL",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_entries_should_not_point_at_local_hosts() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.urls]
+      Homepage = "http://localhost:8000"
+      Docs = "http://192.168.1.20/docs"
+      Source = "https://[::1]/demo"
+      Tracker = "https://10.example.com/issues"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (5, 11, 5, 34),
+        text: "`project.urls` entry `Homepage` points at local host `localhost`, which is unreachable for anyone installing the package",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (6, 7, 6, 33),
+        text: "`project.urls` entry `Docs` points at local host `192.168.1.20`, which is unreachable for anyone installing the package",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .diagnostic(
+      Message {
+        range: (7, 9, 7, 29),
+        text: "`project.urls` entry `Source` points at local host `[::1]`, which is unreachable for anyone installing the package",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_urls_labels_must_not_exceed_limit() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.urls]
+      this-label-is-way-too-long-to-be-valid = "https://example.com"
+      "#
+    })
+    .error(Message {
+      range: (5, 0, 5, 38),
+      text: "`project.urls` label `this-label-is-way-too-long-to-be-valid` must be 32 characters or fewer",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_must_be_a_table() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      urls = "https://example.com"
+      "#
+    })
+    .error(Message {
+      range: (3, 7, 3, 28),
+      text: "`project.urls` must be a table of string URLs",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_reachable_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.urls]
+      Homepage = "https://example.invalid/"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_accepts_code_host_urls() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "MIT"
+
+      [project.urls]
+      Homepage = "https://github.com/demo/demo"
+
+      [tool.pyproject.rules]
+      project-urls-repository = "warning"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_accepts_repository_labels() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "Apache-2.0 OR MIT"
+      urls = { "Source Code" = "https://example.com/demo.git" }
+
+      [tool.pyproject.rules]
+      project-urls-repository = "warning"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_ignores_proprietary_licenses() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "LicenseRef-Proprietary"
+
+      [tool.pyproject.rules]
+      project-urls-repository = "warning"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (3, 10, 3, 34),
+        text: "`project.license` uses custom license `LicenseRef-Proprietary`; add its text to `project.license-files` so it ships with the distribution",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "MIT"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_warns_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "MIT"
+
+      [project.urls]
+      Documentation = "https://demo.readthedocs.io"
+
+      [tool.pyproject.rules]
+      project-urls-repository = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (5, 0, 5, 14),
+      text: "`project.license` `MIT` is open source, but `project.urls` has no repository link; add a `Source` or `Repository` URL so users can find the code",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_urls_repository_warns_on_project_without_urls() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "GPL-3.0-or-later"
+
+      [tool.pyproject.rules]
+      project-urls-repository = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (0, 0, 0, 9),
+      text: "`project.license` `GPL-3.0-or-later` is open source, but `project.urls` has no repository link; add a `Source` or `Repository` URL so users can find the code",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_version_epoch_is_opt_in() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1!2.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_version_epoch_notes_when_enabled() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1!2.0"
+
+      [tool.pyproject.rules]
+      project-version-epoch = "info"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (2, 10, 2, 17),
+        text: "`project.version` uses epoch `1`, which sorts it after every version without one; confirm the versioning scheme reset is intentional",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_version_is_reported_when_not_normalized() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0-1"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (2, 10, 2, 17),
+        text: "`project.version` is not normalized (use `1.0.post1`)",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn project_version_is_required_unless_dynamic() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      "#
+    })
+    .error(Message {
+      range: (0, 0, 0, 9),
+      text: "missing required key `project.version`",
+    })
+    .run();
+
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      dynamic = ["version"]
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_version_must_be_a_string() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = 1
+      "#
+    })
+    .error(Message {
+      range: (2, 10, 2, 11),
+      text: "`project.version` must be a string",
+    })
+    .run();
+  }
+
+  #[test]
+  fn project_version_must_be_pep_440_compliant() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "foo"
+      "#
+    })
+    .error(Message {
+      range: (2, 10, 2, 15),
+      text: "expected version to start with a number, but no leading ASCII digits were found",
     })
     .run();
   }
@@ -3117,6 +5833,10 @@ mod tests {
       project_urls = { Homepage = "" }
       "#
     })
+    .warning(Message {
+      range: (5, 0, 5, 12),
+      text: "`tool.setuptools.project_urls` is package metadata, which setuptools reads from `[project]`; move it to `project.urls`",
+    })
     .error(Message {
       range: (5, 0, 5, 32),
       text: "unknown setting `tool.setuptools.project_urls`",
@@ -3124,6 +5844,308 @@ mod tests {
     .run();
   }
 
+  #[test]
+  fn suppression_comment_ignores_all_rules_on_line() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "Foo!Bar" # pyproject: ignore
+      version = "1.0.0"
+      "#
+    })
+    .run();
+  }
+
+  #[test]
+  fn suppression_comment_ignores_matching_rule() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "Foo!Bar" # pyproject: ignore project-name
+      version = "foo" # pyproject: ignore project-name
+      "#
+    })
+    .error(Message {
+      range: (2, 10, 2, 15),
+      text: "expected version to start with a number, but no leading ASCII digits were found",
+    })
+    .diagnostic(
+      Message {
+        range: (2, 16, 2, 48),
+        text: "unused `pyproject: ignore` for rule `project-name`",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn suppression_comment_reports_unused_blanket_ignore() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo" # pyproject: ignore
+      version = "1.0.0"
+      "#
+    })
+    .diagnostic(
+      Message {
+        range: (1, 14, 1, 33),
+        text: "unused `pyproject: ignore` comment",
+      },
+      lsp::DiagnosticSeverity::INFORMATION,
+    )
+    .run();
+  }
+
+  #[test]
+  fn suppression_comment_unused_ignore_is_configurable() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo" # pyproject: ignore
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      unused-ignore = "off"
+      "#
+    })
+    .run();
+
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo" # pyproject: ignore
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      unused-ignore = "warning"
+      "#
+    })
+    .warning(Message {
+      range: (1, 14, 1, 33),
+      text: "unused `pyproject: ignore` comment",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_poetry_python_accepts_matching_constraint() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.9,<4"
+
+      [tool.poetry.dependencies]
+      python = "^3.9"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 28),
+      text: "`project.requires-python` allows Python 3.9, which is end-of-life; consider raising the lower bound",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_poetry_python_must_match_requires_python() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      requires-python = ">=3.9"
+
+      [tool.poetry.dependencies]
+      python = "^3.8"
+      "#
+    })
+    .warning(Message {
+      range: (3, 18, 3, 25),
+      text: "`project.requires-python` allows Python 3.9, which is end-of-life; consider raising the lower bound",
+    })
+    .warning(Message {
+      range: (6, 9, 6, 15),
+      text: "`tool.poetry.dependencies.python` `^3.8` disagrees with `project.requires-python` `>=3.9`; Python 3.8 is allowed by Poetry but not by `requires-python`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_pyproject_rules_must_use_known_settings() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules.project-dependencies-count]
+      levle = "warning"
+      max = 2
+      options = { max = 2 }
+      "#
+    })
+    .warning(Message {
+      range: (5, 0, 5, 5),
+      text: "`tool.pyproject.rules.project-dependencies-count` has unknown setting `levle` (did you mean `level`?)",
+    })
+    .warning(Message {
+      range: (6, 0, 6, 3),
+      text: "`tool.pyproject.rules.project-dependencies-count` has unknown setting `max`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_pyproject_rules_must_reference_known_rules() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-nam = "off"
+      no-such-rule-at-all = "warning"
+      project-name = "error"
+      "#
+    })
+    .warning(Message {
+      range: (5, 0, 5, 11),
+      text: "`tool.pyproject.rules` references unknown rule `project-nam` (did you mean `project-name`?)",
+    })
+    .warning(Message {
+      range: (6, 0, 6, 19),
+      text: "`tool.pyproject.rules` references unknown rule `no-such-rule-at-all`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_pytest_testpaths_must_exist() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.pytest.ini_options]
+      testpaths = ["tests", "integration", "src/*/tests"]
+      "#
+    })
+    .write_file("tests/test_demo.py", "")
+    .warning(Message {
+      range: (5, 22, 5, 35),
+      text: "`tool.pytest.ini_options.testpaths` entry `integration` does not exist; pytest will not collect any tests from it",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_setuptools_metadata_recommends_project_fields() {
+    Test::new(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.setuptools]
+      install-requires = ["requests"]
+      python_requires = ">=3.9"
+      zip-safe = false
+      "#
+    })
+    .warning(Message {
+      range: (5, 0, 5, 16),
+      text: "`tool.setuptools.install-requires` is package metadata, which setuptools reads from `[project]`; move it to `project.dependencies`",
+    })
+    .error(Message {
+      range: (5, 0, 5, 31),
+      text: "unknown setting `tool.setuptools.install-requires`",
+    })
+    .warning(Message {
+      range: (6, 0, 6, 15),
+      text: "`tool.setuptools.python_requires` is package metadata, which setuptools reads from `[project]`; move it to `project.requires-python`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_setuptools_src_layout_accepts_package_dir() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools>=61"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.setuptools]
+      packages = ["demo"]
+      package-dir = { "" = "src" }
+      "#
+    })
+    .write_file("src/demo/__init__.py", "")
+    .run();
+  }
+
+  #[test]
+  fn tool_setuptools_src_layout_requires_find_where() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools>=61"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.setuptools.packages.find]
+      include = ["demo*"]
+      "#
+    })
+    .write_file("src/demo/__init__.py", "")
+    .warning(Message {
+      range: (8, 0, 8, 31),
+      text: "`tool.setuptools.packages.find` searches the project root, but packages live under `src/`; set `where = [\"src\"]`",
+    })
+    .run();
+  }
+
+  #[test]
+  fn tool_setuptools_src_layout_requires_package_dir() {
+    Test::with_tempdir(indoc! {
+      r#"
+      [build-system]
+      requires = ["setuptools>=61"]
+      build-backend = "setuptools.build_meta"
+
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [tool.setuptools]
+      packages = ["demo", "demo.cli"]
+      "#
+    })
+    .write_file("src/demo/__init__.py", "")
+    .write_file("src/demo/cli/__init__.py", "")
+    .warning(Message {
+      range: (9, 12, 9, 18),
+      text: "`tool.setuptools.packages` entry `demo` only exists under `src/`; set `tool.setuptools.package-dir = {\"\" = \"src\"}` or remove `packages` to use auto-discovery",
+    })
+    .warning(Message {
+      range: (9, 20, 9, 30),
+      text: "`tool.setuptools.packages` entry `demo.cli` only exists under `src/`; set `tool.setuptools.package-dir = {\"\" = \"src\"}` or remove `packages` to use auto-discovery",
+    })
+    .run();
+  }
+
   #[test]
   fn unexpected_entry() {
     Test::new(indoc! {
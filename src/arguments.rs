@@ -11,12 +11,28 @@ use super::*;
   help_template = "{bin} {version}\n\n{usage-heading} {usage}\n\n{all-args}{after-help}"
 )]
 pub(crate) struct Arguments {
+  #[arg(
+    long,
+    global = true,
+    value_enum,
+    default_value_t,
+    help = "When to color output, respecting `NO_COLOR` by default"
+  )]
+  color: ColorMode,
   #[clap(subcommand)]
   subcommand: Subcommand,
 }
 
 impl Arguments {
   pub(crate) async fn run(self) -> Result {
-    self.subcommand.run().await
+    let color = self.color.enabled();
+
+    if color {
+      yansi::enable();
+    } else {
+      yansi::disable();
+    }
+
+    self.subcommand.run(color).await
   }
 }
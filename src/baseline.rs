@@ -0,0 +1,135 @@
+use super::*;
+
+/// Diagnostics recorded by `pyproject check --write-baseline`.
+///
+/// Entries are keyed on the rule id, the normalized message, and the source
+/// text the diagnostic points at rather than its range, so that edits which
+/// only shift lines around don't invalidate the baseline.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct Baseline {
+  diagnostics: Vec<Entry>,
+}
+
+#[derive(
+  Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize,
+)]
+struct Entry {
+  context: String,
+  id: String,
+  message: String,
+  path: String,
+}
+
+impl Baseline {
+  fn entry(path: &str, document: &Document, diagnostic: &Diagnostic) -> Entry {
+    let content = &document.content;
+
+    let start = content
+      .lsp_position_to_char(diagnostic.range.start)
+      .min(content.len_chars());
+
+    let end = content
+      .lsp_position_to_char(diagnostic.range.end)
+      .min(content.len_chars());
+
+    let context = if start < end {
+      content.slice(start..end).to_string()
+    } else {
+      content
+        .get_line(diagnostic.range.start.line as usize)
+        .map(|line| line.to_string())
+        .unwrap_or_default()
+    };
+
+    Entry {
+      context: Self::normalize(&context),
+      id: diagnostic.id.trim().to_string(),
+      message: Self::normalize(&diagnostic.message),
+      path: path.to_string(),
+    }
+  }
+
+  /// Removes diagnostics that are recorded in the baseline, matching each
+  /// recorded entry at most once.
+  pub(crate) fn filter(
+    &self,
+    path: &str,
+    document: &Document,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
+    let mut remaining = BTreeMap::<&Entry, usize>::new();
+
+    for entry in &self.diagnostics {
+      *remaining.entry(entry).or_default() += 1;
+    }
+
+    diagnostics.retain(|diagnostic| {
+      let entry = Self::entry(path, document, diagnostic);
+
+      match remaining.get_mut(&entry) {
+        Some(count) if *count > 0 => {
+          *count -= 1;
+          false
+        }
+        _ => true,
+      }
+    });
+  }
+
+  pub(crate) fn load(path: &Path) -> Result<Self> {
+    let content = fs::read_to_string(path).map_err(|error| {
+      anyhow!("failed to read baseline `{}`: {error}", path.display())
+    })?;
+
+    serde_json::from_str(&content).map_err(|error| {
+      anyhow!("failed to parse baseline `{}`: {error}", path.display())
+    })
+  }
+
+  fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+  }
+
+  pub(crate) fn record(
+    &mut self,
+    path: &str,
+    document: &Document,
+    diagnostics: &[Diagnostic],
+  ) {
+    self.diagnostics.extend(
+      diagnostics
+        .iter()
+        .map(|diagnostic| Self::entry(path, document, diagnostic)),
+    );
+
+    self.diagnostics.sort();
+  }
+
+  pub(crate) fn save(&self, path: &Path) -> Result {
+    let mut json = serde_json::to_string_pretty(self)?;
+
+    json.push('\n');
+
+    fs::write(path, json).map_err(|error| {
+      anyhow!("failed to write baseline `{}`: {error}", path.display())
+    })
+  }
+
+  /// Returns the path of `document` relative to the directory containing the
+  /// baseline, so that baselines can be checked into version control.
+  pub(crate) fn source(baseline: &Path, document: &Path) -> Result<String> {
+    let current_dir = env::current_dir()?;
+
+    let directory = current_dir
+      .join(baseline)
+      .parent()
+      .map(Path::to_path_buf)
+      .unwrap_or(current_dir.clone());
+
+    let document = current_dir.join(document);
+
+    let relative = document.strip_prefix(&directory).unwrap_or(&document);
+
+    Ok(relative.to_string_lossy().replace('\\', "/"))
+  }
+}
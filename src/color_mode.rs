@@ -0,0 +1,23 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub(crate) enum ColorMode {
+  Always,
+  #[default]
+  Auto,
+  Never,
+}
+
+impl ColorMode {
+  /// Whether output should be colored, honoring `NO_COLOR` and only coloring
+  /// terminals when set to `auto`.
+  pub(crate) fn enabled(self) -> bool {
+    match self {
+      Self::Always => true,
+      Self::Auto => {
+        env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+      }
+      Self::Never => false,
+    }
+  }
+}
@@ -1,16 +1,89 @@
 use super::*;
 
 #[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct Config {
+  #[serde(default)]
+  pub license_policy: LicensePolicy,
   #[serde(default)]
   pub rules: HashMap<String, RuleConfig>,
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LicenseIntent {
+  Copyleft,
+  Permissive,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct LicensePolicy {
+  /// License identifiers the project is permitted to use.
+  #[serde(default)]
+  pub allow: Vec<String>,
+  /// License identifiers the project must not use.
+  #[serde(default)]
+  pub deny: Vec<String>,
+  /// Whether the project is intended to be permissively or copyleft licensed.
+  #[serde(default)]
+  pub intent: Option<LicenseIntent>,
+}
+
 impl Config {
+  const STANDALONE_FILES: [&'static str; 2] =
+    ["pyproject-lint.toml", ".pyproject-lint.toml"];
+
+  /// Loads the configuration for the document at `uri`, layering its
+  /// `[tool.pyproject]` table over a sibling standalone configuration file.
+  #[must_use]
+  pub fn load(tree: &Parse, uri: &lsp::Url) -> Self {
+    let config = Self::from(tree);
+
+    match Self::standalone(uri) {
+      Some(standalone) => config.merge(standalone),
+      None => config,
+    }
+  }
+
+  pub(crate) fn merge(mut self, fallback: Self) -> Self {
+    for (id, rule_config) in fallback.rules {
+      self.rules.entry(id).or_insert(rule_config);
+    }
+
+    if self.license_policy.allow.is_empty() {
+      self.license_policy.allow = fallback.license_policy.allow;
+    }
+
+    if self.license_policy.deny.is_empty() {
+      self.license_policy.deny = fallback.license_policy.deny;
+    }
+
+    self.license_policy.intent = self
+      .license_policy
+      .intent
+      .or(fallback.license_policy.intent);
+
+    self
+  }
+
   #[must_use]
   pub fn rule_config(&self, id: &str) -> RuleConfig {
     self.rules.get(id).cloned().unwrap_or_default()
   }
+
+  /// Reads the `pyproject-lint.toml` or `.pyproject-lint.toml` next to the
+  /// document at `uri`, whose top-level tables mirror `[tool.pyproject]`.
+  pub(crate) fn standalone(uri: &lsp::Url) -> Option<Self> {
+    let path = uri.to_file_path().ok()?;
+
+    let directory = path.parent()?;
+
+    Self::STANDALONE_FILES.iter().find_map(|name| {
+      let content = fs::read_to_string(directory.join(name)).ok()?;
+
+      Some(Self::from(parse(&content).into_dom()))
+    })
+  }
 }
 
 impl From<Node> for Config {
@@ -41,6 +114,64 @@ impl From<&Parse> for Config {
   }
 }
 
+impl LicensePolicy {
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.allow.is_empty() && self.deny.is_empty()
+  }
+
+  fn name(license: &spdx::LicenseReq) -> String {
+    match license.license.id() {
+      Some(id) => id.name.to_string(),
+      None => license.license.to_string(),
+    }
+  }
+
+  fn violation(&self, license: &str) -> Option<String> {
+    let matches = |entries: &[String]| {
+      entries
+        .iter()
+        .any(|entry| entry.eq_ignore_ascii_case(license))
+    };
+
+    if matches(&self.deny) {
+      return Some(format!(
+        "`project.license` uses license `{license}`, which is denied by `tool.pyproject.license-policy`"
+      ));
+    }
+
+    if !self.allow.is_empty() && !matches(&self.allow) {
+      return Some(format!(
+        "`project.license` uses license `{license}`, which is not allowed by `tool.pyproject.license-policy`"
+      ));
+    }
+
+    None
+  }
+
+  /// Returns a message for each license in `expression` that the policy
+  /// rejects, or nothing when the expression can be satisfied using only
+  /// permitted licenses, so `MIT OR GPL-3.0-only` passes an allow-list
+  /// containing `MIT`.
+  #[must_use]
+  pub fn violations(&self, expression: &spdx::Expression) -> Vec<String> {
+    if expression
+      .evaluate(|license| self.violation(&Self::name(license)).is_none())
+    {
+      return Vec::new();
+    }
+
+    let mut seen = HashSet::new();
+
+    expression
+      .requirements()
+      .map(|requirement| Self::name(&requirement.req))
+      .filter(|name| seen.insert(name.clone()))
+      .filter_map(|name| self.violation(&name))
+      .collect()
+  }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum RuleLevel {
@@ -70,6 +201,8 @@ pub enum RuleConfig {
   Settings {
     #[serde(default)]
     level: Option<RuleLevel>,
+    #[serde(default)]
+    options: Map<String, Value>,
   },
 }
 
@@ -78,7 +211,15 @@ impl RuleConfig {
   pub fn level(&self) -> Option<RuleLevel> {
     match self {
       RuleConfig::Level(level) => Some(*level),
-      RuleConfig::Settings { level } => *level,
+      RuleConfig::Settings { level, .. } => *level,
+    }
+  }
+
+  #[must_use]
+  pub fn option(&self, key: &str) -> Option<&Value> {
+    match self {
+      RuleConfig::Level(_) => None,
+      RuleConfig::Settings { options, .. } => options.get(key),
     }
   }
 
@@ -98,7 +239,10 @@ impl RuleConfig {
 
 impl Default for RuleConfig {
   fn default() -> Self {
-    Self::Settings { level: None }
+    Self::Settings {
+      level: None,
+      options: Map::new(),
+    }
   }
 }
 
@@ -106,6 +250,35 @@ impl Default for RuleConfig {
 mod tests {
   use super::*;
 
+  #[test]
+  fn parses_license_policy() {
+    let config: Config = serde_json::from_value(json!({
+      "license-policy": {
+        "allow": ["MIT", "Apache-2.0"],
+        "deny": ["GPL-3.0-only"]
+      }
+    }))
+    .unwrap();
+
+    assert_eq!(config.license_policy.allow, vec!["MIT", "Apache-2.0"]);
+    assert_eq!(config.license_policy.deny, vec!["GPL-3.0-only"]);
+  }
+
+  #[test]
+  fn parses_license_policy_intent() {
+    let config: Config = serde_json::from_value(json!({
+      "license-policy": {
+        "intent": "permissive"
+      }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      config.license_policy.intent,
+      Some(LicenseIntent::Permissive)
+    );
+  }
+
   #[test]
   fn parses_rule_config_from_string() {
     let config: Config = serde_json::from_value(json!({
@@ -129,4 +302,36 @@ mod tests {
 
     assert_eq!(config.rule_config("demo").level(), Some(RuleLevel::Hint));
   }
+
+  #[test]
+  fn parses_rule_config_options() {
+    let config: Config = serde_json::from_value(json!({
+      "rules": {
+        "demo": { "level": "warning", "options": { "max": 5 } }
+      }
+    }))
+    .unwrap();
+
+    let rule_config = config.rule_config("demo");
+
+    assert_eq!(rule_config.level(), Some(RuleLevel::Warning));
+    assert_eq!(rule_config.option("max"), Some(&json!(5)));
+    assert_eq!(rule_config.option("min"), None);
+  }
+
+  #[test]
+  fn unknown_rule_settings_keep_other_configuration() {
+    let config: Config = serde_json::from_value(json!({
+      "license-policy": { "deny": ["GPL-3.0-only"] },
+      "rules": {
+        "demo": { "levle": "warning" },
+        "other": "error"
+      }
+    }))
+    .unwrap();
+
+    assert_eq!(config.rule_config("demo").level(), None);
+    assert_eq!(config.rule_config("other").level(), Some(RuleLevel::Error));
+    assert_eq!(config.license_policy.deny, vec!["GPL-3.0-only"]);
+  }
 }
@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Diagnostic {
   /// A short header summarizing the diagnostic.
   pub display: String,
@@ -12,6 +12,8 @@ pub struct Diagnostic {
   pub quickfix: Option<Quickfix>,
   /// The range in the source code where the diagnostic applies.
   pub range: lsp::Range,
+  /// Other locations that help explain the diagnostic.
+  pub related: Vec<lsp::DiagnosticRelatedInformation>,
   /// The severity level of the diagnostic.
   pub severity: lsp::DiagnosticSeverity,
 }
@@ -32,6 +34,7 @@ impl Diagnostic {
       message: message.into(),
       quickfix: None,
       range,
+      related: Vec::new(),
       severity,
     }
   }
@@ -44,6 +47,25 @@ impl Diagnostic {
     }
   }
 
+  #[must_use]
+  pub fn related(
+    self,
+    location: lsp::Location,
+    message: impl Into<String>,
+  ) -> Self {
+    Self {
+      related: self
+        .related
+        .into_iter()
+        .chain(iter::once(lsp::DiagnosticRelatedInformation {
+          location,
+          message: message.into(),
+        }))
+        .collect(),
+      ..self
+    }
+  }
+
   pub fn warning(message: impl Into<String>, range: lsp::Range) -> Self {
     Self::new(message, range, lsp::DiagnosticSeverity::WARNING)
   }
@@ -61,6 +83,8 @@ impl From<&Diagnostic> for lsp::Diagnostic {
       code: Some(lsp::NumberOrString::String(value.id.clone())),
       message: value.message.clone(),
       range: value.range,
+      related_information: (!value.related.is_empty())
+        .then(|| value.related.clone()),
       severity: Some(value.severity),
       source: Some("pyproject".to_string()),
       ..Default::default()
@@ -1,13 +1,16 @@
 use super::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Document {
+  pub bom: bool,
   pub config: Config,
   pub content: Rope,
   pub diagnostics: Vec<Diagnostic>,
+  standalone: Option<Config>,
   pub tree: Parse,
   pub uri: lsp::Url,
   pub version: i32,
+  workspace: Option<Config>,
 }
 
 impl Document {
@@ -24,17 +27,81 @@ impl Document {
 
     self.version = version;
 
+    if content_changes.is_empty() {
+      return;
+    }
+
     for change in content_changes {
       self.content.apply_edit(&self.content.build_edit(&change));
     }
 
-    self.tree = parse(&self.content.to_string());
+    self.bom = self.content.get_char(0) == Some(Self::BOM);
 
-    self.config = Config::from(&self.tree);
+    // taplo can't reparse incrementally, and syntax tree offsets are
+    // absolute, so the whole document is reparsed once per batch of changes,
+    // borrowing the rope's text rather than copying it when it is contiguous.
+    self.tree = Self::parse_tree(&self.content.text());
+
+    self.configure();
 
     self.diagnostics.clear();
   }
 
+  /// Rereads the configuration files this document draws on: a sibling
+  /// standalone configuration file, and the enclosing workspace root's
+  /// `pyproject.toml`, which applies when the document declares neither
+  /// `[tool.pyproject]` nor a standalone file. Edits reuse the files read
+  /// here, so this only needs to run when the document is opened or saved,
+  /// or when a watched configuration file changes.
+  pub fn apply_workspace_config(&mut self, workspace_roots: &[PathBuf]) {
+    self.standalone = Config::standalone(&self.uri);
+
+    self.workspace = self.uri.to_file_path().ok().and_then(|path| {
+      let workspace_root = workspace_roots
+        .iter()
+        .filter(|workspace_root| path.starts_with(workspace_root))
+        .max_by_key(|workspace_root| workspace_root.components().count())?;
+
+      let workspace_pyproject = workspace_root.join("pyproject.toml");
+
+      if workspace_pyproject == path {
+        return None;
+      }
+
+      let content = fs::read_to_string(&workspace_pyproject).ok()?;
+
+      let workspace_uri =
+        lsp::Url::from_file_path(&workspace_pyproject).ok()?;
+
+      Some(Config::load(&parse(&content), &workspace_uri))
+    });
+
+    self.configure();
+  }
+
+  /// Rebuilds `config` from the document's `[tool.pyproject]` table and the
+  /// configuration files last read by `apply_workspace_config`, without
+  /// touching the disk.
+  fn configure(&mut self) {
+    let config = Config::from(&self.tree);
+
+    self.config = match (&self.standalone, &self.workspace) {
+      (Some(standalone), _) => config.merge(standalone.clone()),
+      (None, Some(workspace))
+        if self
+          .tree
+          .clone()
+          .into_dom()
+          .try_get("tool")
+          .and_then(|tool| tool.try_get("pyproject"))
+          .is_err() =>
+      {
+        workspace.clone()
+      }
+      _ => config,
+    };
+  }
+
   #[must_use]
   pub fn resolve_path(&self, path: &str) -> Option<PathBuf> {
     let Ok(mut document_path) = self.uri.to_file_path() else {
@@ -116,17 +183,37 @@ impl Document {
 }
 
 impl Document {
+  const BOM: char = '\u{feff}';
+
   #[must_use]
   pub fn new(source: &str, uri: lsp::Url) -> Self {
-    let tree = parse(source);
-
-    Self {
-      config: Config::from(&tree),
+    let mut document = Self {
+      bom: source.starts_with(Self::BOM),
+      config: Config::default(),
       content: Rope::from_str(source),
       diagnostics: Vec::new(),
-      tree,
+      standalone: Config::standalone(&uri),
+      tree: Self::parse_tree(source),
       uri,
       version: 0,
+      workspace: None,
+    };
+
+    document.configure();
+
+    document
+  }
+
+  /// Parses `text`, blanking out a leading UTF-8 byte order mark with spaces
+  /// of the same encoded length. The mark stays in the stored content, since
+  /// clients count it when addressing line 0, and the spaces keep parser
+  /// offsets lined up with it.
+  fn parse_tree(text: &str) -> Parse {
+    match text.strip_prefix(Self::BOM) {
+      Some(rest) => {
+        parse(&format!("{}{rest}", " ".repeat(Self::BOM.len_utf8())))
+      }
+      None => parse(text),
     }
   }
 }
@@ -137,31 +224,39 @@ impl From<lsp::DidOpenTextDocumentParams> for Document {
       text, uri, version, ..
     } = params.text_document;
 
-    let tree = parse(&text);
-
-    Self {
-      config: Config::from(&tree),
+    let mut document = Self {
+      bom: text.starts_with(Document::BOM),
+      config: Config::default(),
       content: Rope::from_str(&text),
       diagnostics: Vec::new(),
-      tree,
+      standalone: Config::standalone(&uri),
+      tree: Document::parse_tree(&text),
       uri,
       version,
-    }
+      workspace: None,
+    };
+
+    document.configure();
+
+    document
   }
 }
 
 impl From<&str> for Document {
   fn from(value: &str) -> Self {
-    let tree = parse(value);
+    let tree = Document::parse_tree(value);
 
     Self {
+      bom: value.starts_with(Document::BOM),
       config: Config::from(&tree),
       content: Rope::from_str(value),
       diagnostics: Vec::new(),
+      standalone: None,
       tree,
       uri: lsp::Url::from_file_path(env::temp_dir().join("pyproject.toml"))
         .unwrap(),
       version: 1,
+      workspace: None,
     }
   }
 }
@@ -172,12 +267,15 @@ impl From<lsp::Url> for Document {
     let tree = parse("");
 
     Self {
+      bom: false,
       config: Config::from(&tree),
       content: Rope::from_str(""),
       diagnostics: Vec::new(),
+      standalone: None,
       tree,
       uri: value,
       version: 1,
+      workspace: None,
     }
   }
 }
@@ -236,6 +334,31 @@ mod tests {
     );
   }
 
+  #[test]
+  fn apply_change_preserves_bom() {
+    let mut document = Document::from("[project]\n");
+
+    assert!(!document.bom);
+
+    document.apply_change(lsp::DidChangeTextDocumentParams {
+      text_document: lsp::VersionedTextDocumentIdentifier {
+        uri: lsp::Url::parse("file:///pyproject.toml").unwrap(),
+        version: 2,
+      },
+      content_changes: vec![lsp::TextDocumentContentChangeEvent {
+        range: None,
+        range_length: None,
+        text: "\u{feff}[project]\n".to_string(),
+      }],
+    });
+
+    assert!(document.bom);
+
+    assert_eq!(document.content.to_string(), "\u{feff}[project]\n");
+
+    assert!(document.tree.clone().into_dom().try_get("project").is_ok());
+  }
+
   #[test]
   #[cfg(unix)]
   fn resolve_path_relative() {
@@ -365,6 +488,44 @@ mod tests {
     assert_eq!(document.root().unwrap(), PathBuf::from("/"));
   }
 
+  #[test]
+  fn loads_standalone_configuration() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join(".pyproject-lint.toml"),
+      indoc! {
+        r#"
+        [rules]
+        project-name = "off"
+        project-version = "off"
+
+        [license-policy]
+        deny = ["AGPL-3.0-only"]
+        "#
+      },
+    )
+    .unwrap();
+
+    let uri =
+      lsp::Url::from_file_path(tempdir.path().join("pyproject.toml")).unwrap();
+
+    let document =
+      Document::new("[tool.pyproject.rules]\nproject-name = \"hint\"\n", uri);
+
+    assert_eq!(
+      document.config.rule_config("project-name").level(),
+      Some(crate::config::RuleLevel::Hint)
+    );
+
+    assert_eq!(
+      document.config.rule_config("project-version").level(),
+      Some(crate::config::RuleLevel::Off)
+    );
+
+    assert_eq!(document.config.license_policy.deny, vec!["AGPL-3.0-only"]);
+  }
+
   #[test]
   fn parses_tool_configuration() {
     let document = Document::from(indoc! {
@@ -384,6 +545,116 @@ mod tests {
     );
   }
 
+  #[test]
+  fn apply_change_reuses_loaded_configuration() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    let uri =
+      lsp::Url::from_file_path(tempdir.path().join("pyproject.toml")).unwrap();
+
+    let mut document = Document::new("[project]\n", uri.clone());
+
+    fs::write(
+      tempdir.path().join("pyproject-lint.toml"),
+      "[rules]\nproject-name = \"off\"\n",
+    )
+    .unwrap();
+
+    document.apply_change(lsp::DidChangeTextDocumentParams {
+      text_document: lsp::VersionedTextDocumentIdentifier { uri, version: 2 },
+      content_changes: vec![lsp::TextDocumentContentChangeEvent {
+        range: None,
+        range_length: None,
+        text: "[project]\nname = \"foo\"\n\n[tool.pyproject.rules]\nproject-version = \"off\"\n"
+          .to_string(),
+      }],
+    });
+
+    assert_eq!(document.config.rule_config("project-name").level(), None);
+
+    assert_eq!(
+      document.config.rule_config("project-version").level(),
+      Some(crate::config::RuleLevel::Off)
+    );
+
+    document.apply_workspace_config(&[]);
+
+    assert_eq!(
+      document.config.rule_config("project-name").level(),
+      Some(crate::config::RuleLevel::Off)
+    );
+  }
+
+  #[test]
+  fn apply_workspace_config_inherits_root_configuration() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join("pyproject.toml"),
+      "[tool.pyproject.rules]\nproject-name = \"off\"\n",
+    )
+    .unwrap();
+
+    let uri = lsp::Url::from_file_path(
+      tempdir.path().join("packages/foo/pyproject.toml"),
+    )
+    .unwrap();
+
+    let mut inherited =
+      Document::new("[project]\nname = \"foo\"\n", uri.clone());
+
+    inherited.apply_workspace_config(&[tempdir.path().to_path_buf()]);
+
+    assert_eq!(
+      inherited.config.rule_config("project-name").level(),
+      Some(crate::config::RuleLevel::Off)
+    );
+
+    let mut local =
+      Document::new("[tool.pyproject.rules]\nproject-name = \"hint\"\n", uri);
+
+    local.apply_workspace_config(&[tempdir.path().to_path_buf()]);
+
+    assert_eq!(
+      local.config.rule_config("project-name").level(),
+      Some(crate::config::RuleLevel::Hint)
+    );
+  }
+
+  #[test]
+  fn apply_workspace_config_prefers_standalone_configuration() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join("pyproject.toml"),
+      "[tool.pyproject.rules]\nproject-name = \"off\"\n",
+    )
+    .unwrap();
+
+    let package = tempdir.path().join("packages/foo");
+
+    fs::create_dir_all(&package).unwrap();
+
+    fs::write(
+      package.join("pyproject-lint.toml"),
+      "[rules]\nproject-version = \"off\"\n",
+    )
+    .unwrap();
+
+    let uri = lsp::Url::from_file_path(package.join("pyproject.toml")).unwrap();
+
+    let mut document = Document::new("[project]\nname = \"foo\"\n", uri);
+
+    document.apply_workspace_config(&[tempdir.path().to_path_buf()]);
+
+    assert_eq!(document.config.rule_config("project-name").level(), None);
+
+    assert_eq!(
+      document.config.rule_config("project-version").level(),
+      Some(crate::config::RuleLevel::Off)
+    );
+  }
+
   #[test]
   #[cfg(windows)]
   fn root_windows() {
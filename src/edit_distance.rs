@@ -0,0 +1,48 @@
+/// Returns the optimal string alignment distance between `a` and `b`: the
+/// number of insertions, deletions, substitutions, and transpositions of
+/// adjacent characters needed to turn one into the other.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+  let (a, b) = (a.chars().collect::<Vec<_>>(), b.chars().collect::<Vec<_>>());
+
+  let mut before = Vec::new();
+
+  let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+  for i in 1..=a.len() {
+    let mut current = vec![i];
+
+    for j in 1..=b.len() {
+      let cost = usize::from(a[i - 1] != b[j - 1]);
+
+      let mut distance = (previous[j - 1] + cost)
+        .min(previous[j] + 1)
+        .min(current[j - 1] + 1);
+
+      if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+        distance = distance.min(before[j - 2] + 1);
+      }
+
+      current.push(distance);
+    }
+
+    before = std::mem::replace(&mut previous, current);
+  }
+
+  previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn distance() {
+    assert_eq!(edit_distance("", ""), 0);
+    assert_eq!(edit_distance("redis", "redis"), 0);
+    assert_eq!(edit_distance("reddis", "redis"), 1);
+    assert_eq!(edit_distance("project-nam", "project-name"), 1);
+    assert_eq!(edit_distance("kitten", "sitting"), 3);
+    assert_eq!(edit_distance("reqeusts", "requests"), 1);
+    assert_eq!(edit_distance("ab", "ba"), 1);
+  }
+}
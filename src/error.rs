@@ -7,6 +7,10 @@ pub enum Error {
   },
   #[error(transparent)]
   Io(#[from] std::io::Error),
+  #[error("document already has a `[project]` table")]
+  MigrateExistingProject,
+  #[error("document has no `[tool.poetry]` table to migrate")]
+  MigrateMissingPoetry,
   #[error("no releases found for `{package}`")]
   NoPyPiReleases { package: String },
   #[error("failed to parse PyPI response: {source}")]
@@ -1,4 +1,5 @@
 use {
+  edit_distance::edit_distance,
   globwalk::GlobWalkerBuilder,
   indoc::indoc,
   jsonschema::{
@@ -8,8 +9,10 @@ use {
   log::{debug, warn},
   mailparse::{MailAddr, addrparse},
   pep440_rs::{Operator, Version, VersionSpecifiers},
-  pep508_rs::{ExtraName, PackageName, Requirement, VerbatimUrl, VersionOrUrl},
-  pypi_client::PyPiClient,
+  pep508_rs::{
+    ExtraName, MarkerTree, PackageName, Requirement, VerbatimUrl, VersionOrUrl,
+  },
+  pypi_client::{PyPiClient, Release},
   rayon::prelude::*,
   re::PROJECT_NAME,
   regex::Regex,
@@ -22,40 +25,46 @@ use {
   schema_pointer::SchemaPointer,
   schema_store::SchemaStore,
   schemas::SCHEMAS,
-  serde::Deserialize,
+  serde::{Deserialize, Serialize, de::DeserializeOwned},
   serde_json::{Map, Value, json},
   std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     env,
     fmt::{self, Display, Formatter},
     fs, iter,
+    net::IpAddr,
     path::{Path, PathBuf},
     str::FromStr,
     sync::{LazyLock, Mutex, OnceLock},
-    time::Duration,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
   },
+  suppression::Suppression,
   taplo::{
     dom::{
       KeyOrIndex, Node,
       error::Error as SemanticError,
-      node::{Key, TableKind},
+      node::{DomNode, Key, TableKind},
     },
     parser::{Parse, parse},
-    syntax::SyntaxElement,
+    rowan::Direction,
+    syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken},
   },
   text_size::TextSize,
   tower_lsp::lsp_types as lsp,
+  url_client::UrlClient,
 };
 
 pub use {
   analyzer::Analyzer,
   builtin::Builtin,
   builtins::BUILTINS,
-  config::{Config, RuleConfig, RuleLevel},
+  config::{Config, LicenseIntent, LicensePolicy, RuleConfig, RuleLevel},
   dependency::Dependency,
   diagnostic::Diagnostic,
   document::Document,
   error::Error,
+  migrator::Migrator,
   quickfix::Quickfix,
   quickfixer::Quickfixer,
   resolver::Resolver,
@@ -75,8 +84,10 @@ mod config;
 mod dependency;
 mod diagnostic;
 mod document;
+mod edit_distance;
 mod error;
 mod into_range;
+mod migrator;
 mod pypi_client;
 mod quickfix;
 mod quickfixer;
@@ -91,5 +102,7 @@ mod schema_pointer;
 mod schema_store;
 mod schemas;
 mod span;
+mod suppression;
+mod url_client;
 
 type Result<T = (), E = Error> = std::result::Result<T, E>;
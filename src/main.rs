@@ -1,32 +1,55 @@
 use {
   anyhow::{Error, anyhow, bail},
   arguments::Arguments,
-  ariadne::{Color, Label, Report, ReportKind, sources},
-  clap::Parser,
+  ariadne::{Color, Config, Label, Report, ReportKind, sources},
+  baseline::Baseline,
+  clap::{Parser, ValueEnum},
+  color_mode::ColorMode,
   env_logger::Env,
+  output_format::{JsonDiagnostic, OutputFormat},
+  overlay::{Origin, Overlay},
   owo_colors::OwoColorize,
   pyproject::{
-    Analyzer, BUILTINS, Builtin, Document, Quickfixer, Resolver, RopeExt,
+    Analyzer, BUILTINS, Builtin, Diagnostic, Document, Edit, Migrator,
+    Quickfixer, Resolver, RopeExt, Rule,
   },
+  rayon::ThreadPoolBuilder,
+  serde::{Deserialize, Serialize},
+  serde_json::json,
   server::Server,
   similar::TextDiff,
   std::{
     backtrace::BacktraceStatus,
+    cmp::Reverse,
     collections::BTreeMap,
     env, fs,
-    path::PathBuf,
+    io::{self, IsTerminal},
+    mem,
+    num::NonZeroUsize,
+    ops::Range,
+    path::{Path, PathBuf},
     process,
     sync::{
       Arc,
       atomic::{AtomicBool, Ordering},
     },
+    thread,
+    time::Instant,
   },
   subcommand::Subcommand,
+  taplo::{
+    parser::parse,
+    syntax::{SyntaxElement, SyntaxKind, SyntaxNode},
+  },
   tokio::sync::RwLock,
   tower_lsp::{Client, LanguageServer, LspService, jsonrpc, lsp_types as lsp},
 };
 
 mod arguments;
+mod baseline;
+mod color_mode;
+mod output_format;
+mod overlay;
 mod server;
 mod subcommand;
 
@@ -34,10 +57,6 @@ type Result<T = (), E = Error> = std::result::Result<T, E>;
 
 #[tokio::main]
 async fn main() {
-  if env::var_os("NO_COLOR").is_some() {
-    yansi::disable();
-  }
-
   let env = Env::default().default_filter_or("info");
 
   env_logger::Builder::from_env(env).init();
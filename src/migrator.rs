@@ -0,0 +1,411 @@
+use super::*;
+
+/// Converts legacy `[tool.poetry]` metadata into a PEP 621 `[project]` table,
+/// leaving anything that can't be translated under `[tool.poetry]`.
+#[derive(Debug)]
+pub struct Migrator<'a> {
+  document: &'a Document,
+}
+
+impl<'a> Migrator<'a> {
+  fn array(field: &str, items: &[String]) -> String {
+    format!(
+      "{field} = [\n{}\n]",
+      items
+        .iter()
+        .map(|item| format!("  {item},"))
+        .collect::<Vec<_>>()
+        .join("\n")
+    )
+  }
+
+  fn author(value: &str) -> String {
+    let value = value.trim();
+
+    match value
+      .strip_suffix('>')
+      .and_then(|value| value.rsplit_once('<'))
+    {
+      Some((name, email)) if !name.trim().is_empty() => format!(
+        "{{ name = {}, email = {} }}",
+        Self::quote(name.trim()),
+        Self::quote(email.trim())
+      ),
+      Some((_, email)) => {
+        format!("{{ email = {} }}", Self::quote(email.trim()))
+      }
+      None => format!("{{ name = {} }}", Self::quote(value)),
+    }
+  }
+
+  fn authors(node: &Node) -> Option<Vec<String>> {
+    node
+      .as_array()?
+      .items()
+      .read()
+      .iter()
+      .map(|item| item.as_str().map(|author| Self::author(author.value())))
+      .collect()
+  }
+
+  /// Translates a Poetry version constraint into a PEP 440 specifier set,
+  /// returning `None` for `||` alternatives, which PEP 440 can't express.
+  fn constraint(constraint: &str) -> Option<String> {
+    let alternatives = ToolPoetryPythonRule::translate(constraint)?;
+
+    let [specifiers] = alternatives.as_slice() else {
+      return None;
+    };
+
+    Some(
+      specifiers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(","),
+    )
+  }
+
+  fn dependency(name: &str, node: &Node) -> Option<String> {
+    let (mut extras, mut version, mut markers) =
+      (String::new(), String::new(), String::new());
+
+    match node {
+      Node::Str(constraint) => version = Self::constraint(constraint.value())?,
+      Node::Table(table) => {
+        for (key, value) in table.entries().read().iter() {
+          match key.value() {
+            "extras" => {
+              extras = format!(
+                "[{}]",
+                value
+                  .as_array()?
+                  .items()
+                  .read()
+                  .iter()
+                  .map(|extra| extra
+                    .as_str()
+                    .map(|extra| extra.value().to_string()))
+                  .collect::<Option<Vec<_>>>()?
+                  .join(",")
+              );
+            }
+            "markers" => markers = format!("; {}", value.as_str()?.value()),
+            "version" => version = Self::constraint(value.as_str()?.value())?,
+            _ => return None,
+          }
+        }
+      }
+      _ => return None,
+    }
+
+    let requirement = format!("{name}{extras}{version}{markers}");
+
+    Requirement::<VerbatimUrl>::from_str(&requirement)
+      .ok()
+      .map(|_| requirement)
+  }
+
+  /// Returns the line of the `[table]` header that defines `key`, if `key`
+  /// names a table declared with a header.
+  fn header_line(&self, key: &Key) -> Option<u32> {
+    let line = key.span(&self.document.content).start.line;
+
+    self
+      .document
+      .content
+      .get_line(line as usize)?
+      .to_string()
+      .trim_start()
+      .starts_with('[')
+      .then_some(line)
+  }
+
+  /// Returns the document with every `[tool.poetry]` field that could be
+  /// translated moved into a new `[project]` table, inserted where
+  /// `[tool.poetry]` begins.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the document already has a `[project]` table or has
+  /// no `[tool.poetry]` table to migrate.
+  pub fn migrate(&self) -> Result<String, Error> {
+    let context = RuleContext::new(self.document);
+
+    if context.get("project").is_some() {
+      return Err(Error::MigrateExistingProject);
+    }
+
+    let (Some(tool), Some(poetry)) = (
+      context
+        .get("tool")
+        .and_then(|node| node.as_table().cloned()),
+      context
+        .get("tool.poetry")
+        .and_then(|node| node.as_table().cloned()),
+    ) else {
+      return Err(Error::MigrateMissingPoetry);
+    };
+
+    let content = &self.document.content;
+
+    let lines = |key: &Key, value: &Node| {
+      key.span(content).start.line..=value.span(content).end.line
+    };
+
+    let mut fields = Vec::new();
+
+    let mut removed = HashSet::new();
+
+    let entries = poetry.entries().read();
+
+    let mut migrated = 0;
+
+    for (key, value) in entries.iter() {
+      let field = match key.value() {
+        field @ ("name" | "version" | "description") => value
+          .as_str()
+          .map(|string| format!("{field} = {}", Self::quote(string.value()))),
+        "authors" => Self::authors(value)
+          .filter(|authors| !authors.is_empty())
+          .map(|authors| Self::array("authors", &authors)),
+        _ => None,
+      };
+
+      if let Some(field) = field {
+        fields.push(field);
+        removed.extend(lines(key, value));
+        migrated += 1;
+      }
+    }
+
+    if let Some((key, table)) = entries
+      .iter()
+      .find(|(key, _)| key.value() == "dependencies")
+      .and_then(|(key, value)| Some((key, value.as_table()?)))
+    {
+      let mut requirements = Vec::new();
+
+      let dependencies = table.entries().read();
+
+      for (name, value) in dependencies.iter() {
+        if name.value() == "python" {
+          if let Some(python) = value
+            .as_str()
+            .and_then(|python| Self::constraint(python.value()))
+          {
+            fields.push(format!("requires-python = {}", Self::quote(&python)));
+            removed.extend(lines(name, value));
+          }
+        } else if let Some(requirement) = Self::dependency(name.value(), value)
+        {
+          requirements.push(Self::quote(&requirement));
+          removed.extend(lines(name, value));
+        }
+      }
+
+      if !requirements.is_empty() {
+        fields.push(Self::array("dependencies", &requirements));
+      }
+
+      if dependencies
+        .iter()
+        .all(|(name, _)| removed.contains(&name.span(content).start.line))
+      {
+        removed.extend(self.header_line(key));
+        migrated += 1;
+      }
+    }
+
+    let header = tool
+      .entries()
+      .read()
+      .iter()
+      .find(|(key, _)| key.value() == "poetry")
+      .and_then(|(key, _)| self.header_line(key));
+
+    if migrated == entries.len() {
+      removed.extend(header);
+    }
+
+    let project = format!("[project]\n{}\n", fields.join("\n"));
+
+    let mut output = String::new();
+
+    let mut skipped = false;
+
+    for (index, line) in content.lines().enumerate() {
+      let index = u32::try_from(index).unwrap_or(u32::MAX);
+
+      if Some(index) == header {
+        output.push_str(&project);
+        output.push('\n');
+      }
+
+      if removed.contains(&index) {
+        skipped = true;
+        continue;
+      }
+
+      let blank = line.to_string().trim().is_empty();
+
+      if skipped && blank && (output.is_empty() || output.ends_with("\n\n")) {
+        continue;
+      }
+
+      skipped &= blank;
+
+      output.extend(line.chars());
+    }
+
+    if header.is_none() {
+      if !output.is_empty() {
+        output.push('\n');
+      }
+
+      output.push_str(&project);
+    }
+
+    while output.ends_with("\n\n") {
+      output.pop();
+    }
+
+    Ok(output)
+  }
+
+  #[must_use]
+  pub fn new(document: &'a Document) -> Self {
+    Self { document }
+  }
+
+  /// Renders `value` as a TOML basic string, whose escapes are a superset of
+  /// those JSON produces.
+  fn quote(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, indoc::indoc, pretty_assertions::assert_eq};
+
+  #[test]
+  fn constraint() {
+    #[track_caller]
+    fn case(constraint: &str, expected: Option<&str>) {
+      assert_eq!(Migrator::constraint(constraint).as_deref(), expected);
+    }
+
+    case("^1.2", Some(">=1.2,<2"));
+    case("^0.2.3", Some(">=0.2.3,<0.3"));
+    case("~1.2", Some(">=1.2,<1.3"));
+    case(">=1,<2", Some(">=1,<2"));
+    case("1.2.3", Some("==1.2.3"));
+    case("*", Some(""));
+    case("~2.7 || ^3.6", None);
+  }
+
+  #[test]
+  fn migrate() {
+    let document = Document::from(indoc! {
+      r#"
+      [build-system]
+      requires = ["poetry-core"]
+      build-backend = "poetry.core.masonry.api"
+
+      [tool.poetry]
+      name = "demo"
+      version = "0.1.0"
+      description = "A demo"
+      authors = ["Jane Doe <jane@example.com>", "Bob"]
+      packages = [{ include = "demo" }]
+
+      [tool.poetry.dependencies]
+      python = "^3.10"
+      requests = { version = "^2.31", extras = ["socks"] }
+      rich = "~13.7"
+      click = ">=8,<9"
+      local = { path = "../local" }
+
+      [tool.poetry.group.dev.dependencies]
+      pytest = "^8"
+      "#
+    });
+
+    assert_eq!(
+      Migrator::new(&document).migrate().unwrap(),
+      indoc! {
+        r#"
+        [build-system]
+        requires = ["poetry-core"]
+        build-backend = "poetry.core.masonry.api"
+
+        [project]
+        name = "demo"
+        version = "0.1.0"
+        description = "A demo"
+        authors = [
+          { name = "Jane Doe", email = "jane@example.com" },
+          { name = "Bob" },
+        ]
+        requires-python = ">=3.10,<4"
+        dependencies = [
+          "requests[socks]>=2.31,<3",
+          "rich>=13.7,<13.8",
+          "click>=8,<9",
+        ]
+
+        [tool.poetry]
+        packages = [{ include = "demo" }]
+
+        [tool.poetry.dependencies]
+        local = { path = "../local" }
+
+        [tool.poetry.group.dev.dependencies]
+        pytest = "^8"
+        "#
+      }
+    );
+  }
+
+  #[test]
+  fn migrate_maps_python_version() {
+    let document = Document::from(indoc! {
+      r#"
+      [tool.poetry]
+      name = "demo"
+
+      [tool.poetry.dependencies]
+      python = "~3.11"
+      "#
+    });
+
+    assert_eq!(
+      Migrator::new(&document).migrate().unwrap(),
+      indoc! {
+        r#"
+        [project]
+        name = "demo"
+        requires-python = ">=3.11,<3.12"
+        "#
+      }
+    );
+  }
+
+  #[test]
+  fn migrate_requires_poetry_without_project() {
+    assert!(matches!(
+      Migrator::new(&Document::from(
+        "[project]\nname = \"demo\"\n\n[tool.poetry]\nname = \"demo\"\n"
+      ))
+      .migrate(),
+      Err(Error::MigrateExistingProject)
+    ));
+
+    assert!(matches!(
+      Migrator::new(&Document::from("[tool.black]\nline-length = 88\n"))
+        .migrate(),
+      Err(Error::MigrateMissingPoetry)
+    ));
+  }
+}
@@ -0,0 +1,142 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub(crate) enum OutputFormat {
+  #[value(alias = "text-compact")]
+  Grep,
+  #[default]
+  #[value(alias = "full")]
+  Human,
+  Json,
+  Sarif,
+}
+
+/// A diagnostic as reported by `pyproject check --output-format json`, with
+/// a zero-based range into the file at `path`.
+#[derive(Debug, Serialize)]
+pub(crate) struct JsonDiagnostic {
+  pub(crate) message: String,
+  pub(crate) path: String,
+  pub(crate) range: lsp::Range,
+  pub(crate) rule: String,
+  pub(crate) severity: String,
+}
+
+impl JsonDiagnostic {
+  const SARIF_SCHEMA: &'static str =
+    "https://json.schemastore.org/sarif-2.1.0.json";
+
+  /// Builds a SARIF 2.1.0 log of `diagnostics`, listing every registered rule
+  /// as a reporting descriptor so code scanning can show rule descriptions.
+  pub(crate) fn sarif(diagnostics: &[Self]) -> serde_json::Value {
+    let mut rules = inventory::iter::<&dyn Rule>
+      .into_iter()
+      .map(|rule| (rule.id(), rule.message()))
+      .collect::<Vec<_>>();
+
+    rules.sort_unstable();
+
+    let results = diagnostics
+      .iter()
+      .map(|diagnostic| {
+        let level = match diagnostic.severity.as_str() {
+          "error" => "error",
+          "warning" => "warning",
+          _ => "note",
+        };
+
+        json!({
+          "ruleId": diagnostic.rule,
+          "level": level,
+          "message": { "text": diagnostic.message },
+          "locations": [{
+            "physicalLocation": {
+              "artifactLocation": { "uri": diagnostic.path },
+              "region": {
+                "startLine": diagnostic.range.start.line + 1,
+                "startColumn": diagnostic.range.start.character + 1,
+                "endLine": diagnostic.range.end.line + 1,
+                "endColumn": diagnostic.range.end.character + 1,
+              },
+            },
+          }],
+        })
+      })
+      .collect::<Vec<_>>();
+
+    json!({
+      "$schema": Self::SARIF_SCHEMA,
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "rules": rules
+              .into_iter()
+              .map(|(id, message)| json!({
+                "id": id,
+                "shortDescription": { "text": message },
+              }))
+              .collect::<Vec<_>>(),
+          },
+        },
+        "results": results,
+      }],
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn sarif() {
+    let sarif = JsonDiagnostic::sarif(&[JsonDiagnostic {
+      message: "`project.name` must be a valid distribution name".into(),
+      path: "pyproject.toml".into(),
+      range: lsp::Range::new(
+        lsp::Position::new(1, 7),
+        lsp::Position::new(1, 16),
+      ),
+      rule: "project-name".into(),
+      severity: "error".into(),
+    }]);
+
+    assert_eq!(sarif["$schema"], JsonDiagnostic::SARIF_SCHEMA);
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+
+    assert_eq!(run["tool"]["driver"]["name"], env!("CARGO_PKG_NAME"));
+
+    assert!(
+      run["tool"]["driver"]["rules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|rule| rule["id"] == "project-name")
+    );
+
+    assert_eq!(
+      run["results"],
+      json!([{
+        "ruleId": "project-name",
+        "level": "error",
+        "message": { "text": "`project.name` must be a valid distribution name" },
+        "locations": [{
+          "physicalLocation": {
+            "artifactLocation": { "uri": "pyproject.toml" },
+            "region": {
+              "startLine": 2,
+              "startColumn": 8,
+              "endLine": 2,
+              "endColumn": 17,
+            },
+          },
+        }],
+      }])
+    );
+  }
+}
@@ -0,0 +1,557 @@
+use super::*;
+
+/// A base `pyproject.toml` merged with an overlay fragment, such as the
+/// overrides a CI job applies before building.
+///
+/// Tables merge recursively, including tables the base defines inline or
+/// through dotted keys, and values in the overlay replace values in the
+/// base. The merged content is produced by splicing overlay text into the
+/// base, so that offsets in the merged document can be traced back to the
+/// file they came from.
+#[derive(Debug)]
+pub(crate) struct Overlay {
+  base: String,
+  content: String,
+  overlay: String,
+  segments: Vec<Segment>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Origin {
+  Base,
+  Overlay,
+}
+
+#[derive(Debug)]
+struct Edit {
+  pieces: Vec<(String, usize)>,
+  range: Range<usize>,
+}
+
+#[derive(Debug)]
+struct Entry {
+  range: Range<usize>,
+  value: Range<usize>,
+}
+
+#[derive(Debug, Default)]
+struct Layout {
+  headers: BTreeMap<Vec<String>, usize>,
+  root: usize,
+  sections: Vec<Section>,
+  tables: BTreeMap<Vec<String>, Table>,
+  values: BTreeMap<Vec<String>, Range<usize>>,
+}
+
+#[derive(Debug)]
+struct Section {
+  array: bool,
+  entries: Vec<(Vec<String>, Entry)>,
+  header: Option<(Vec<String>, Range<usize>)>,
+}
+
+/// A table in the base that is defined without a header, whose definition
+/// new keys from the overlay have to be spliced into.
+#[derive(Debug)]
+enum Table {
+  /// Defined by dotted keys in the section whose header path has `section`
+  /// keys, with new lines going at `offset`.
+  Dotted { offset: usize, section: usize },
+  /// Defined inline, with new entries going at `offset`.
+  Inline { empty: bool, offset: usize },
+}
+
+#[derive(Debug)]
+struct Segment {
+  merged: Range<usize>,
+  origin: Origin,
+  start: usize,
+}
+
+impl Overlay {
+  pub(crate) fn content(&self) -> &str {
+    &self.content
+  }
+
+  fn dotted(path: &[String]) -> String {
+    path
+      .iter()
+      .map(|key| {
+        if !key.is_empty()
+          && key.chars().all(|character| {
+            character.is_ascii_alphanumeric()
+              || character == '-'
+              || character == '_'
+          })
+        {
+          key.clone()
+        } else {
+          format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(".")
+  }
+
+  fn entry(node: &SyntaxNode) -> Option<(Vec<String>, Entry)> {
+    let key = node
+      .children()
+      .find(|child| child.kind() == SyntaxKind::KEY)?;
+
+    let value = node
+      .children()
+      .find(|child| child.kind() == SyntaxKind::VALUE)?;
+
+    let start = usize::from(key.text_range().start());
+    let end = usize::from(value.text_range().end());
+
+    Some((
+      Self::key(&key),
+      Entry {
+        range: start..end,
+        value: usize::from(value.text_range().start())..end,
+      },
+    ))
+  }
+
+  fn key(node: &SyntaxNode) -> Vec<String> {
+    node
+      .descendants_with_tokens()
+      .filter_map(SyntaxElement::into_token)
+      .filter(|token| token.kind() == SyntaxKind::IDENT)
+      .map(|token| {
+        let text = token.text();
+
+        text
+          .strip_prefix('"')
+          .and_then(|text| text.strip_suffix('"'))
+          .or_else(|| {
+            text
+              .strip_prefix('\'')
+              .and_then(|text| text.strip_suffix('\''))
+          })
+          .unwrap_or(text)
+          .to_string()
+      })
+      .collect()
+  }
+
+  fn layout(text: &str) -> Layout {
+    let mut layout = Layout {
+      root: text.len(),
+      ..Layout::default()
+    };
+
+    let mut section = Section {
+      array: false,
+      entries: Vec::new(),
+      header: None,
+    };
+
+    for node in parse(text).into_syntax().children() {
+      match node.kind() {
+        kind @ (SyntaxKind::TABLE_HEADER | SyntaxKind::TABLE_ARRAY_HEADER) => {
+          let Some(key) = node
+            .children()
+            .find(|child| child.kind() == SyntaxKind::KEY)
+          else {
+            continue;
+          };
+
+          let path = Self::key(&key);
+
+          let range = usize::from(node.text_range().start())
+            ..usize::from(node.text_range().end());
+
+          if layout.sections.is_empty() && section.header.is_none() {
+            layout.root = range.start;
+          }
+
+          let array = kind == SyntaxKind::TABLE_ARRAY_HEADER;
+
+          if !array {
+            let line_end = text[range.end..]
+              .find('\n')
+              .map_or(text.len(), |offset| range.end + offset + 1);
+
+            layout.headers.insert(path.clone(), line_end);
+          }
+
+          layout.sections.push(mem::replace(
+            &mut section,
+            Section {
+              array,
+              entries: Vec::new(),
+              header: Some((path, range)),
+            },
+          ));
+        }
+        SyntaxKind::ENTRY => {
+          let Some((key, entry)) = Self::entry(&node) else {
+            continue;
+          };
+
+          if !section.array {
+            let parent = section
+              .header
+              .as_ref()
+              .map(|(path, _)| path.clone())
+              .unwrap_or_default();
+
+            let line_end = text[entry.range.end..]
+              .find('\n')
+              .map_or(text.len(), |offset| entry.range.end + offset + 1);
+
+            for length in parent.len() + 1..parent.len() + key.len() {
+              let mut path = parent.clone();
+
+              path.extend(key[..length - parent.len()].iter().cloned());
+
+              layout.tables.insert(
+                path,
+                Table::Dotted {
+                  offset: line_end,
+                  section: parent.len(),
+                },
+              );
+            }
+
+            Self::record(&mut layout, &parent, &node);
+          }
+
+          section.entries.push((key, entry));
+        }
+        _ => {}
+      }
+    }
+
+    layout.sections.push(section);
+
+    layout
+  }
+
+  fn locate(&self, offset: usize) -> (Origin, usize) {
+    self
+      .segments
+      .iter()
+      .rev()
+      .find(|segment| segment.merged.start <= offset)
+      .map_or((Origin::Base, offset), |segment| {
+        (
+          segment.origin,
+          segment.start + offset - segment.merged.start,
+        )
+      })
+  }
+
+  pub(crate) fn merge(base: &str, overlay: &str) -> Result<Self> {
+    if let Some(error) = parse(overlay).errors.first() {
+      bail!("{}", error.message);
+    }
+
+    let mut base_layout = Self::layout(base);
+    let overlay_layout = Self::layout(overlay);
+
+    let mut edits = Vec::new();
+    let mut appended = Vec::new();
+
+    let newline = |offset: usize| {
+      if offset == base.len() && !base.is_empty() && !base.ends_with('\n') {
+        vec![("\n".to_string(), 0)]
+      } else {
+        Vec::new()
+      }
+    };
+
+    for section in overlay_layout.sections {
+      let header = section.header.as_ref().map(|(path, _)| path.clone());
+
+      if section.array {
+        let start = section.header.as_ref().map_or(0, |(_, range)| range.start);
+
+        let end = section
+          .entries
+          .last()
+          .map(|(_, entry)| entry.range.end)
+          .or(section.header.as_ref().map(|(_, range)| range.end))
+          .unwrap_or(start);
+
+        appended.push(("\n".to_string(), start));
+        appended.push((format!("{}\n", &overlay[start..end]), start));
+
+        continue;
+      }
+
+      let mut missing = Vec::new();
+
+      for (key, entry) in section.entries {
+        let mut path = header.clone().unwrap_or_default();
+
+        path.extend(key.iter().cloned());
+
+        if let Some(value) = base_layout.values.get(&path) {
+          edits.push(Edit {
+            pieces: vec![(
+              overlay[entry.value.clone()].to_string(),
+              entry.value.start,
+            )],
+            range: value.clone(),
+          });
+
+          continue;
+        }
+
+        let assignment = |parent: usize| {
+          if path[parent..] == key {
+            vec![(overlay[entry.range.clone()].to_string(), entry.range.start)]
+          } else {
+            vec![
+              (
+                format!("{} = ", Self::dotted(&path[parent..])),
+                entry.range.start,
+              ),
+              (overlay[entry.value.clone()].to_string(), entry.value.start),
+            ]
+          }
+        };
+
+        if let Some((parent, table)) =
+          (1..path.len()).rev().find_map(|length| {
+            base_layout
+              .tables
+              .get(&path[..length])
+              .map(|table| (length, table))
+          })
+        {
+          let (offset, pieces) = match *table {
+            Table::Dotted { offset, section } => {
+              let mut pieces = newline(offset);
+
+              pieces.extend(assignment(section));
+              pieces.push(("\n".to_string(), entry.range.end));
+
+              (offset, pieces)
+            }
+            Table::Inline { empty, offset } => {
+              let mut pieces = if empty {
+                vec![(" ".to_string(), entry.range.start)]
+              } else {
+                vec![(", ".to_string(), entry.range.start)]
+              };
+
+              pieces.extend(assignment(parent));
+
+              if empty {
+                pieces.push((" ".to_string(), entry.range.end));
+              }
+
+              base_layout.tables.insert(
+                path[..parent].to_vec(),
+                Table::Inline {
+                  empty: false,
+                  offset,
+                },
+              );
+
+              (offset, pieces)
+            }
+          };
+
+          edits.push(Edit {
+            pieces,
+            range: offset..offset,
+          });
+
+          continue;
+        }
+
+        let line = (
+          format!("{}\n", &overlay[entry.range.clone()]),
+          entry.range.start,
+        );
+
+        let position = match &header {
+          None => Some(base_layout.root),
+          Some(header) => base_layout.headers.get(header).copied(),
+        };
+
+        match position {
+          Some(position) => {
+            let mut pieces = newline(position);
+
+            pieces.push(line);
+
+            edits.push(Edit {
+              pieces,
+              range: position..position,
+            });
+          }
+          None => missing.push(line),
+        }
+      }
+
+      if let (false, Some((path, range))) = (missing.is_empty(), section.header)
+      {
+        appended.push(("\n".to_string(), range.start));
+        appended.push((format!("[{}]\n", Self::dotted(&path)), range.start));
+        appended.extend(missing);
+      }
+    }
+
+    if !appended.is_empty() {
+      let mut pieces = newline(base.len());
+
+      pieces.extend(appended);
+
+      edits.push(Edit {
+        pieces,
+        range: base.len()..base.len(),
+      });
+    }
+
+    edits.sort_by_key(|edit| edit.range.start);
+
+    let mut content = String::new();
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+
+    for edit in edits {
+      if edit.range.start < cursor {
+        continue;
+      }
+
+      if cursor < edit.range.start {
+        segments.push(Segment {
+          merged: content.len()..content.len() + edit.range.start - cursor,
+          origin: Origin::Base,
+          start: cursor,
+        });
+
+        content.push_str(&base[cursor..edit.range.start]);
+      }
+
+      for (text, start) in edit.pieces {
+        segments.push(Segment {
+          merged: content.len()..content.len() + text.len(),
+          origin: Origin::Overlay,
+          start,
+        });
+
+        content.push_str(&text);
+      }
+
+      cursor = edit.range.end;
+    }
+
+    if cursor < base.len() {
+      segments.push(Segment {
+        merged: content.len()..content.len() + base.len() - cursor,
+        origin: Origin::Base,
+        start: cursor,
+      });
+
+      content.push_str(&base[cursor..]);
+    }
+
+    Ok(Self {
+      base: base.to_string(),
+      content,
+      overlay: overlay.to_string(),
+      segments,
+    })
+  }
+
+  /// Records the value of the entry `node` under `parent`, along with the
+  /// values in and insertion point of any inline table it holds.
+  fn record(layout: &mut Layout, parent: &[String], node: &SyntaxNode) {
+    let Some((key, entry)) = Self::entry(node) else {
+      return;
+    };
+
+    let mut path = parent.to_vec();
+
+    path.extend(key);
+
+    layout.values.insert(path.clone(), entry.value);
+
+    let Some(table) = node
+      .children()
+      .find(|child| child.kind() == SyntaxKind::VALUE)
+      .and_then(|value| {
+        value
+          .children()
+          .find(|child| child.kind() == SyntaxKind::INLINE_TABLE)
+      })
+    else {
+      return;
+    };
+
+    let mut inline = Table::Inline {
+      empty: true,
+      offset: table
+        .first_token()
+        .map_or(usize::from(table.text_range().start()), |brace| {
+          usize::from(brace.text_range().end())
+        }),
+    };
+
+    for child in table
+      .children()
+      .filter(|child| child.kind() == SyntaxKind::ENTRY)
+    {
+      Self::record(layout, &path, &child);
+
+      inline = Table::Inline {
+        empty: false,
+        offset: usize::from(child.text_range().end()),
+      };
+    }
+
+    layout.tables.insert(path, inline);
+  }
+
+  /// Returns the file that the character range `range` of the merged
+  /// content was taken from, along with the corresponding character range in
+  /// that file.
+  pub(crate) fn resolve(&self, range: Range<usize>) -> (Origin, Range<usize>) {
+    let byte = |index: usize| {
+      self
+        .content
+        .char_indices()
+        .nth(index)
+        .map_or(self.content.len(), |(offset, _)| offset)
+    };
+
+    let (start, end) = (byte(range.start), byte(range.end));
+
+    let (origin, source_start) = self.locate(start);
+
+    let source_end = match end.checked_sub(1).map(|last| self.locate(last)) {
+      Some((last_origin, last)) if end > start && last_origin == origin => {
+        (last + 1).max(source_start)
+      }
+      _ => source_start,
+    };
+
+    let text = self.source(origin);
+
+    let character = |offset: usize| {
+      let mut offset = offset.min(text.len());
+
+      while !text.is_char_boundary(offset) {
+        offset -= 1;
+      }
+
+      text[..offset].chars().count()
+    };
+
+    (origin, character(source_start)..character(source_end))
+  }
+
+  pub(crate) fn source(&self, origin: Origin) -> &str {
+    match origin {
+      Origin::Base => &self.base,
+      Origin::Overlay => &self.overlay,
+    }
+  }
+}
@@ -3,54 +3,327 @@ use super::*;
 #[derive(Debug, Deserialize)]
 struct PyPiResponse {
   info: PackageInfo,
+  #[serde(default)]
   releases: HashMap<String, Vec<ReleaseFile>>,
 }
 
 #[derive(Debug, Deserialize)]
 struct PackageInfo {
+  #[serde(default)]
+  author_email: Option<String>,
+  #[serde(default)]
+  home_page: Option<String>,
+  #[serde(default)]
+  maintainer_email: Option<String>,
+  #[serde(default)]
+  project_urls: Option<HashMap<String, String>>,
+  #[serde(default)]
+  provides_extra: Option<Vec<String>>,
+  #[serde(default)]
+  requires_python: Option<String>,
+  #[serde(default)]
+  summary: Option<String>,
+  #[serde(default)]
+  version: String,
+}
+
+/// The latest version and releases of a package recorded on disk, along with
+/// when they were fetched in seconds since the Unix epoch. Entries written
+/// before releases were cached have none.
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedPackage {
+  fetched: u64,
+  #[serde(default)]
+  releases: Option<Vec<CachedRelease>>,
+  version: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedRelease {
   version: String,
+  yanked: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+struct Package {
+  extras: Option<Vec<String>>,
+  latest_version: Option<Version>,
+  owners: Vec<String>,
+  releases: Vec<Release>,
+  requires_python: Option<VersionSpecifiers>,
+  summary: Option<String>,
+}
+
+/// A published release of a package. `yanked` holds the yank reason when
+/// every file in the release is yanked, which is empty if none was given.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+  pub(crate) version: Version,
+  pub(crate) yanked: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseResponse {
+  info: ReleaseInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseInfo {
+  #[serde(default)]
+  provides_extra: Option<Vec<String>>,
+  #[serde(default)]
+  requires_python: Option<String>,
+}
+
+/// Metadata published for a specific release of a package.
+#[derive(Clone, Debug)]
+struct ReleaseMetadata {
+  extras: Option<Vec<String>>,
+  requires_python: Option<VersionSpecifiers>,
 }
 
 #[derive(Debug, Deserialize)]
 struct ReleaseFile {
   #[serde(default)]
   yanked: bool,
+  #[serde(default)]
+  yanked_reason: Option<String>,
 }
 
 pub(crate) struct PyPiClient {
   base_url: String,
-  cache: Mutex<HashMap<String, Version>>,
+  cache: Mutex<HashMap<String, Package>>,
+  cache_dir: Option<PathBuf>,
+  cache_ttl: Duration,
   http: ReqwestClient,
+  #[cfg(test)]
+  mocks: Mutex<HashMap<String, Value>>,
+  offline: bool,
+  release_cache: Mutex<HashMap<String, ReleaseMetadata>>,
 }
 
 impl PyPiClient {
+  const DEFAULT_CACHE_TTL: Duration = Duration::from_hours(24);
+
+  const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+  fn cached(&self, package: &PackageName) -> Option<CachedPackage> {
+    let path = self.cache_dir.as_ref()?.join(format!("{package}.json"));
+
+    let entry =
+      serde_json::from_str::<CachedPackage>(&fs::read_to_string(path).ok()?)
+        .ok()?;
+
+    if Self::now().saturating_sub(entry.fetched) >= self.cache_ttl.as_secs() {
+      return None;
+    }
+
+    Some(entry)
+  }
+
+  fn cached_latest_version(&self, package: &PackageName) -> Option<Version> {
+    Version::from_str(&self.cached(package)?.version).ok()
+  }
+
+  fn cached_releases(&self, package: &PackageName) -> Option<Vec<Release>> {
+    self
+      .cached(package)?
+      .releases?
+      .into_iter()
+      .map(|release| {
+        Some(Release {
+          version: Version::from_str(&release.version).ok()?,
+          yanked: release.yanked,
+        })
+      })
+      .collect()
+  }
+
+  /// Returns the directory latest versions fetched from `base_url` are cached
+  /// in, honoring `PYPROJECT_CACHE_DIR` before the platform cache directory.
+  fn default_cache_dir(base_url: &str) -> Option<PathBuf> {
+    let root = match env::var_os("PYPROJECT_CACHE_DIR")
+      .filter(|value| !value.is_empty())
+    {
+      Some(directory) => PathBuf::from(directory),
+      None if cfg!(windows) => {
+        PathBuf::from(env::var_os("LOCALAPPDATA")?).join("pyproject")
+      }
+      None if cfg!(target_os = "macos") => {
+        PathBuf::from(env::var_os("HOME")?).join("Library/Caches/pyproject")
+      }
+      None => env::var_os("XDG_CACHE_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+          env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache"))
+        })?
+        .join("pyproject"),
+    };
+
+    let host = lsp::Url::parse(base_url).ok()?.host_str()?.to_string();
+
+    Some(root.join("pypi").join(host))
+  }
+
+  pub(crate) fn extras(&self, package: &PackageName) -> Option<Vec<String>> {
+    self.package(package)?.extras
+  }
+
+  /// Returns the extras declared by a specific release of `package`, rather
+  /// than its latest release.
+  pub(crate) fn extras_for(
+    &self,
+    package: &PackageName,
+    version: &Version,
+  ) -> Option<Vec<String>> {
+    self.release(package, version)?.extras
+  }
+
+  /// Fetches and decodes `{base_url}/pypi/{path}/json`. This is the only place
+  /// the client reaches the network, so it is where offline mode applies and
+  /// where tests substitute canned payloads.
+  fn fetch<T: DeserializeOwned>(&self, path: &str) -> Option<T> {
+    #[cfg(test)]
+    if let Some(payload) = self
+      .mocks
+      .lock()
+      .ok()
+      .and_then(|mocks| mocks.get(path).cloned())
+    {
+      return serde_json::from_value(payload).ok();
+    }
+
+    if self.offline {
+      return None;
+    }
+
+    self
+      .http
+      .get(format!("{}/pypi/{path}/json", self.base_url))
+      .send()
+      .ok()?
+      .error_for_status()
+      .ok()?
+      .json::<T>()
+      .ok()
+  }
+
+  /// Returns the latest version of `package`, reusing a version cached on
+  /// disk until it is older than the cache TTL, including when offline.
   pub(crate) fn latest_version(
     &self,
     package: &PackageName,
   ) -> Option<Version> {
+    self
+      .cached_latest_version(package)
+      .or_else(|| self.package(package)?.latest_version)
+  }
+
+  /// Looks up the latest version of every package in `names`, issuing at
+  /// most `MAX_CONCURRENT_REQUESTS` requests at a time.
+  pub(crate) fn latest_versions(
+    &self,
+    names: &[PackageName],
+  ) -> HashMap<PackageName, Option<Version>> {
+    names
+      .chunks(Self::MAX_CONCURRENT_REQUESTS)
+      .flat_map(|chunk| {
+        chunk
+          .par_iter()
+          .map(|name| (name.clone(), self.latest_version(name)))
+          .collect::<Vec<_>>()
+      })
+      .collect()
+  }
+
+  /// Edits the canned payload served for `path`, dropping any metadata
+  /// already cached from it.
+  #[cfg(test)]
+  fn mock(&self, path: &str, f: impl FnOnce(&mut Value)) {
+    let key = format!("{}/{path}", self.base_url);
+
+    self.cache.lock().unwrap().remove(&key);
+    self.release_cache.lock().unwrap().remove(&key);
+
+    f(self
+      .mocks
+      .lock()
+      .unwrap()
+      .entry(path.to_string())
+      .or_default());
+  }
+
+  fn new() -> Self {
+    let base_url = env::var("PYPROJECT_PYPI_BASE_URL")
+      .unwrap_or_else(|_| "https://pypi.org".to_string())
+      .trim_end_matches('/')
+      .to_string();
+
+    let http = ReqwestClient::builder()
+      .timeout(Duration::from_secs(5))
+      .user_agent(format!(
+        "{}/{}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+      ))
+      .build()
+      .unwrap_or_else(|error| {
+        debug!("failed to configure HTTP client: {error}");
+        ReqwestClient::new()
+      });
+
+    Self {
+      // Tests answer from canned payloads, which must not leak into the
+      // user's cache.
+      cache_dir: if cfg!(test) {
+        None
+      } else {
+        Self::default_cache_dir(&base_url)
+      },
+      base_url,
+      cache: Mutex::new(HashMap::new()),
+      cache_ttl: env::var("PYPROJECT_CACHE_TTL")
+        .ok()
+        .and_then(|seconds| seconds.parse().ok())
+        .map_or(Self::DEFAULT_CACHE_TTL, Duration::from_secs),
+      http,
+      #[cfg(test)]
+      mocks: Mutex::new(HashMap::new()),
+      offline: env::var_os("PYPROJECT_OFFLINE")
+        .is_some_and(|value| !value.is_empty()),
+      release_cache: Mutex::new(HashMap::new()),
+    }
+  }
+
+  fn now() -> u64 {
+    SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .map_or(0, |elapsed| elapsed.as_secs())
+  }
+
+  /// Returns the author and maintainer emails and project URLs published for
+  /// `package`, which identify who owns the name.
+  pub(crate) fn owners(&self, package: &PackageName) -> Option<Vec<String>> {
+    self.package(package).map(|package| package.owners)
+  }
+
+  fn package(&self, package: &PackageName) -> Option<Package> {
     let name = package.to_string();
 
     let cache_key = format!("{}/{}", self.base_url, name);
 
-    if let Some(version) = self
+    if let Some(package) = self
       .cache
       .lock()
       .inspect_err(|error| debug!("failed to lock PyPI cache: {error}"))
       .ok()
       .and_then(|cache| cache.get(&cache_key).cloned())
     {
-      return Some(version);
+      return Some(package);
     }
 
-    let payload = self
-      .http
-      .get(format!("{}/pypi/{}/json", self.base_url, name))
-      .send()
-      .ok()?
-      .error_for_status()
-      .ok()?
-      .json::<PyPiResponse>()
-      .ok()?;
+    let payload = self.fetch::<PyPiResponse>(&name)?;
 
     let max_version = |current: Option<Version>, candidate: Version| {
       Some(match current {
@@ -59,11 +332,29 @@ impl PyPiClient {
       })
     };
 
-    let (latest_release, latest_prerelease) = payload
+    let mut releases = payload
       .releases
       .into_iter()
-      .filter(|(_, files)| files.iter().any(|file| !file.yanked))
-      .filter_map(|(raw_version, _)| Version::from_str(&raw_version).ok())
+      .filter(|(_, files)| !files.is_empty())
+      .filter_map(|(raw_version, files)| {
+        Some(Release {
+          version: Version::from_str(&raw_version).ok()?,
+          yanked: files.iter().all(|file| file.yanked).then(|| {
+            files
+              .iter()
+              .find_map(|file| file.yanked_reason.clone())
+              .unwrap_or_default()
+          }),
+        })
+      })
+      .collect::<Vec<_>>();
+
+    releases.sort_by(|a, b| a.version.cmp(&b.version));
+
+    let (latest_release, latest_prerelease) = releases
+      .iter()
+      .filter(|release| release.yanked.is_none())
+      .map(|release| release.version.clone())
       .fold((None, None), |(release, prerelease), version| {
         if version.any_prerelease() {
           (release, max_version(prerelease, version))
@@ -72,43 +363,172 @@ impl PyPiClient {
         }
       });
 
-    let latest = latest_release
+    let latest_version = latest_release
       .or(latest_prerelease)
-      .or_else(|| Version::from_str(&payload.info.version).ok())?;
+      .or_else(|| Version::from_str(&payload.info.version).ok());
+
+    let owners = payload
+      .info
+      .author_email
+      .into_iter()
+      .chain(payload.info.maintainer_email)
+      .chain(payload.info.home_page)
+      .chain(
+        payload
+          .info
+          .project_urls
+          .into_iter()
+          .flat_map(HashMap::into_values),
+      )
+      .filter(|owner| !owner.trim().is_empty())
+      .collect();
+
+    let package = Package {
+      extras: payload.info.provides_extra,
+      latest_version,
+      owners,
+      releases,
+      requires_python: Self::parse_requires_python(
+        payload.info.requires_python,
+      ),
+      summary: payload
+        .info
+        .summary
+        .filter(|summary| !summary.trim().is_empty()),
+    };
+
+    self.store(&name, &package);
 
     if let Ok(mut cache) = self.cache.lock() {
-      cache.insert(cache_key, latest.clone());
+      cache.insert(cache_key, package.clone());
     } else {
       debug!("failed to lock PyPI cache for insert");
     }
 
-    Some(latest)
+    Some(package)
   }
 
-  fn new() -> Self {
-    let base_url = env::var("PYPROJECT_PYPI_BASE_URL")
-      .unwrap_or_else(|_| "https://pypi.org".to_string())
-      .trim_end_matches('/')
-      .to_string();
+  fn parse_requires_python(
+    requires_python: Option<String>,
+  ) -> Option<VersionSpecifiers> {
+    requires_python
+      .filter(|requires_python| !requires_python.trim().is_empty())
+      .and_then(|requires_python| {
+        VersionSpecifiers::from_str(&requires_python).ok()
+      })
+  }
 
-    let http = ReqwestClient::builder()
-      .timeout(Duration::from_secs(5))
-      .user_agent(format!(
-        "{}/{}",
-        env!("CARGO_PKG_NAME"),
-        env!("CARGO_PKG_VERSION")
-      ))
-      .build()
-      .unwrap_or_else(|error| {
-        debug!("failed to configure HTTP client: {error}");
-        ReqwestClient::new()
-      });
+  fn release(
+    &self,
+    package: &PackageName,
+    version: &Version,
+  ) -> Option<ReleaseMetadata> {
+    let cache_key = format!("{}/{}/{}", self.base_url, package, version);
 
-    Self {
-      base_url,
-      cache: Mutex::new(HashMap::new()),
-      http,
+    if let Some(release) = self
+      .release_cache
+      .lock()
+      .inspect_err(|error| debug!("failed to lock PyPI cache: {error}"))
+      .ok()
+      .and_then(|release_cache| release_cache.get(&cache_key).cloned())
+    {
+      return Some(release);
     }
+
+    let payload =
+      self.fetch::<ReleaseResponse>(&format!("{package}/{version}"))?;
+
+    let release = ReleaseMetadata {
+      extras: payload.info.provides_extra,
+      requires_python: Self::parse_requires_python(
+        payload.info.requires_python,
+      ),
+    };
+
+    if let Ok(mut release_cache) = self.release_cache.lock() {
+      release_cache.insert(cache_key, release.clone());
+    } else {
+      debug!("failed to lock PyPI cache for insert");
+    }
+
+    Some(release)
+  }
+
+  /// Returns every release of `package` that has files, oldest first,
+  /// reusing releases cached on disk like `latest_version`.
+  pub(crate) fn releases(&self, package: &PackageName) -> Option<Vec<Release>> {
+    self
+      .cached_releases(package)
+      .or_else(|| self.package(package).map(|package| package.releases))
+  }
+
+  pub(crate) fn requires_python(
+    &self,
+    package: &PackageName,
+  ) -> Option<VersionSpecifiers> {
+    self.package(package)?.requires_python
+  }
+
+  /// Returns the `requires_python` metadata published for a specific release
+  /// of `package`, rather than its latest release.
+  pub(crate) fn requires_python_for(
+    &self,
+    package: &PackageName,
+    version: &Version,
+  ) -> Option<VersionSpecifiers> {
+    self.release(package, version)?.requires_python
+  }
+
+  /// Makes `package` declare `extras`, in the release `version` or in its
+  /// latest release if `version` is `None`, in place of the network.
+  #[cfg(test)]
+  pub(crate) fn set_mock_extras(
+    &self,
+    package: &str,
+    version: Option<&str>,
+    extras: &[&str],
+  ) {
+    let package = package.parse::<PackageName>().unwrap();
+
+    let path = match version {
+      Some(version) => {
+        format!("{package}/{}", version.parse::<Version>().unwrap())
+      }
+      None => package.to_string(),
+    };
+
+    self.mock(&path, |payload| {
+      payload["info"]["provides_extra"] = json!(extras);
+    });
+  }
+
+  /// Makes `package` report `version` as its latest, non-yanked release, in
+  /// place of the network.
+  #[cfg(test)]
+  pub(crate) fn set_mock_latest_version(&self, package: &str, version: &str) {
+    let package = package.parse::<PackageName>().unwrap();
+
+    self.mock(package.as_ref(), |payload| {
+      payload["info"]["version"] = json!(version);
+      payload["releases"][version] = json!([{ "yanked": false }]);
+    });
+  }
+
+  /// Records a yanked `version` of `package`, with an optional yank reason,
+  /// in place of the network.
+  #[cfg(test)]
+  pub(crate) fn set_mock_yanked(
+    &self,
+    package: &str,
+    version: &str,
+    reason: Option<&str>,
+  ) {
+    let package = package.parse::<PackageName>().unwrap();
+
+    self.mock(package.as_ref(), |payload| {
+      payload["releases"][version] =
+        json!([{ "yanked": true, "yanked_reason": reason }]);
+    });
   }
 
   pub(crate) fn shared() -> &'static Self {
@@ -116,12 +536,99 @@ impl PyPiClient {
 
     INSTANCE.get_or_init(Self::new)
   }
+
+  /// Returns a client that answers only from the on-disk cache, for offline
+  /// runs.
+  pub(crate) fn shared_offline() -> &'static Self {
+    static INSTANCE: OnceLock<PyPiClient> = OnceLock::new();
+
+    INSTANCE.get_or_init(|| Self {
+      offline: true,
+      ..Self::new()
+    })
+  }
+
+  /// Records the latest version and releases of the package `name` on disk.
+  fn store(&self, name: &str, package: &Package) {
+    let Some(directory) = self.cache_dir.as_ref() else {
+      return;
+    };
+
+    if self.cache_ttl.is_zero() {
+      return;
+    }
+
+    let Some(version) = package.latest_version.as_ref() else {
+      return;
+    };
+
+    let entry = CachedPackage {
+      fetched: Self::now(),
+      releases: Some(
+        package
+          .releases
+          .iter()
+          .map(|release| CachedRelease {
+            version: release.version.to_string(),
+            yanked: release.yanked.clone(),
+          })
+          .collect(),
+      ),
+      version: version.to_string(),
+    };
+
+    let result = fs::create_dir_all(directory).and_then(|()| {
+      fs::write(
+        directory.join(format!("{name}.json")),
+        serde_json::to_string(&entry)?,
+      )
+    });
+
+    if let Err(error) = result {
+      debug!("failed to cache metadata of `{name}`: {error}");
+    }
+  }
+
+  /// Returns the one-line summary published for `package`, if any.
+  pub(crate) fn summary(&self, package: &PackageName) -> Option<String> {
+    self.package(package)?.summary
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use {super::*, mockito::Server};
 
+  #[test]
+  fn extras_for() {
+    let mut server = Server::new();
+
+    let mock = server
+      .mock("GET", "/pypi/foo/1.0.0/json")
+      .with_body(r#"{ "info": { "provides_extra": ["redis"] } }"#)
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    for _ in 0..2 {
+      assert_eq!(
+        client.extras_for(&"foo".parse().unwrap(), &"1.0.0".parse().unwrap()),
+        Some(vec!["redis".to_string()])
+      );
+    }
+
+    mock.assert();
+  }
+
   #[test]
   fn latest_version() {
     #[track_caller]
@@ -136,7 +643,12 @@ mod tests {
       let client = PyPiClient {
         base_url: server.url(),
         cache: Mutex::new(HashMap::new()),
+        cache_dir: None,
+        cache_ttl: Duration::ZERO,
         http: ReqwestClient::new(),
+        mocks: Mutex::new(HashMap::new()),
+        offline: false,
+        release_cache: Mutex::new(HashMap::new()),
       };
 
       let package = "foo".parse().unwrap();
@@ -186,4 +698,443 @@ mod tests {
       "1.1.0",
     );
   }
+
+  #[test]
+  fn latest_version_cache_expiry() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join("foo.json"),
+      r#"{"fetched": 0, "version": "1.0.0"}"#,
+    )
+    .unwrap();
+
+    let mut server = Server::new();
+
+    let mock = server
+      .mock("GET", "/pypi/foo/json")
+      .with_body(r#"{"info": {"version": "2.0.0"}, "releases": {}}"#)
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: Some(tempdir.path().to_path_buf()),
+      cache_ttl: Duration::from_mins(1),
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let package = "foo".parse().unwrap();
+
+    assert_eq!(
+      client.latest_version(&package),
+      Some("2.0.0".parse().unwrap())
+    );
+
+    assert_eq!(
+      client.cached_latest_version(&package),
+      Some("2.0.0".parse().unwrap())
+    );
+
+    mock.assert();
+  }
+
+  #[test]
+  fn latest_version_cache_hit() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join("foo.json"),
+      format!(
+        r#"{{"fetched": {}, "version": "1.0.0"}}"#,
+        PyPiClient::now()
+      ),
+    )
+    .unwrap();
+
+    let mut server = Server::new();
+
+    let mock = server.mock("GET", "/pypi/foo/json").expect(0).create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: Some(tempdir.path().to_path_buf()),
+      cache_ttl: Duration::from_mins(1),
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    assert_eq!(
+      client.latest_version(&"foo".parse().unwrap()),
+      Some("1.0.0".parse().unwrap())
+    );
+
+    mock.assert();
+  }
+
+  #[test]
+  fn latest_versions() {
+    let mut server = Server::new();
+
+    let mocks = [("bar", "2.0.0"), ("foo", "1.0.0")]
+      .into_iter()
+      .map(|(name, version)| {
+        server
+          .mock("GET", format!("/pypi/{name}/json").as_str())
+          .with_body(format!(
+            r#"{{"info": {{"version": "{version}"}}, "releases": {{}}}}"#
+          ))
+          .create()
+      })
+      .collect::<Vec<_>>();
+
+    let missing = server
+      .mock("GET", "/pypi/baz/json")
+      .with_status(404)
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let names =
+      ["foo", "bar", "baz"].map(|name| PackageName::from_str(name).unwrap());
+
+    assert_eq!(
+      client.latest_versions(&names),
+      HashMap::from([
+        (names[0].clone(), Some("1.0.0".parse().unwrap())),
+        (names[1].clone(), Some("2.0.0".parse().unwrap())),
+        (names[2].clone(), None),
+      ])
+    );
+
+    for mock in mocks {
+      mock.assert();
+    }
+
+    missing.assert();
+  }
+
+  #[test]
+  fn metadata() {
+    let mut server = Server::new();
+
+    let mock = server
+      .mock("GET", "/pypi/foo/json")
+      .with_body(
+        r#"{
+          "info": {
+            "version": "1.0.0",
+            "author_email": "Jane <jane@example.com>",
+            "home_page": "",
+            "project_urls": { "Source": "https://github.com/jane/foo" },
+            "provides_extra": ["redis", "sqs"],
+            "requires_python": ">=3.9",
+            "summary": "Distributed task queue"
+          },
+          "releases": {}
+        }"#,
+      )
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let package = "foo".parse().unwrap();
+
+    assert_eq!(
+      client.extras(&package),
+      Some(vec!["redis".to_string(), "sqs".to_string()])
+    );
+
+    assert_eq!(
+      client.latest_version(&package),
+      Some("1.0.0".parse().unwrap())
+    );
+
+    assert_eq!(
+      client.owners(&package),
+      Some(vec![
+        "Jane <jane@example.com>".to_string(),
+        "https://github.com/jane/foo".to_string(),
+      ])
+    );
+
+    assert_eq!(
+      client.requires_python(&package),
+      Some(">=3.9".parse().unwrap())
+    );
+
+    assert_eq!(
+      client.summary(&package),
+      Some("Distributed task queue".to_string())
+    );
+
+    mock.assert();
+  }
+
+  #[test]
+  fn mock() {
+    let client = PyPiClient {
+      base_url: "http://127.0.0.1:1".to_string(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    client.set_mock_latest_version("foo", "2.0.0");
+    client.set_mock_yanked("foo", "1.5.0", Some("broken"));
+    client.set_mock_yanked("bar", "1.0.0", None);
+    client.set_mock_extras("foo", Some("1.5.0"), &["redis"]);
+
+    let foo = "foo".parse().unwrap();
+
+    assert_eq!(client.latest_version(&foo), Some("2.0.0".parse().unwrap()));
+
+    assert_eq!(
+      client.releases(&foo),
+      Some(vec![
+        Release {
+          version: "1.5.0".parse().unwrap(),
+          yanked: Some("broken".to_string()),
+        },
+        Release {
+          version: "2.0.0".parse().unwrap(),
+          yanked: None,
+        },
+      ])
+    );
+
+    assert_eq!(
+      client.extras_for(&foo, &"1.5.0".parse().unwrap()),
+      Some(vec!["redis".to_string()])
+    );
+
+    assert_eq!(client.latest_version(&"bar".parse().unwrap()), None);
+
+    client.set_mock_latest_version("foo", "3.0.0");
+
+    assert_eq!(client.latest_version(&foo), Some("3.0.0".parse().unwrap()));
+  }
+
+  #[test]
+  fn offline() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    fs::write(
+      tempdir.path().join("foo.json"),
+      format!(
+        r#"{{
+          "fetched": {},
+          "releases": [{{ "version": "1.0.0", "yanked": null }}],
+          "version": "1.0.0"
+        }}"#,
+        PyPiClient::now()
+      ),
+    )
+    .unwrap();
+
+    let client = PyPiClient {
+      base_url: "http://127.0.0.1:1".to_string(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: Some(tempdir.path().to_path_buf()),
+      cache_ttl: Duration::from_mins(1),
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: true,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let foo = "foo".parse().unwrap();
+
+    assert_eq!(client.latest_version(&foo), Some("1.0.0".parse().unwrap()));
+
+    assert_eq!(
+      client.releases(&foo),
+      Some(vec![Release {
+        version: "1.0.0".parse().unwrap(),
+        yanked: None,
+      }])
+    );
+
+    assert_eq!(client.latest_version(&"bar".parse().unwrap()), None);
+
+    assert_eq!(
+      client.requires_python_for(&foo, &"1.0.0".parse().unwrap()),
+      None
+    );
+  }
+
+  #[test]
+  fn release_requires_python() {
+    let mut server = Server::new();
+
+    let old = server
+      .mock("GET", "/pypi/foo/1.0.0/json")
+      .with_body(r#"{ "info": { "requires_python": "" } }"#)
+      .create();
+
+    let new = server
+      .mock("GET", "/pypi/foo/2.0.0/json")
+      .with_body(r#"{ "info": { "requires_python": ">=3.10" } }"#)
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let package = "foo".parse().unwrap();
+
+    for _ in 0..2 {
+      assert_eq!(
+        client.requires_python_for(&package, &"1.0.0".parse().unwrap()),
+        None
+      );
+
+      assert_eq!(
+        client.requires_python_for(&package, &"2.0.0".parse().unwrap()),
+        Some(">=3.10".parse().unwrap())
+      );
+    }
+
+    old.assert();
+    new.assert();
+  }
+
+  #[test]
+  fn releases() {
+    let mut server = Server::new();
+
+    let mock = server
+      .mock("GET", "/pypi/foo/json")
+      .with_body(
+        r#"{
+          "info": { "version": "2.0.0" },
+          "releases": {
+            "2.0.0": [{ "yanked": false }],
+            "1.0.0": [
+              { "yanked": true, "yanked_reason": "security" },
+              { "yanked": true }
+            ],
+            "1.1.0": [{ "yanked": true }],
+            "0.1.0": []
+          }
+        }"#,
+      )
+      .create();
+
+    let client = PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: None,
+      cache_ttl: Duration::ZERO,
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline: false,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    assert_eq!(
+      client.releases(&"foo".parse().unwrap()),
+      Some(vec![
+        Release {
+          version: "1.0.0".parse().unwrap(),
+          yanked: Some("security".to_string()),
+        },
+        Release {
+          version: "1.1.0".parse().unwrap(),
+          yanked: Some(String::new()),
+        },
+        Release {
+          version: "2.0.0".parse().unwrap(),
+          yanked: None,
+        },
+      ])
+    );
+
+    mock.assert();
+  }
+
+  #[test]
+  fn releases_cache() {
+    let tempdir = tempfile::TempDir::new().unwrap();
+
+    let mut server = Server::new();
+
+    let mock = server
+      .mock("GET", "/pypi/foo/json")
+      .with_body(
+        r#"{
+          "info": { "version": "2.0.0" },
+          "releases": {
+            "1.0.0": [{ "yanked": true, "yanked_reason": "security" }],
+            "2.0.0": [{ "yanked": false }]
+          }
+        }"#,
+      )
+      .create();
+
+    let releases = Some(vec![
+      Release {
+        version: "1.0.0".parse().unwrap(),
+        yanked: Some("security".to_string()),
+      },
+      Release {
+        version: "2.0.0".parse().unwrap(),
+        yanked: None,
+      },
+    ]);
+
+    let client = |offline| PyPiClient {
+      base_url: server.url(),
+      cache: Mutex::new(HashMap::new()),
+      cache_dir: Some(tempdir.path().to_path_buf()),
+      cache_ttl: Duration::from_mins(1),
+      http: ReqwestClient::new(),
+      mocks: Mutex::new(HashMap::new()),
+      offline,
+      release_cache: Mutex::new(HashMap::new()),
+    };
+
+    let package = "foo".parse().unwrap();
+
+    assert_eq!(client(false).releases(&package), releases);
+
+    assert_eq!(client(true).releases(&package), releases);
+
+    mock.assert();
+  }
 }
@@ -82,6 +82,234 @@ mod tests {
     Quickfixer::new(parameters, &Analyzer::new(document).analyze()).collect()
   }
 
+  #[test]
+  fn returns_classifier_order_edits() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      classifiers = [
+        "Topic :: Utilities",
+        "Development Status :: 4 - Beta",
+      ]
+
+      [tool.pyproject.rules]
+      project-classifiers-order = "warning"
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (3, 14, 3, 14).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Group classifiers by category".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![
+              lsp::TextEdit {
+                range: (4, 2, 4, 22).range(),
+                new_text: "\"Development Status :: 4 - Beta\"".to_string(),
+              },
+              lsp::TextEdit {
+                range: (5, 2, 5, 34).range(),
+                new_text: "\"Topic :: Utilities\"".to_string(),
+              },
+            ],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
+  #[test]
+  fn returns_keyword_case_replacement() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      keywords = ["packaging", "TOML"]
+
+      [tool.pyproject.rules]
+      project-keywords-case = "warning"
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (3, 27, 3, 27).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Replace `TOML` with `toml`".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![lsp::TextEdit {
+              range: (3, 26, 3, 30).range(),
+              new_text: "toml".to_string(),
+            }],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
+  #[test]
+  fn returns_license_canonical_replacement() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      license = "mit"
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (3, 12, 3, 12).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Replace `mit` with `MIT`".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![lsp::TextEdit {
+              range: (3, 11, 3, 14).range(),
+              new_text: "MIT".to_string(),
+            }],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
+  #[test]
+  fn returns_marker_quote_replacement() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dependencies = [
+        "colorama; sys_platform == 'win32'",
+        'uvloop; sys_platform != "win32"',
+      ]
+
+      [tool.pyproject.rules]
+      project-dependencies-marker-quotes = "warning"
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (4, 4, 4, 4).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Use double quotes in environment marker".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![lsp::TextEdit {
+              range: (4, 2, 4, 37).range(),
+              new_text: "'colorama; sys_platform == \"win32\"'".to_string(),
+            }],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
+  #[test]
+  fn returns_optional_dependency_extra_rename() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+
+      [project.optional-dependencies]
+      Test_Utils = ["pytest"]
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (5, 2, 5, 2).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Replace `Test_Utils` with `test-utils`".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![lsp::TextEdit {
+              range: (5, 0, 5, 10).range(),
+              new_text: "test-utils".to_string(),
+            }],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
   #[test]
   fn returns_project_name_normalization_replacement() {
     let document = Document::from(indoc! {
@@ -124,4 +352,67 @@ mod tests {
       })]
     );
   }
+
+  #[test]
+  fn returns_version_normalization_replacement() {
+    let document = Document::from(indoc! {
+      r"
+      [project]
+      name = 'demo'
+      version = '1.0-1'
+      "
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (2, 12, 2, 12).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(
+      actions(&parameters, &document),
+      vec![lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
+        title: "Replace `1.0-1` with `1.0.post1`".to_string(),
+        kind: Some(lsp::CodeActionKind::QUICKFIX),
+        edit: Some(lsp::WorkspaceEdit {
+          changes: Some(HashMap::from([(
+            document.uri,
+            vec![lsp::TextEdit {
+              range: (2, 11, 2, 16).range(),
+              new_text: "1.0.post1".to_string(),
+            }],
+          )])),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })]
+    );
+  }
+
+  #[test]
+  fn skips_version_normalization_replacement_in_multi_line_string() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = """1.0-1"""
+      "#
+    });
+
+    let parameters = lsp::CodeActionParams {
+      text_document: lsp::TextDocumentIdentifier {
+        uri: document.uri.clone(),
+      },
+      range: (2, 14, 2, 14).range(),
+      context: lsp::CodeActionContext::default(),
+      work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+      partial_result_params: lsp::PartialResultParams::default(),
+    };
+
+    assert_eq!(actions(&parameters, &document), Vec::new());
+  }
 }
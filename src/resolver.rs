@@ -3,16 +3,804 @@ use super::*;
 #[derive(Debug)]
 pub struct Resolver<'a> {
   document: &'a Document,
+  offline: bool,
 }
 
 impl<'a> Resolver<'a> {
+  const PACKAGE_EXTRAS: [(&'static str, &'static [&'static str]); 10] = [
+    ("black", &["colorama", "d", "jupyter", "uvloop"]),
+    ("celery", &["msgpack", "redis", "sqs", "yaml"]),
+    ("coverage", &["toml"]),
+    ("fastapi", &["all", "standard"]),
+    ("httpx", &["brotli", "cli", "http2", "socks", "zstd"]),
+    ("psycopg", &["binary", "c", "pool"]),
+    ("pydantic", &["email", "timezone"]),
+    ("requests", &["security", "socks", "use-chardet-on-py3"]),
+    (
+      "sqlalchemy",
+      &[
+        "aiosqlite",
+        "asyncio",
+        "mypy",
+        "mysql",
+        "postgresql",
+        "postgresql-asyncpg",
+      ],
+    ),
+    ("uvicorn", &["standard"]),
+  ];
+
+  const README_CONTENT_TYPES: [(&'static str, &'static str); 3] = [
+    ("text/markdown", "Markdown readme"),
+    ("text/x-rst", "reStructuredText readme"),
+    ("text/plain", "Plain text readme"),
+  ];
+
+  fn collect_symbols(
+    &self,
+    node: &Node,
+    container: Option<&str>,
+    query: &str,
+    symbols: &mut Vec<lsp::SymbolInformation>,
+  ) {
+    let Some(table) = node.as_table() else {
+      return;
+    };
+
+    for (key, value) in table.entries().read().iter() {
+      let name = match container {
+        Some(container) => format!("{container}.{}", key.value()),
+        None => key.value().to_string(),
+      };
+
+      let kind = if value.is_table() {
+        lsp::SymbolKind::MODULE
+      } else {
+        lsp::SymbolKind::PROPERTY
+      };
+
+      if name.to_lowercase().contains(query) {
+        #[allow(deprecated)]
+        symbols.push(lsp::SymbolInformation {
+          name: name.clone(),
+          kind,
+          tags: None,
+          deprecated: None,
+          location: lsp::Location {
+            uri: self.document.uri.clone(),
+            range: key.span(&self.document.content),
+          },
+          container_name: container.map(str::to_string),
+        });
+      }
+
+      self.collect_symbols(value, Some(&name), query, symbols);
+    }
+  }
+
+  fn dependency_group_occurrences(
+    &self,
+  ) -> Vec<(String, lsp::Range, lsp::DocumentHighlightKind)> {
+    let content = &self.document.content;
+
+    let Ok(groups) = self
+      .document
+      .tree
+      .clone()
+      .into_dom()
+      .try_get("dependency-groups")
+    else {
+      return Vec::new();
+    };
+
+    let Some(table) = groups.as_table() else {
+      return Vec::new();
+    };
+
+    let mut occurrences = Vec::new();
+
+    for (key, value) in table.entries().read().iter() {
+      occurrences.push((
+        DependencyGroupsRule::normalize_group_name(key.value()),
+        key.span(content),
+        lsp::DocumentHighlightKind::WRITE,
+      ));
+
+      let Some(array) = value.as_array() else {
+        continue;
+      };
+
+      for item in array.items().read().iter() {
+        let Ok(include) = item.try_get("include-group") else {
+          continue;
+        };
+
+        let Some(name) = include.as_str() else {
+          continue;
+        };
+
+        occurrences.push((
+          DependencyGroupsRule::normalize_group_name(name.value()),
+          include.span(content),
+          lsp::DocumentHighlightKind::READ,
+        ));
+      }
+    }
+
+    occurrences
+  }
+
+  fn dependency_hover(
+    &self,
+    position: lsp::Position,
+    package: impl Fn(&PackageName) -> Option<(Option<String>, Version)>,
+  ) -> Option<lsp::Hover> {
+    let content = &self.document.content;
+
+    let dependencies = RuleContext::new(self.document)
+      .get("project.dependencies")?
+      .as_array()?
+      .clone();
+
+    let items = dependencies.items().read();
+
+    let item = items.iter().find(|item| {
+      let range = item.span(content);
+      range.start <= position && position <= range.end
+    })?;
+
+    let requirement =
+      Requirement::<VerbatimUrl>::from_str(item.as_str()?.value()).ok()?;
+
+    let (summary, latest_version) = package(&requirement.name)?;
+
+    let heading = match summary {
+      Some(summary) => format!("**{}** — {summary}", requirement.name),
+      None => format!("**{}**", requirement.name),
+    };
+
+    Some(lsp::Hover {
+      contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+        kind: lsp::MarkupKind::Markdown,
+        value: format!("{heading}\n\nLatest: {latest_version}"),
+      }),
+      range: Some(item.span(content)),
+    })
+  }
+
+  fn document_symbols(&self, node: &Node) -> Vec<lsp::DocumentSymbol> {
+    let Some(table) = node.as_table() else {
+      return Vec::new();
+    };
+
+    let content = &self.document.content;
+
+    table
+      .entries()
+      .read()
+      .iter()
+      .map(|(key, value)| {
+        let (key_range, value_range) = (key.span(content), value.span(content));
+
+        let kind = match value {
+          Node::Table(_) => lsp::SymbolKind::OBJECT,
+          Node::Array(_) => lsp::SymbolKind::ARRAY,
+          _ => lsp::SymbolKind::FIELD,
+        };
+
+        let children = self.document_symbols(value);
+
+        let range = children.iter().fold(
+          lsp::Range {
+            start: key_range.start.min(value_range.start),
+            end: key_range.end.max(value_range.end),
+          },
+          |range, child| lsp::Range {
+            start: range.start.min(child.range.start),
+            end: range.end.max(child.range.end),
+          },
+        );
+
+        #[allow(deprecated)]
+        lsp::DocumentSymbol {
+          name: key.value().to_string(),
+          detail: None,
+          kind,
+          tags: None,
+          deprecated: None,
+          range,
+          selection_range: key_range,
+          children: (!children.is_empty()).then_some(children),
+        }
+      })
+      .collect()
+  }
+
+  /// Returns the `project.dynamic` field names that may still be added when
+  /// `token` lies inside the `project.dynamic` array, skipping fields already
+  /// declared statically in `[project]` or listed in the array.
+  fn dynamic_completions(
+    &self,
+    token: &SyntaxToken,
+  ) -> Option<Vec<lsp::CompletionItem>> {
+    if token.kind() == SyntaxKind::BRACKET_END {
+      return None;
+    }
+
+    let array = token
+      .parent_ancestors()
+      .find(|node| node.kind() == SyntaxKind::ARRAY)?;
+
+    let entry = array
+      .parent()
+      .filter(|node| node.kind() == SyntaxKind::VALUE)?
+      .parent()
+      .filter(|node| node.kind() == SyntaxKind::ENTRY)?;
+
+    if Self::entry_path(&entry)? != ["project", "dynamic"] {
+      return None;
+    }
+
+    let mut present = self
+      .document
+      .tree
+      .clone()
+      .into_dom()
+      .try_get("project")
+      .ok()
+      .and_then(|project| {
+        project.as_table().map(|table| {
+          table
+            .entries()
+            .read()
+            .iter()
+            .map(|(key, _)| key.value().to_string())
+            .collect::<HashSet<_>>()
+        })
+      })
+      .unwrap_or_default();
+
+    present.extend(
+      array
+        .descendants_with_tokens()
+        .filter_map(SyntaxElement::into_token)
+        .filter(|item| {
+          item != token
+            && matches!(
+              item.kind(),
+              SyntaxKind::STRING | SyntaxKind::STRING_LITERAL
+            )
+        })
+        .map(|item| item.text().trim_matches(['"', '\'']).to_string()),
+    );
+
+    Some(
+      DYNAMIC_FIELDS
+        .iter()
+        .filter(|field| !present.contains(**field))
+        .map(|field| {
+          Builtin::Value {
+            name: field,
+            description: "Dynamic `project` field",
+          }
+          .completion_item()
+        })
+        .collect(),
+    )
+  }
+
+  /// Returns the dotted path of `entry`, including the header of the table
+  /// it appears under, or `None` if `entry` is nested in an inline table.
+  fn entry_path(entry: &SyntaxNode) -> Option<Vec<String>> {
+    if entry.parent()?.kind() != SyntaxKind::ROOT {
+      return None;
+    }
+
+    let mut path = entry
+      .siblings(Direction::Prev)
+      .find(|node| {
+        matches!(
+          node.kind(),
+          SyntaxKind::TABLE_HEADER | SyntaxKind::TABLE_ARRAY_HEADER
+        )
+      })
+      .filter(|header| header.kind() == SyntaxKind::TABLE_HEADER)
+      .map(|header| Self::key_path(&header))
+      .unwrap_or_default();
+
+    path.extend(Self::key_path(entry));
+
+    Some(path)
+  }
+
+  /// Returns the known extras of the package named in the dependency string
+  /// under `offset` when the cursor follows an unclosed `[`, replacing the
+  /// partially typed extra and closing the bracket if needed.
+  fn extra_completions(
+    &self,
+    token: &SyntaxToken,
+    offset: usize,
+  ) -> Option<Vec<lsp::CompletionItem>> {
+    if !matches!(
+      token.kind(),
+      SyntaxKind::STRING | SyntaxKind::STRING_LITERAL
+    ) {
+      return None;
+    }
+
+    let entry = token
+      .parent_ancestors()
+      .take_while(|node| node.kind() != SyntaxKind::INLINE_TABLE)
+      .find(|node| node.kind() == SyntaxKind::ENTRY)?;
+
+    let in_array = token
+      .parent_ancestors()
+      .take_while(|node| node != &entry)
+      .any(|node| node.kind() == SyntaxKind::ARRAY);
+
+    let path = Self::entry_path(&entry)?;
+
+    let dependencies = match path.as_slice() {
+      [project, dependencies] => {
+        project == "project" && dependencies == "dependencies"
+      }
+      [project, optional, _] => {
+        project == "project" && optional == "optional-dependencies"
+      }
+      _ => false,
+    };
+
+    if !in_array || !dependencies {
+      return None;
+    }
+
+    let text = token.text();
+
+    let start = usize::from(token.text_range().start());
+
+    let quote = text.chars().next()?;
+
+    let cursor = offset.checked_sub(start + quote.len_utf8())?;
+
+    let inner = text.get(quote.len_utf8()..)?;
+
+    let prefix = inner.get(..cursor)?;
+
+    if prefix.contains(quote) {
+      return None;
+    }
+
+    let (name, extras) = prefix.split_once('[')?;
+
+    if extras.contains(']') {
+      return None;
+    }
+
+    let package = PackageName::from_str(name.trim()).ok()?;
+
+    let (_, known) = Self::PACKAGE_EXTRAS
+      .iter()
+      .find(|(name, _)| *name == package.as_ref())?;
+
+    let word_start = prefix.len()
+      - extras
+        .rsplit(',')
+        .next()
+        .unwrap_or_default()
+        .trim_start()
+        .len();
+
+    let rest = inner[cursor..].trim_end_matches(quote);
+
+    let word_end = cursor
+      + rest
+        .find(|character: char| {
+          !(character.is_alphanumeric() || matches!(character, '-' | '_' | '.'))
+        })
+        .unwrap_or(rest.len());
+
+    let closed = inner[word_end..].contains(']');
+
+    let range = (
+      u32::try_from(start + quote.len_utf8() + word_start).ok()?,
+      u32::try_from(start + quote.len_utf8() + word_end).ok()?,
+    )
+      .span(&self.document.content);
+
+    let description = format!("Extra of `{package}`");
+
+    Some(
+      known
+        .iter()
+        .map(|extra| {
+          let new_text = if closed {
+            (*extra).to_string()
+          } else {
+            format!("{extra}]")
+          };
+
+          lsp::CompletionItem {
+            filter_text: Some((*extra).to_string()),
+            insert_text: None,
+            text_edit: Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+              range,
+              new_text,
+            })),
+            ..Builtin::Value {
+              name: extra,
+              description: &description,
+            }
+            .completion_item()
+          }
+        })
+        .collect(),
+    )
+  }
+
+  fn inlay_hints(
+    &self,
+    range: lsp::Range,
+    latest_version: impl Fn(&PackageName) -> Option<Version>,
+  ) -> Vec<lsp::InlayHint> {
+    let context = RuleContext::new(self.document);
+
+    let mut arrays = Vec::new();
+
+    if let Some(dependencies) = context.get("project.dependencies") {
+      arrays.extend(dependencies.as_array().cloned());
+    }
+
+    if let Some(optional) = context.get("project.optional-dependencies")
+      && let Some(table) = optional.as_table()
+    {
+      arrays.extend(
+        table
+          .entries()
+          .read()
+          .iter()
+          .filter_map(|(_, value)| value.as_array().cloned()),
+      );
+    }
+
+    let mut hints = Vec::new();
+
+    for array in arrays {
+      for item in array.items().read().iter() {
+        let position = item.span(&self.document.content).end;
+
+        if position < range.start || range.end < position {
+          continue;
+        }
+
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+          &requirement.version_or_url
+        else {
+          continue;
+        };
+
+        let Some(latest) = latest_version(&requirement.name) else {
+          continue;
+        };
+
+        if specifiers.contains(&latest) {
+          continue;
+        }
+
+        hints.push(lsp::InlayHint {
+          position,
+          label: lsp::InlayHintLabel::String(format!("⇡ {latest}")),
+          kind: None,
+          text_edits: None,
+          tooltip: Some(lsp::InlayHintTooltip::String(format!(
+            "`{}` {latest} is available on PyPI",
+            requirement.name
+          ))),
+          padding_left: Some(true),
+          padding_right: None,
+          data: None,
+        });
+      }
+    }
+
+    hints
+  }
+
+  fn key_path(node: &SyntaxNode) -> Vec<String> {
+    node
+      .children()
+      .find(|child| child.kind() == SyntaxKind::KEY)
+      .into_iter()
+      .flat_map(|key| key.descendants_with_tokens())
+      .filter_map(SyntaxElement::into_token)
+      .filter(|token| token.kind() == SyntaxKind::IDENT)
+      .map(|token| token.text().trim_matches(['"', '\'']).to_string())
+      .collect()
+  }
+
+  fn license_hover(&self, position: lsp::Position) -> Option<lsp::Hover> {
+    let content = &self.document.content;
+
+    let license = self
+      .document
+      .tree
+      .clone()
+      .into_dom()
+      .try_get("project")
+      .ok()?
+      .try_get("license")
+      .ok()?;
+
+    license.as_str()?;
+
+    let range = license.text_ranges(false).next()?;
+
+    let (start, end) = (usize::from(range.start()), usize::from(range.end()));
+
+    let cursor = content.char_to_byte(content.lsp_position_to_char(position));
+
+    if cursor < start || cursor > end {
+      return None;
+    }
+
+    let text = content.byte_slice(start..end).to_string();
+
+    let is_token = |character: char| {
+      character.is_ascii_alphanumeric() || matches!(character, '-' | '.' | '+')
+    };
+
+    let offset = cursor - start;
+
+    let token_start = text[..offset]
+      .rfind(|character| !is_token(character))
+      .map_or(0, |index| index + 1);
+
+    let token_end = text[offset..]
+      .find(|character| !is_token(character))
+      .map_or(text.len(), |index| offset + index);
+
+    let id = spdx::license_id(&text[token_start..token_end])?;
+
+    let flag = |value: bool| if value { "yes" } else { "no" };
+
+    Some(lsp::Hover {
+      contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+        kind: lsp::MarkupKind::Markdown,
+        value: format!(
+          "**{}** (`{}`)\n\n- OSI approved: {}\n- FSF free/libre: {}\n- Deprecated: {}",
+          id.full_name,
+          id.name,
+          flag(id.is_osi_approved()),
+          flag(id.is_fsf_free_libre()),
+          flag(id.is_deprecated()),
+        ),
+      }),
+      range: Some(lsp::Range {
+        start: content.byte_to_lsp_position(start + token_start),
+        end: content.byte_to_lsp_position(start + token_end),
+      }),
+    })
+  }
+
   #[must_use]
   pub fn new(document: &'a Document) -> Self {
-    Self { document }
+    Self {
+      document,
+      offline: false,
+    }
+  }
+
+  /// Answers package index lookups from cached results only, as rules do in
+  /// an offline analysis.
+  #[must_use]
+  pub fn offline(self, offline: bool) -> Self {
+    Self { offline, ..self }
+  }
+
+  fn pypi(&self) -> &'static PyPiClient {
+    RuleContext::new(self.document).offline(self.offline).pypi()
+  }
+
+  /// Returns the string values already present in the array enclosing
+  /// `position`, so completions don't re-offer them after a comma. The item
+  /// under the cursor is left out, since it's the one being typed.
+  #[must_use]
+  pub fn resolve_array_items(
+    &self,
+    position: lsp::Position,
+  ) -> HashSet<String> {
+    let content = &self.document.content;
+
+    let offset = content.char_to_byte(content.lsp_position_to_char(position));
+
+    let Some(token) = TextSize::try_from(offset).ok().and_then(|offset| {
+      self
+        .document
+        .tree
+        .clone()
+        .into_syntax()
+        .token_at_offset(offset)
+        .left_biased()
+    }) else {
+      return HashSet::new();
+    };
+
+    let Some(array) = token
+      .parent_ancestors()
+      .find(|node| node.kind() == SyntaxKind::ARRAY)
+    else {
+      return HashSet::new();
+    };
+
+    array
+      .children()
+      .filter(|node| node.kind() == SyntaxKind::VALUE)
+      .flat_map(|value| value.children_with_tokens())
+      .filter_map(SyntaxElement::into_token)
+      .filter(|item| {
+        matches!(item.kind(), SyntaxKind::STRING | SyntaxKind::STRING_LITERAL)
+      })
+      .filter(|item| item != &token)
+      .map(|item| item.text().trim_matches(['"', '\'']).to_string())
+      .collect()
+  }
+
+  /// Returns completions specific to the value under `position`, such as the
+  /// supported values of `project.readme.content-type`, the known extras of
+  /// a dependency, or the fields that may be listed in `project.dynamic`, or
+  /// `None` when the position has no value-specific
+  /// completions.
+  #[must_use]
+  pub fn resolve_completions(
+    &self,
+    position: lsp::Position,
+  ) -> Option<Vec<lsp::CompletionItem>> {
+    let content = &self.document.content;
+
+    let offset = content.char_to_byte(content.lsp_position_to_char(position));
+
+    let token = self
+      .document
+      .tree
+      .clone()
+      .into_syntax()
+      .token_at_offset(TextSize::try_from(offset).ok()?)
+      .left_biased()?;
+
+    if let Some(items) = self.extra_completions(&token, offset) {
+      return Some(items);
+    }
+
+    if let Some(items) = self.dynamic_completions(&token) {
+      return Some(items);
+    }
+
+    let entry = token
+      .parent_ancestors()
+      .find(|node| node.kind() == SyntaxKind::ENTRY)?;
+
+    let after_equals = entry
+      .children_with_tokens()
+      .find(|element| element.kind() == SyntaxKind::EQ)
+      .is_some_and(|equals| usize::from(equals.text_range().end()) <= offset);
+
+    if !after_equals || Self::key_path(&entry) != ["content-type"] {
+      return None;
+    }
+
+    let readme = entry
+      .parent()
+      .filter(|node| node.kind() == SyntaxKind::INLINE_TABLE)?
+      .parent()
+      .filter(|node| node.kind() == SyntaxKind::VALUE)?
+      .parent()
+      .filter(|node| node.kind() == SyntaxKind::ENTRY)?;
+
+    if Self::entry_path(&readme)? != ["project", "readme"] {
+      return None;
+    }
+
+    let string = matches!(
+      token.kind(),
+      SyntaxKind::STRING | SyntaxKind::STRING_LITERAL
+    )
+    .then(|| token.text_range().span(content));
+
+    Some(
+      Self::README_CONTENT_TYPES
+        .iter()
+        .map(|(name, description)| {
+          let item = Builtin::Value { name, description }.completion_item();
+
+          match string {
+            Some(range) => lsp::CompletionItem {
+              text_edit: Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+                range,
+                new_text: format!("\"{name}\""),
+              })),
+              ..item
+            },
+            None => item,
+          }
+        })
+        .collect(),
+    )
+  }
+
+  #[must_use]
+  pub fn resolve_definition(
+    &self,
+    position: lsp::Position,
+  ) -> Option<lsp::Location> {
+    let occurrences = self.dependency_group_occurrences();
+
+    let (target, _, _) = occurrences.iter().find(|(_, range, kind)| {
+      *kind == lsp::DocumentHighlightKind::READ
+        && range.start <= position
+        && position <= range.end
+    })?;
+
+    occurrences
+      .iter()
+      .find(|(name, _, kind)| {
+        name == target && *kind == lsp::DocumentHighlightKind::WRITE
+      })
+      .map(|(_, range, _)| lsp::Location {
+        uri: self.document.uri.clone(),
+        range: *range,
+      })
+  }
+
+  #[must_use]
+  pub fn resolve_document_highlights(
+    &self,
+    position: lsp::Position,
+  ) -> Option<Vec<lsp::DocumentHighlight>> {
+    let occurrences = self.dependency_group_occurrences();
+
+    let (target, _, _) = occurrences
+      .iter()
+      .find(|(_, range, _)| range.start <= position && position <= range.end)?;
+
+    Some(
+      occurrences
+        .iter()
+        .filter(|(name, _, _)| name == target)
+        .map(|(_, range, kind)| lsp::DocumentHighlight {
+          range: *range,
+          kind: Some(*kind),
+        })
+        .collect(),
+    )
+  }
+
+  #[must_use]
+  pub fn resolve_document_symbols(&self) -> Vec<lsp::DocumentSymbol> {
+    self.document_symbols(&self.document.tree.clone().into_dom())
   }
 
   #[must_use]
   pub fn resolve_hover(&self, position: lsp::Position) -> Option<lsp::Hover> {
+    if let Some(hover) = self.license_hover(position) {
+      return Some(hover);
+    }
+
+    if let Some(hover) = self.dependency_hover(position, |package| {
+      let pypi = self.pypi();
+      Some((pypi.summary(package), pypi.latest_version(package)?))
+    }) {
+      return Some(hover);
+    }
+
     let (instance, pointers) = SchemaPointer::build(self.document).ok()?;
 
     let pointer = pointers.pointer_for_position(position)?;
@@ -44,12 +832,643 @@ impl<'a> Resolver<'a> {
       ),
     })
   }
+
+  /// Returns hints showing the latest release on the package index after each
+  /// dependency in `range` whose constraint excludes it.
+  #[must_use]
+  pub fn resolve_inlay_hints(&self, range: lsp::Range) -> Vec<lsp::InlayHint> {
+    self.inlay_hints(range, |package| self.pypi().latest_version(package))
+  }
+
+  #[must_use]
+  pub fn resolve_prepare_rename(
+    &self,
+    position: lsp::Position,
+  ) -> Option<lsp::PrepareRenameResponse> {
+    let (_, range, _) = self
+      .dependency_group_occurrences()
+      .into_iter()
+      .find(|(_, range, _)| range.start <= position && position <= range.end)?;
+
+    Some(lsp::PrepareRenameResponse::Range(self.unquoted(range)))
+  }
+
+  /// Returns a snippet that scaffolds a `[project]` table with tab stops for
+  /// its core fields when the cursor is on an otherwise empty line or in a
+  /// partially typed table header, unless the document already declares one.
+  #[must_use]
+  pub fn resolve_project_scaffold(
+    &self,
+    position: lsp::Position,
+  ) -> Option<lsp::CompletionItem> {
+    if self
+      .document
+      .tree
+      .clone()
+      .into_dom()
+      .try_get("project")
+      .is_ok()
+    {
+      return None;
+    }
+
+    let content = &self.document.content;
+
+    let cursor = content.lsp_position_to_char(position);
+
+    let line = content.char_to_line(cursor);
+
+    let start = content.line_to_char(line);
+
+    let text = content.line(line).to_string();
+
+    let (before, after) =
+      text.split_at(content.char_to_byte(cursor) - content.char_to_byte(start));
+
+    let typed =
+      before.trim_start_matches(|c: char| c.is_whitespace() || c == '\u{feff}');
+
+    let header = typed.strip_prefix('[').is_some_and(|name| {
+      name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    });
+
+    if !(typed.is_empty() || header) || !matches!(after.trim(), "" | "]") {
+      return None;
+    }
+
+    let token = TextSize::try_from(content.char_to_byte(cursor))
+      .ok()
+      .and_then(|offset| {
+        self
+          .document
+          .tree
+          .clone()
+          .into_syntax()
+          .token_at_offset(offset)
+          .left_biased()
+      });
+
+    if token.is_some_and(|token| {
+      matches!(
+        token.kind(),
+        SyntaxKind::MULTI_LINE_STRING | SyntaxKind::MULTI_LINE_STRING_LITERAL
+      ) || token.parent_ancestors().any(|node| {
+        matches!(node.kind(), SyntaxKind::ARRAY | SyntaxKind::INLINE_TABLE)
+      })
+    }) {
+      return None;
+    }
+
+    let line_start = content.char_to_byte(start);
+
+    let range = lsp::Range {
+      start: content
+        .byte_to_lsp_position(line_start + before.len() - typed.len()),
+      end: content.byte_to_lsp_position(
+        line_start + before.len() + after.trim_end().len(),
+      ),
+    };
+
+    let snippet = [
+      "[project]",
+      "name = \"${1:name}\"",
+      "version = \"${2:0.1.0}\"",
+      "description = \"${3:description}\"",
+      "requires-python = \"${4:>=3.10}\"",
+      "$0",
+    ]
+    .join("\n");
+
+    Some(lsp::CompletionItem {
+      label: "project (scaffold)".to_string(),
+      kind: Some(lsp::CompletionItemKind::SNIPPET),
+      detail: Some("Scaffold a `[project]` table".to_string()),
+      filter_text: Some(
+        if header { "[project" } else { "project" }.to_string(),
+      ),
+      insert_text: Some(snippet.clone()),
+      insert_text_format: Some(lsp::InsertTextFormat::SNIPPET),
+      text_edit: Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+        range,
+        new_text: snippet,
+      })),
+      ..Default::default()
+    })
+  }
+
+  /// Renames the dependency group under `position` along with every
+  /// `include-group` reference to it.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `new_name` is not a valid dependency group name.
+  pub fn resolve_rename(
+    &self,
+    position: lsp::Position,
+    new_name: &str,
+  ) -> Result<Option<lsp::WorkspaceEdit>, String> {
+    if !PROJECT_NAME.is_match(new_name) {
+      return Err(format!("`{new_name}` is not a valid dependency group name"));
+    }
+
+    let occurrences = self.dependency_group_occurrences();
+
+    let Some((target, _, _)) = occurrences
+      .iter()
+      .find(|(_, range, _)| range.start <= position && position <= range.end)
+    else {
+      return Ok(None);
+    };
+
+    let edits = occurrences
+      .iter()
+      .filter(|(name, _, _)| name == target)
+      .map(|(_, range, kind)| {
+        let unquoted = self.unquoted(*range);
+
+        let new_text = if unquoted == *range
+          && *kind == lsp::DocumentHighlightKind::WRITE
+          && new_name.contains('.')
+        {
+          format!("\"{new_name}\"")
+        } else {
+          new_name.to_string()
+        };
+
+        lsp::TextEdit {
+          range: unquoted,
+          new_text,
+        }
+      })
+      .collect();
+
+    Ok(Some(lsp::WorkspaceEdit {
+      changes: Some(HashMap::from([(self.document.uri.clone(), edits)])),
+      ..Default::default()
+    }))
+  }
+
+  #[must_use]
+  pub fn resolve_workspace_symbols(
+    &self,
+    query: &str,
+  ) -> Vec<lsp::SymbolInformation> {
+    let query = query.to_lowercase();
+
+    let mut symbols = Vec::new();
+
+    self.collect_symbols(
+      &self.document.tree.clone().into_dom(),
+      None,
+      &query,
+      &mut symbols,
+    );
+
+    symbols
+  }
+
+  fn unquoted(&self, range: lsp::Range) -> lsp::Range {
+    let content = &self.document.content;
+
+    let start = content.lsp_position_to_char(range.start);
+    let end = content.lsp_position_to_char(range.end);
+
+    let text = content.slice(start..end).to_string();
+
+    let quoted = text.len() >= 2
+      && ['"', '\'']
+        .into_iter()
+        .any(|quote| text.starts_with(quote) && text.ends_with(quote));
+
+    if !quoted {
+      return range;
+    }
+
+    lsp::Range {
+      start: content.byte_to_lsp_position(content.char_to_byte(start + 1)),
+      end: content.byte_to_lsp_position(content.char_to_byte(end - 1)),
+    }
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use {super::*, indoc::indoc, pretty_assertions::assert_eq};
 
+  #[test]
+  fn resolve_array_items_excludes_item_under_cursor() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      classifiers = ["Typing :: Typed", 'Framework :: Django', "Fram"]
+      keywords = ["cli"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let mut items = resolver
+      .resolve_array_items(lsp::Position::new(1, 61))
+      .into_iter()
+      .collect::<Vec<_>>();
+
+    items.sort();
+
+    assert_eq!(items, ["Framework :: Django", "Typing :: Typed"]);
+
+    let mut items = resolver
+      .resolve_array_items(lsp::Position::new(1, 56))
+      .into_iter()
+      .collect::<Vec<_>>();
+
+    items.sort();
+
+    assert_eq!(items, ["Fram", "Framework :: Django", "Typing :: Typed"]);
+
+    assert!(
+      resolver
+        .resolve_array_items(lsp::Position::new(2, 0))
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn resolve_completions_offers_dynamic_fields() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "foo"
+      version = "1.0.0"
+      description = "bar"
+      dynamic = ["readme", ]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let labels = resolver
+      .resolve_completions(lsp::Position::new(4, 21))
+      .unwrap()
+      .into_iter()
+      .map(|item| item.label)
+      .collect::<Vec<_>>();
+
+    assert!(labels.contains(&"dependencies".to_string()));
+    assert!(!labels.contains(&"description".to_string()));
+    assert!(!labels.contains(&"name".to_string()));
+    assert!(!labels.contains(&"readme".to_string()));
+    assert!(!labels.contains(&"version".to_string()));
+
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(3, 15)),
+      None
+    );
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(4, 23)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_completions_offers_package_extras() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      dependencies = ["requests[sec"]
+
+      [project.optional-dependencies]
+      http = ["httpx[http2,so]"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let items = resolver
+      .resolve_completions(lsp::Position::new(1, 29))
+      .unwrap();
+
+    assert_eq!(
+      items
+        .iter()
+        .map(|item| item.label.as_str())
+        .collect::<Vec<_>>(),
+      ["security", "socks", "use-chardet-on-py3"]
+    );
+
+    assert_eq!(items[0].kind, Some(lsp::CompletionItemKind::ENUM_MEMBER));
+
+    assert_eq!(
+      items[0].text_edit,
+      Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+        range: (1, 26, 1, 29).range(),
+        new_text: "security]".to_string(),
+      }))
+    );
+
+    let items = resolver
+      .resolve_completions(lsp::Position::new(4, 23))
+      .unwrap();
+
+    assert_eq!(
+      items[3].text_edit,
+      Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+        range: (4, 21, 4, 23).range(),
+        new_text: "socks".to_string(),
+      }))
+    );
+  }
+
+  #[test]
+  fn resolve_completions_ignores_unknown_package_extras() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      dependencies = ["unknown[sec", "requests"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(1, 28)),
+      None
+    );
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(1, 40)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_completions_offers_readme_content_types() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      readme = { file = "README.md", content-type = "text/" }
+      description = "text"
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let items = resolver
+      .resolve_completions(lsp::Position::new(1, 50))
+      .unwrap();
+
+    assert_eq!(
+      items
+        .iter()
+        .map(|item| item.label.as_str())
+        .collect::<Vec<_>>(),
+      ["text/markdown", "text/x-rst", "text/plain"]
+    );
+
+    assert_eq!(
+      items[0].text_edit,
+      Some(lsp::CompletionTextEdit::Edit(lsp::TextEdit {
+        range: (1, 46, 1, 53).range(),
+        new_text: "\"text/markdown\"".to_string(),
+      }))
+    );
+
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(1, 24)),
+      None
+    );
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(1, 35)),
+      None
+    );
+    assert_eq!(
+      resolver.resolve_completions(lsp::Position::new(2, 16)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_completions_offers_readme_content_types_without_value() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      readme = { file = "README.md", content-type = }
+      "#
+    });
+
+    let items = Resolver::new(&document)
+      .resolve_completions(lsp::Position::new(1, 46))
+      .unwrap();
+
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].insert_text.as_deref(), Some("\"text/markdown\""));
+    assert_eq!(items[0].text_edit, None);
+  }
+
+  #[test]
+  fn resolve_definition_ignores_missing_groups() {
+    let document = Document::from(indoc! {
+      r#"
+      [dependency-groups]
+      dev = [{ include-group = "test" }]
+      "#
+    });
+
+    assert_eq!(
+      Resolver::new(&document).resolve_definition(lsp::Position::new(1, 28)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_document_highlights_matches_normalized_group_names() {
+    let document = Document::from(indoc! {
+      r#"
+      [dependency-groups]
+      Test_Utils = ["pytest"]
+      dev = [{ include-group = "test-utils" }, "ruff"]
+      docs = ["sphinx"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let expected = vec![
+      lsp::DocumentHighlight {
+        range: (1, 0, 1, 10).range(),
+        kind: Some(lsp::DocumentHighlightKind::WRITE),
+      },
+      lsp::DocumentHighlight {
+        range: (2, 25, 2, 37).range(),
+        kind: Some(lsp::DocumentHighlightKind::READ),
+      },
+    ];
+
+    assert_eq!(
+      resolver.resolve_document_highlights(lsp::Position::new(1, 3)),
+      Some(expected.clone())
+    );
+
+    assert_eq!(
+      resolver.resolve_document_highlights(lsp::Position::new(2, 30)),
+      Some(expected)
+    );
+
+    assert_eq!(
+      resolver.resolve_document_highlights(lsp::Position::new(3, 8)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_workspace_symbols_matches_nested_keys() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+
+      [tool.poetry]
+      name = "demo"
+      "#
+    });
+
+    let names = Resolver::new(&document)
+      .resolve_workspace_symbols("NAME")
+      .into_iter()
+      .map(|symbol| (symbol.name, symbol.kind, symbol.location.range))
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      names,
+      vec![
+        (
+          "project.name".to_string(),
+          lsp::SymbolKind::PROPERTY,
+          (1, 0, 1, 4).range()
+        ),
+        (
+          "tool.poetry.name".to_string(),
+          lsp::SymbolKind::PROPERTY,
+          (4, 0, 4, 4).range()
+        ),
+      ]
+    );
+
+    assert_eq!(
+      Resolver::new(&document)
+        .resolve_workspace_symbols("poetry")
+        .into_iter()
+        .map(|symbol| symbol.name)
+        .collect::<Vec<_>>(),
+      vec!["tool.poetry".to_string(), "tool.poetry.name".to_string()]
+    );
+  }
+
+  #[test]
+  fn resolve_hover_describes_license_identifiers() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      license = "MIT OR GPL-2.0+"
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    assert_eq!(
+      resolver.resolve_hover(lsp::Position::new(1, 12)).unwrap(),
+      lsp::Hover {
+        contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+          kind: lsp::MarkupKind::Markdown,
+          value: "**MIT License** (`MIT`)\n\n- OSI approved: yes\n- FSF free/libre: yes\n- Deprecated: no".to_string(),
+        }),
+        range: Some((1, 11, 1, 14).range()),
+      }
+    );
+
+    assert_eq!(
+      resolver
+        .resolve_hover(lsp::Position::new(1, 20))
+        .unwrap()
+        .range,
+      Some((1, 18, 1, 26).range()),
+    );
+
+    assert!(resolver.license_hover(lsp::Position::new(1, 16)).is_none());
+  }
+
+  #[test]
+  fn resolve_hover_describes_dependencies() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dependencies = ["requests>=2", "unknown"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let package = |package: &PackageName| {
+      (package.as_ref() == "requests").then(|| {
+        (
+          Some("Python HTTP for Humans.".to_string()),
+          Version::from_str("2.32.3").unwrap(),
+        )
+      })
+    };
+
+    assert_eq!(
+      resolver.dependency_hover(lsp::Position::new(3, 20), package),
+      Some(lsp::Hover {
+        contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+          kind: lsp::MarkupKind::Markdown,
+          value: "**requests** — Python HTTP for Humans.\n\nLatest: 2.32.3"
+            .to_string(),
+        }),
+        range: Some((3, 16, 3, 29).range()),
+      })
+    );
+
+    assert_eq!(
+      resolver.dependency_hover(lsp::Position::new(3, 34), package),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_hover_looks_up_dependencies_through_gated_client() {
+    PyPiClient::shared().set_mock_latest_version("hover-mock", "2.0.0");
+
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      dependencies = ["hover-mock>=1"]
+      "#
+    });
+
+    let position = lsp::Position::new(1, 20);
+
+    assert_eq!(
+      Resolver::new(&document).resolve_hover(position),
+      Some(lsp::Hover {
+        contents: lsp::HoverContents::Markup(lsp::MarkupContent {
+          kind: lsp::MarkupKind::Markdown,
+          value: "**hover-mock**\n\nLatest: 2.0.0".to_string(),
+        }),
+        range: Some((1, 16, 1, 31).range()),
+      })
+    );
+
+    assert!(
+      Resolver::new(&document)
+        .offline(true)
+        .resolve_hover(position)
+        .is_none_or(|hover| hover.range != Some((1, 16, 1, 31).range()))
+    );
+  }
+
   #[test]
   fn resolve_hover_returns_schema_description() {
     let document = Document::from(indoc! {
@@ -74,4 +1493,226 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn resolve_inlay_hints_shows_excluded_latest_versions() {
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      dependencies = ["requests>=2,<3", "rich>=13"]
+
+      [project.optional-dependencies]
+      test = ["pytest<8", "tox @ https://example.com/tox.tar.gz"]
+      "#
+    });
+
+    let latest_version = |package: &PackageName| match package.as_ref() {
+      "requests" => Some(Version::from_str("3.0.1").unwrap()),
+      "rich" => Some(Version::from_str("14.0.0").unwrap()),
+      "pytest" => Some(Version::from_str("8.4.2").unwrap()),
+      _ => None,
+    };
+
+    let hints = Resolver::new(&document)
+      .inlay_hints((0, 0, 5, 0).range(), latest_version)
+      .into_iter()
+      .map(|hint| {
+        let lsp::InlayHintLabel::String(label) = hint.label else {
+          unreachable!();
+        };
+
+        (hint.position, label)
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      hints,
+      vec![
+        (lsp::Position::new(1, 32), "⇡ 3.0.1".to_string()),
+        (lsp::Position::new(4, 18), "⇡ 8.4.2".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn resolve_inlay_hints_uses_gated_client() {
+    PyPiClient::shared().set_mock_latest_version("inlay-mock", "3.0.1");
+
+    let document = Document::from(indoc! {
+      r#"
+      [project]
+      dependencies = ["inlay-mock<3"]
+      "#
+    });
+
+    let labels = |resolver: Resolver| {
+      resolver
+        .resolve_inlay_hints((0, 0, 2, 0).range())
+        .into_iter()
+        .filter_map(|hint| match hint.label {
+          lsp::InlayHintLabel::String(label) => Some(label),
+          lsp::InlayHintLabel::LabelParts(_) => None,
+        })
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(labels(Resolver::new(&document)), vec!["⇡ 3.0.1"]);
+
+    assert!(labels(Resolver::new(&document).offline(true)).is_empty());
+  }
+
+  #[test]
+  fn resolve_prepare_rename_targets_dependency_groups() {
+    let document = Document::from(indoc! {
+      r#"
+      [dependency-groups]
+      test = ["pytest"]
+      dev = [{ include-group = "test" }, "ruff"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    assert_eq!(
+      resolver.resolve_prepare_rename(lsp::Position::new(1, 2)),
+      Some(lsp::PrepareRenameResponse::Range((1, 0, 1, 4).range()))
+    );
+
+    assert_eq!(
+      resolver.resolve_prepare_rename(lsp::Position::new(2, 28)),
+      Some(lsp::PrepareRenameResponse::Range((2, 26, 2, 30).range()))
+    );
+
+    assert_eq!(
+      resolver.resolve_prepare_rename(lsp::Position::new(2, 38)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_project_scaffold_inserts_snippet() {
+    let item = Resolver::new(&Document::from(""))
+      .resolve_project_scaffold(lsp::Position::new(0, 0))
+      .unwrap();
+
+    assert_eq!(item.label, "project (scaffold)");
+    assert_eq!(
+      item.insert_text_format,
+      Some(lsp::InsertTextFormat::SNIPPET)
+    );
+    assert_eq!(
+      item.insert_text.as_deref(),
+      Some(indoc! {
+        r#"
+        [project]
+        name = "${1:name}"
+        version = "${2:0.1.0}"
+        description = "${3:description}"
+        requires-python = "${4:>=3.10}"
+        $0"#
+      })
+    );
+
+    assert_eq!(
+      Resolver::new(&Document::from("[project]\nname = \"foo\"\n"))
+        .resolve_project_scaffold(lsp::Position::new(2, 0)),
+      None
+    );
+  }
+
+  #[test]
+  fn resolve_project_scaffold_requires_header_or_empty_line() {
+    let document = Document::from(indoc! {
+      r#"
+      [tool.demo]
+      key = "value"
+      items = [
+        "a",
+
+      ]
+
+      [pro]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    let item = resolver
+      .resolve_project_scaffold(lsp::Position::new(7, 4))
+      .unwrap();
+
+    assert_eq!(item.filter_text.as_deref(), Some("[project"));
+
+    let Some(lsp::CompletionTextEdit::Edit(edit)) = item.text_edit else {
+      panic!("expected a text edit");
+    };
+
+    assert_eq!(edit.range, (7, 0, 7, 5).range());
+
+    assert!(
+      resolver
+        .resolve_project_scaffold(lsp::Position::new(6, 0))
+        .is_some()
+    );
+
+    for position in [(1, 0), (1, 6), (1, 9), (4, 0), (0, 6)] {
+      assert_eq!(
+        resolver
+          .resolve_project_scaffold(lsp::Position::new(position.0, position.1)),
+        None,
+        "{position:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn resolve_rename_updates_group_and_references() {
+    let document = Document::from(indoc! {
+      r#"
+      [dependency-groups]
+      Test = ["pytest"]
+      dev = [{ include-group = "test" }, "ruff"]
+      docs = ["sphinx"]
+      "#
+    });
+
+    let resolver = Resolver::new(&document);
+
+    assert_eq!(
+      resolver
+        .resolve_rename(lsp::Position::new(2, 28), "tests")
+        .unwrap(),
+      Some(lsp::WorkspaceEdit {
+        changes: Some(HashMap::from([(
+          document.uri.clone(),
+          vec![
+            lsp::TextEdit {
+              range: (1, 0, 1, 4).range(),
+              new_text: "tests".to_string(),
+            },
+            lsp::TextEdit {
+              range: (2, 26, 2, 30).range(),
+              new_text: "tests".to_string(),
+            },
+          ],
+        )])),
+        ..Default::default()
+      })
+    );
+
+    assert_eq!(
+      resolver
+        .resolve_rename(lsp::Position::new(1, 1), "test.unit")
+        .unwrap()
+        .and_then(|edit| edit.changes)
+        .unwrap()[&document.uri][0]
+        .new_text,
+      "\"test.unit\""
+    );
+
+    assert_eq!(
+      resolver.resolve_rename(lsp::Position::new(1, 1), "not valid"),
+      Err("`not valid` is not a valid dependency group name".to_string())
+    );
+  }
 }
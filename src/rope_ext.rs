@@ -15,6 +15,7 @@ pub trait RopeExt {
   ) -> Edit<'a>;
   fn byte_to_lsp_position(&self, byte: usize) -> lsp::Position;
   fn lsp_position_to_char(&self, position: lsp::Position) -> usize;
+  fn text(&self) -> Cow<'_, str>;
 }
 
 impl RopeExt for Rope {
@@ -74,6 +75,15 @@ impl RopeExt for Rope {
       self.char_to_utf16_cu(row_char) + position.character as usize,
     )
   }
+
+  /// Returns the rope's content, borrowing it when it is stored in a single
+  /// chunk instead of copying it into a new string.
+  fn text(&self) -> Cow<'_, str> {
+    self
+      .slice(..)
+      .as_str()
+      .map_or_else(|| Cow::Owned(self.to_string()), Cow::Borrowed)
+  }
 }
 
 #[cfg(test)]
@@ -281,4 +291,16 @@ mod tests {
 
     assert_eq!(rope.to_string(), "🧪baz");
   }
+
+  #[test]
+  fn text_matches_rope_contents() {
+    let small = Rope::from_str("[project]\nname = \"demo\"\n");
+
+    assert!(matches!(small.text(), Cow::Borrowed(_)));
+    assert_eq!(small.text(), small.to_string());
+
+    let large = Rope::from_str(&"name = \"demo\"\n".repeat(1000));
+
+    assert_eq!(large.text(), large.to_string());
+  }
 }
@@ -40,41 +40,94 @@ macro_rules! define_rule {
   };
 }
 
-pub(crate) use schema::SchemaRule;
+pub(crate) use {
+  build_system_backend_version::BuildSystemBackendVersionRule,
+  dependency_groups::DependencyGroupsRule,
+  project_classifiers::ProjectClassifiersRule,
+  project_dependencies_requires_python::ProjectDependenciesRequiresPythonRule,
+  project_dynamic::DYNAMIC_FIELDS,
+  project_license_files::ProjectLicenseFilesRule,
+  project_license_mechanisms::ProjectLicenseMechanismsRule,
+  project_optional_dependencies_groups_overlap::ProjectOptionalDependenciesGroupsOverlapRule,
+  schema::SchemaRule, tool_poetry_python::ToolPoetryPythonRule,
+  unused_ignore::UnusedIgnoreRule,
+};
 
 mod build_system;
+mod build_system_backend_package;
+mod build_system_backend_version;
+mod build_system_packages;
+mod build_system_requires;
 mod dependency_groups;
+mod dependency_groups_overlap;
+mod dependency_url_hash;
+mod document_bom;
 mod project_classifiers;
+mod project_classifiers_order;
+mod project_classifiers_python;
 mod project_dependencies;
+mod project_dependencies_count;
+mod project_dependencies_dev_tools;
+mod project_dependencies_direct_references;
+mod project_dependencies_duplicates;
+mod project_dependencies_marker_quotes;
+mod project_dependencies_minimum_python;
+mod project_dependencies_prerelease;
+mod project_dependencies_python_markers;
+mod project_dependencies_requires_python;
 mod project_dependencies_version_bounds;
+mod project_dependencies_yanked;
 mod project_dependency_deprecations;
+mod project_dependency_extras;
 mod project_dependency_updates;
 mod project_description;
 mod project_dynamic;
+mod project_dynamic_backend;
 mod project_entry_points;
 mod project_entry_points_extras;
 mod project_import_names;
 mod project_keywords;
-mod project_license_classifiers;
+mod project_keywords_case;
+mod project_keywords_classifiers;
 mod project_license_classifiers_deprecated;
+mod project_license_dual;
 mod project_license_files;
+mod project_license_intent;
+mod project_license_mechanisms;
 mod project_license_value;
 mod project_license_value_deprecations;
 mod project_name;
+mod project_name_availability;
 mod project_name_normalization;
+mod project_name_typosquat;
 mod project_optional_dependencies;
+mod project_optional_dependencies_groups_overlap;
+mod project_optional_dependencies_unused;
 mod project_people;
+mod project_people_placeholder_email;
+mod project_publish_readiness;
 mod project_readme;
 mod project_readme_content_type;
 mod project_requires_python;
+mod project_requires_python_eol;
+mod project_requires_python_exclusions;
 mod project_requires_python_upper_bound;
 mod project_unknown_keys;
 mod project_urls;
+mod project_urls_reachable;
+mod project_urls_repository;
 mod project_version;
+mod project_version_epoch;
 mod schema;
 mod semantic;
 mod syntax;
+mod tool_poetry_python;
+mod tool_pyproject_rules;
+mod tool_pytest_testpaths;
+mod tool_setuptools_metadata;
+mod tool_setuptools_src_layout;
 mod top_level_unknown_keys;
+mod unused_ignore;
 
 inventory::collect!(&'static dyn Rule);
 
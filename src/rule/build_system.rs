@@ -17,6 +17,25 @@ define_rule! {
 impl BuildSystemRule {
   const KEYS: [&str; 3] = ["requires", "build-backend", "backend-path"];
 
+  const KNOWN_BACKENDS: [&str; 14] = [
+    "flit_core.buildapi",
+    "hatchling.build",
+    "maturin",
+    "mesonpy",
+    "pbr.build",
+    "pdm.backend",
+    "pdm.pep517.api",
+    "poetry.core.masonry.api",
+    "poetry.masonry.api",
+    "scikit_build_core.build",
+    "setuptools.build_meta",
+    "setuptools.build_meta:__legacy__",
+    "sipbuild.api",
+    "uv_build",
+  ];
+
+  const MAX_BACKEND_DISTANCE: usize = 2;
+
   fn check_backend_path(
     document: &Document,
     content: &Rope,
@@ -140,8 +159,17 @@ impl BuildSystemRule {
       ));
     };
 
-    if Self::is_entry_point(string.value()) {
-      return None;
+    let value = string.value();
+
+    if Self::is_entry_point(value) {
+      return Self::closest_backend(value).map(|backend| {
+        Diagnostic::warning(
+          format!(
+            "`build-system.build-backend` `{value}` is not a known build backend (did you mean `{backend}`?)"
+          ),
+          build_backend.span(content),
+        )
+      });
     }
 
     Some(Diagnostic::error(
@@ -188,11 +216,23 @@ impl BuildSystemRule {
       )),
     }
 
-    if let Ok(build_backend) = build_system.try_get("build-backend")
-      && let Some(diagnostic) =
+    if let Ok(build_backend) = build_system.try_get("build-backend") {
+      if let Some(diagnostic) =
         Self::check_build_backend(content, &build_backend)
-    {
-      diagnostics.push(diagnostic);
+      {
+        diagnostics.push(diagnostic);
+      }
+
+      if let Ok(requires) = build_system.try_get("requires")
+        && requires
+          .as_array()
+          .is_some_and(|array| array.items().read().is_empty())
+      {
+        diagnostics.push(Diagnostic::error(
+          "`build-system.requires` must not be empty when `build-system.build-backend` is set, since the backend cannot be installed",
+          build_backend.span(content),
+        ));
+      }
     }
 
     if let Ok(backend_paths) = build_system.try_get("backend-path") {
@@ -241,6 +281,19 @@ impl BuildSystemRule {
       .collect()
   }
 
+  fn closest_backend(value: &str) -> Option<&'static str> {
+    if Self::KNOWN_BACKENDS.contains(&value) {
+      return None;
+    }
+
+    Self::KNOWN_BACKENDS
+      .iter()
+      .map(|backend| (edit_distance(value, backend), *backend))
+      .filter(|(distance, _)| *distance <= Self::MAX_BACKEND_DISTANCE)
+      .min()
+      .map(|(_, backend)| backend)
+  }
+
   fn is_entry_point(value: &str) -> bool {
     let (module, object) = value
       .split_once(':')
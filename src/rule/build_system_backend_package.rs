@@ -0,0 +1,70 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `build-system.requires` doesn't list the package providing
+  /// `build-system.build-backend`.
+  ///
+  /// Backends are often installed under a different name than their module,
+  /// e.g. `poetry.core.masonry.api` comes from `poetry-core` rather than
+  /// `poetry`, and requiring the wrong package either fails the build or
+  /// pulls in a much larger dependency tree. Only well-known backends are
+  /// checked, and in-tree backends declared with `backend-path` are skipped.
+  BuildSystemBackendPackageRule {
+    id: "build-system-backend-package",
+    message: "`build-system.requires` does not provide the build backend",
+    run(context) {
+      if context.get("build-system.backend-path").is_some() {
+        return Vec::new();
+      }
+
+      let (Some(backend), Some(requires)) = (
+        context.get("build-system.build-backend"),
+        context.get("build-system.requires"),
+      ) else {
+        return Vec::new();
+      };
+
+      let (Some(module), Some(array)) = (backend.as_str(), requires.as_array())
+      else {
+        return Vec::new();
+      };
+
+      let Some(package) =
+        BuildSystemBackendVersionRule::backend_package(module.value())
+      else {
+        return Vec::new();
+      };
+
+      let names = array
+        .items()
+        .read()
+        .iter()
+        .filter_map(|item| {
+          Requirement::<VerbatimUrl>::from_str(item.as_str()?.value())
+            .ok()
+            .map(|requirement| requirement.name.to_string())
+        })
+        .collect::<Vec<_>>();
+
+      if names.is_empty() || names.iter().any(|name| name == package) {
+        return Vec::new();
+      }
+
+      let found = names
+        .iter()
+        .find(|name| {
+          package.starts_with(name.as_str()) || name.starts_with(package)
+        })
+        .map(|name| format!(" (found `{name}`)"))
+        .unwrap_or_default();
+
+      vec![Diagnostic::warning(
+        format!(
+          "`build-system.build-backend` `{}` is provided by `{package}`, which `build-system.requires` does not list{found}",
+          module.value()
+        ),
+        backend.span(context.content()),
+      )]
+    }
+  }
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+define_rule! {
+  /// Warns when the build backend in `build-system.requires` has no lower bound.
+  ///
+  /// Backend behavior, including support for `pyproject.toml` metadata, varies
+  /// between releases, so the package providing `build-system.build-backend`
+  /// should declare a minimum version. Disabled by default.
+  BuildSystemBackendVersionRule {
+    id: "build-system-backend-version",
+    message: "build backend has no minimum version",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(backend) = context.get("build-system.build-backend") else {
+        return Vec::new();
+      };
+
+      let Some(backend) = backend.as_str().map(|string| string.value().to_string())
+      else {
+        return Vec::new();
+      };
+
+      let Some(package) = Self::backend_package(&backend) else {
+        return Vec::new();
+      };
+
+      let Some(requires) = context.get("build-system.requires") else {
+        return Vec::new();
+      };
+
+      let Some(array) = requires.as_array() else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        if requirement.name.as_ref() != package {
+          continue;
+        }
+
+        let has_lower_bound = match &requirement.version_or_url {
+          Some(VersionOrUrl::VersionSpecifier(specifiers)) => {
+            specifiers.iter().any(|specifier| {
+              matches!(
+                specifier.operator(),
+                Operator::Equal
+                  | Operator::EqualStar
+                  | Operator::ExactEqual
+                  | Operator::GreaterThan
+                  | Operator::GreaterThanEqual
+                  | Operator::TildeEqual
+              )
+            })
+          }
+          Some(VersionOrUrl::Url(_)) => true,
+          None => false,
+        };
+
+        if !has_lower_bound {
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`build-system.requires` entry `{package}` should specify a minimum version for build backend `{backend}`"
+            ),
+            item.span(context.content()),
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl BuildSystemBackendVersionRule {
+  const BACKENDS: [(&'static str, &'static str); 11] = [
+    ("flit_core", "flit-core"),
+    ("hatchling", "hatchling"),
+    ("maturin", "maturin"),
+    ("mesonpy", "meson-python"),
+    ("pdm.backend", "pdm-backend"),
+    ("pdm.pep517", "pdm-pep517"),
+    ("poetry.core", "poetry-core"),
+    ("poetry.masonry", "poetry"),
+    ("scikit_build_core", "scikit-build-core"),
+    ("setuptools", "setuptools"),
+    ("uv_build", "uv-build"),
+  ];
+
+  /// Returns the distribution that provides the build backend module
+  /// `backend`, if it is a well-known one.
+  pub(crate) fn backend_package(backend: &str) -> Option<&'static str> {
+    let module = backend.split(':').next()?.trim();
+
+    Self::BACKENDS
+      .iter()
+      .find(|(prefix, _)| {
+        module
+          .strip_prefix(prefix)
+          .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+      })
+      .map(|(_, package)| *package)
+  }
+}
@@ -0,0 +1,93 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a project with a `[build-system]` has no package to build.
+  ///
+  /// Looks for a `src/` directory, a package or module named after the
+  /// project, or any top-level directory containing an `__init__.py`. Projects
+  /// that configure their packages explicitly, or that use a backend for
+  /// compiled extensions, are skipped. Disabled by default, since the
+  /// heuristic can't see everything a backend discovers.
+  BuildSystemPackagesRule {
+    id: "build-system-packages",
+    message: "no package found to build",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(build_system) = context.get("build-system") else {
+        return Vec::new();
+      };
+
+      let Some(root) = context.document().root() else {
+        return Vec::new();
+      };
+
+      let compiled = context
+        .get("build-system.build-backend")
+        .and_then(|backend| backend.as_str().map(|string| string.value().to_string()))
+        .is_some_and(|backend| {
+          Self::COMPILED_BACKENDS
+            .iter()
+            .any(|compiled| backend.starts_with(compiled))
+        });
+
+      if compiled
+        || Self::PACKAGE_SETTINGS
+          .iter()
+          .any(|setting| context.get(setting).is_some())
+      {
+        return Vec::new();
+      }
+
+      let module = context
+        .get("project.name")
+        .and_then(|name| name.as_str().map(|string| string.value().to_string()))
+        .map(|name| name.to_lowercase().replace(['-', '.'], "_"));
+
+      if Self::has_package(&root, module.as_deref()) {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::warning(
+        "`[build-system]` is configured, but no package or module was found under the project root or `src/`; the built distribution may be empty",
+        build_system.span(context.content()),
+      )]
+    }
+  }
+}
+
+impl BuildSystemPackagesRule {
+  const COMPILED_BACKENDS: [&'static str; 3] =
+    ["maturin", "mesonpy", "scikit_build_core"];
+
+  const PACKAGE_SETTINGS: [&'static str; 10] = [
+    "tool.flit.module",
+    "tool.hatch.build",
+    "tool.pdm.build",
+    "tool.poetry.packages",
+    "tool.setuptools.ext-modules",
+    "tool.setuptools.package-dir",
+    "tool.setuptools.packages",
+    "tool.setuptools.py-modules",
+    "tool.uv.build-backend.module-name",
+    "tool.uv.build-backend.module-root",
+  ];
+
+  fn has_package(root: &Path, module: Option<&str>) -> bool {
+    if root.join("src").is_dir() {
+      return true;
+    }
+
+    if let Some(module) = module
+      && (root.join(module).is_dir()
+        || root.join(format!("{module}.py")).is_file())
+    {
+      return true;
+    }
+
+    fs::read_dir(root).is_ok_and(|entries| {
+      entries
+        .filter_map(Result::ok)
+        .any(|entry| entry.path().join("__init__.py").is_file())
+    })
+  }
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+define_rule! {
+  /// Checks the packages listed in `build-system.requires`.
+  ///
+  /// Package names should be written in their normalized form, and a
+  /// `setuptools` requirement must not allow releases older than 61 when the
+  /// file declares `[project]`, since those can't read its metadata. Entries
+  /// that aren't valid PEP 508 strings are left to the `build-system` rule.
+  BuildSystemRequiresRule {
+    id: "build-system-requires",
+    message: "questionable `build-system.requires` entry",
+    run(context) {
+      let Some(requires) = context.get("build-system.requires") else {
+        return Vec::new();
+      };
+
+      let Some(array) = requires.as_array() else {
+        return Vec::new();
+      };
+
+      let has_project = context.get("project").is_some();
+
+      let content = context.content();
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let value = string.value();
+
+        let Ok(requirement) = Requirement::<VerbatimUrl>::from_str(value) else {
+          continue;
+        };
+
+        if let Some(raw_name) = Dependency::new(value).name() {
+          let normalized = requirement.name.to_string();
+
+          if raw_name != normalized {
+            diagnostics.push(Diagnostic::warning(
+              format!(
+                "`build-system.requires` package name `{raw_name}` should be normalized (use `{normalized}`)"
+              ),
+              item.span(content),
+            ));
+          }
+        }
+
+        if has_project
+          && requirement.name.as_ref() == "setuptools"
+          && Self::setuptools_predates_project(&requirement)
+        {
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`build-system.requires` item `{value}` allows setuptools older than {}, which cannot read `[project]` metadata; require `setuptools>={}`",
+              Self::SETUPTOOLS_PROJECT_VERSION,
+              Self::SETUPTOOLS_PROJECT_VERSION
+            ),
+            item.span(content),
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl BuildSystemRequiresRule {
+  const SETUPTOOLS_PROJECT_VERSION: u64 = 61;
+
+  fn setuptools_predates_project(
+    requirement: &Requirement<VerbatimUrl>,
+  ) -> bool {
+    let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+      &requirement.version_or_url
+    else {
+      return requirement.version_or_url.is_none();
+    };
+
+    !specifiers.iter().any(|specifier| {
+      matches!(
+        specifier.operator(),
+        Operator::Equal
+          | Operator::EqualStar
+          | Operator::ExactEqual
+          | Operator::GreaterThan
+          | Operator::GreaterThanEqual
+          | Operator::TildeEqual
+      ) && specifier.version().release().first().copied().unwrap_or(0)
+        >= Self::SETUPTOOLS_PROJECT_VERSION
+    })
+  }
+}
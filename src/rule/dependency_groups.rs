@@ -4,7 +4,9 @@ define_rule! {
   /// Validates `dependency-groups` configuration per PEP 735.
   ///
   /// Checks that `include-group` objects contain only the `include-group` key
-  /// and that referenced groups exist in the dependency-groups table.
+  /// and that referenced groups exist in the dependency-groups table. Empty
+  /// groups are noted, since they are usually placeholders that were never
+  /// filled in.
   DependencyGroupsRule {
     id: "dependency-groups",
     message: "invalid `dependency-groups` configuration",
@@ -48,6 +50,19 @@ define_rule! {
           &mut diagnostics,
         );
 
+        if group_value
+          .as_array()
+          .is_some_and(|array| array.items().read().is_empty())
+        {
+          diagnostics.push(Diagnostic::new(
+            format!(
+              "`dependency-groups.{group_name}` is empty; add requirements or an `include-group`, or remove the group"
+            ),
+            group_key.span(context.content()),
+            lsp::DiagnosticSeverity::INFORMATION,
+          ));
+        }
+
         let group = DependencyGroup {
           name: group_name.to_string(),
           includes,
@@ -129,7 +144,7 @@ impl DependencyGroupsRule {
     diagnostics
   }
 
-  fn normalize_group_name(name: &str) -> String {
+  pub(crate) fn normalize_group_name(name: &str) -> String {
     let mut normalized = String::new();
 
     let mut last_was_sep = false;
@@ -297,6 +312,15 @@ impl DependencyGroupsRule {
     path.push(group_name.to_string());
 
     for include in &group.includes {
+      if include.normalized_name == group_name {
+        diagnostics.push(Diagnostic::error(
+          format!("dependency group `{}` must not include itself", group.name),
+          include.range,
+        ));
+
+        continue;
+      }
+
       if let Some(index) = path
         .iter()
         .position(|name| name == &include.normalized_name)
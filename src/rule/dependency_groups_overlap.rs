@@ -0,0 +1,100 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a package is listed in both an extra and a dependency group.
+  ///
+  /// Tools like `pytest` often end up in a `test` extra as well as a PEP 735
+  /// `[dependency-groups]` entry, and the two constraints drift apart over
+  /// time. Each dependency group entry whose package also appears in
+  /// `project.optional-dependencies` is reported, regardless of the names of
+  /// the extra and the group. Direct URL references are ignored, as are
+  /// extras that `project-optional-dependencies-groups-overlap` already
+  /// reports as duplicating the group when that rule is enabled.
+  DependencyGroupsOverlapRule {
+    id: "dependency-groups-overlap",
+    message: "package is declared in both an extra and a dependency group",
+    run(context) {
+      let (Some(optional_dependencies), Some(dependency_groups)) = (
+        context.get("project.optional-dependencies"),
+        context.get("dependency-groups"),
+      ) else {
+        return Vec::new();
+      };
+
+      let (Some(extras), Some(groups)) =
+        (optional_dependencies.as_table(), dependency_groups.as_table())
+      else {
+        return Vec::new();
+      };
+
+      let summarized = if context
+        .document()
+        .config
+        .rule_config(ProjectOptionalDependenciesGroupsOverlapRule.id())
+        .severity(
+          lsp::DiagnosticSeverity::INFORMATION,
+          ProjectOptionalDependenciesGroupsOverlapRule.default_level(),
+        )
+        .is_some()
+      {
+        ProjectOptionalDependenciesGroupsOverlapRule::overlaps(context)
+          .into_iter()
+          .map(|overlap| (overlap.extra_key.value().to_string(), overlap.group_key.value().to_string()))
+          .collect::<HashSet<_>>()
+      } else {
+        HashSet::new()
+      };
+
+      let content = context.content();
+
+      let mut declared = HashMap::<PackageName, Vec<(String, lsp::Range)>>::new();
+
+      for (key, value) in extras.entries().read().iter() {
+        for (name, item) in
+          ProjectOptionalDependenciesGroupsOverlapRule::requirements(value)
+        {
+          declared
+            .entry(name)
+            .or_default()
+            .push((key.value().to_string(), item.span(content)));
+        }
+      }
+
+      let mut diagnostics = Vec::new();
+
+      for (key, value) in groups.entries().read().iter() {
+        let group = key.value().to_string();
+
+        for (name, item) in
+          ProjectOptionalDependenciesGroupsOverlapRule::requirements(value)
+        {
+          let Some((extra, range)) = declared.get(&name).and_then(|extras| {
+            extras.iter().find(|(extra, _)| {
+              !summarized.contains(&(extra.clone(), group.clone()))
+            })
+          }) else {
+            continue;
+          };
+
+          diagnostics.push(
+            Diagnostic::warning(
+              format!(
+                "`{name}` appears in both `project.optional-dependencies.{extra}` and `dependency-groups.{group}`"
+              ),
+              item.span(content),
+            )
+            .related(
+              lsp::Location {
+                range: *range,
+                uri: context.document().uri.clone(),
+              },
+              format!("`{name}` declared in extra `{extra}` here"),
+            ),
+          );
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
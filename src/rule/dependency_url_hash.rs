@@ -0,0 +1,92 @@
+use super::*;
+
+define_rule! {
+  /// Warns on direct URL dependencies that aren't pinned by a hash.
+  ///
+  /// A requirement such as `pkg @ https://example.com/pkg.whl` installs
+  /// whatever the server returns, so the URL should carry a `#sha256=`
+  /// fragment, or a VCS URL should pin a full commit. Local `file:` URLs are
+  /// ignored. Disabled by default.
+  DependencyUrlHashRule {
+    id: "dependency-url-hash",
+    message: "direct URL dependency is not pinned by a hash",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let mut diagnostics = Vec::new();
+
+      for field in ["project.dependencies", "build-system.requires"] {
+        let Some(node) = context.get(field) else {
+          continue;
+        };
+
+        let Some(array) = node.as_array() else {
+          continue;
+        };
+
+        for item in array.items().read().iter() {
+          let Some(string) = item.as_str() else {
+            continue;
+          };
+
+          let Ok(requirement) =
+            Requirement::<VerbatimUrl>::from_str(string.value())
+          else {
+            continue;
+          };
+
+          let Some(VersionOrUrl::Url(url)) = &requirement.version_or_url else {
+            continue;
+          };
+
+          if Self::is_pinned(url) {
+            continue;
+          }
+
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`{field}` entry `{}` is installed from a URL without a hash; add a `#sha256=` fragment or depend on a release from an index instead",
+              requirement.name
+            ),
+            item.span(context.content()),
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl DependencyUrlHashRule {
+  const HASH_ALGORITHMS: [&'static str; 3] = ["sha256", "sha384", "sha512"];
+
+  fn is_pinned(url: &VerbatimUrl) -> bool {
+    let url = url.to_url();
+
+    if url.scheme() == "file" {
+      return true;
+    }
+
+    let has_hash = url.fragment().is_some_and(|fragment| {
+      fragment.split('&').any(|pair| {
+        pair.split_once('=').is_some_and(|(algorithm, digest)| {
+          Self::HASH_ALGORITHMS.contains(&algorithm)
+            && !digest.is_empty()
+            && digest
+              .chars()
+              .all(|character| character.is_ascii_hexdigit())
+        })
+      })
+    });
+
+    let has_commit = url.scheme().starts_with("git+")
+      && url.path().rsplit_once('@').is_some_and(|(_, revision)| {
+        revision.len() == 40
+          && revision
+            .chars()
+            .all(|character| character.is_ascii_hexdigit())
+      });
+
+    has_hash || has_commit
+  }
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+define_rule! {
+  /// Warns when the document starts with a UTF-8 byte order mark.
+  ///
+  /// TOML files are UTF-8 by definition, so the mark is redundant and some
+  /// build tools reject it outright.
+  DocumentBomRule {
+    id: "document-bom",
+    message: "document starts with a byte order mark",
+    run(context) {
+      if !context.document().bom {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::warning(
+        "remove the UTF-8 byte order mark from the start of the file",
+        lsp::Range::default(),
+      )]
+    }
+  }
+}
@@ -64,7 +64,27 @@ define_rule! {
 }
 
 impl ProjectClassifiersRule {
-  fn classifiers() -> &'static HashSet<&'static str> {
+  pub(crate) fn categories() -> &'static [&'static str] {
+    static CATEGORIES: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    CATEGORIES.get_or_init(|| {
+      let mut categories = Vec::new();
+
+      for line in include_str!("classifiers.txt").lines() {
+        let Some(category) = line.split(" :: ").next().map(str::trim) else {
+          continue;
+        };
+
+        if !category.is_empty() && !categories.contains(&category) {
+          categories.push(category);
+        }
+      }
+
+      categories
+    })
+  }
+
+  pub(crate) fn classifiers() -> &'static HashSet<&'static str> {
     static CLASSIFIERS: OnceLock<HashSet<&'static str>> = OnceLock::new();
 
     CLASSIFIERS.get_or_init(|| {
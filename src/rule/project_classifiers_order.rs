@@ -0,0 +1,104 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.classifiers` are not grouped by category.
+  ///
+  /// Suggests ordering classifiers by their top-level category following the
+  /// PyPI trove classifier list, keeping unknown classifiers at the end.
+  /// Disabled by default.
+  ProjectClassifiersOrderRule {
+    id: "project-classifiers-order",
+    message: "`project.classifiers` are not grouped by category",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(classifiers) = context.get("project.classifiers") else {
+        return Vec::new();
+      };
+
+      let Some(array) = classifiers.as_array() else {
+        return Vec::new();
+      };
+
+      let content = context.content();
+
+      let items = array.items().read();
+
+      let Some(values) = items
+        .iter()
+        .map(|item| item.as_str().map(|string| string.value().to_string()))
+        .collect::<Option<Vec<_>>>()
+      else {
+        return Vec::new();
+      };
+
+      let mut order = (0..values.len()).collect::<Vec<_>>();
+
+      order.sort_by_key(|&index| Self::category_rank(&values[index]));
+
+      if order.iter().enumerate().all(|(position, &index)| position == index) {
+        return Vec::new();
+      }
+
+      // Every moved item needs its source text, since a partial reordering
+      // would duplicate some classifiers and drop others.
+      let edits = order
+        .iter()
+        .enumerate()
+        .filter(|(position, index)| position != *index)
+        .map(|(position, &index)| {
+          Some(lsp::TextEdit {
+            range: items[position].span(content),
+            new_text: Self::source(content, &items[index])?,
+          })
+        })
+        .collect::<Option<Vec<_>>>();
+
+      let diagnostic = Diagnostic::warning(
+        format!(
+          "`project.classifiers` should be grouped by category in PyPI order ({})",
+          ProjectClassifiersRule::categories()
+            .iter()
+            .map(|category| format!("`{category}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+        classifiers.span(content),
+      );
+
+      vec![match edits {
+        Some(edits) => diagnostic.quickfix(Quickfix {
+          edits,
+          title: "Group classifiers by category".to_string(),
+        }),
+        None => diagnostic,
+      }]
+    }
+  }
+}
+
+impl ProjectClassifiersOrderRule {
+  fn category_rank(classifier: &str) -> usize {
+    let categories = ProjectClassifiersRule::categories();
+
+    if !ProjectClassifiersRule::classifiers().contains(classifier) {
+      return categories.len();
+    }
+
+    let category = classifier.split(" :: ").next().unwrap_or_default();
+
+    categories
+      .iter()
+      .position(|candidate| *candidate == category)
+      .unwrap_or(categories.len())
+  }
+
+  fn source(content: &Rope, node: &Node) -> Option<String> {
+    let range = node.text_ranges(false).next()?;
+
+    Some(
+      content
+        .get_byte_slice(usize::from(range.start())..usize::from(range.end()))?
+        .to_string(),
+    )
+  }
+}
@@ -0,0 +1,152 @@
+use super::*;
+
+define_rule! {
+  /// Warns when Python version classifiers contradict each other or
+  /// `project.requires-python`.
+  ///
+  /// Flags version classifiers that `requires-python` rules out, classifiers
+  /// for another major version next to `:: Only`, and minor version
+  /// classifiers listed without the classifier for their major version.
+  ProjectClassifiersPythonRule {
+    id: "project-classifiers-python",
+    message: "Python classifiers are inconsistent",
+    run(context) {
+      let Some(array) = context
+        .get("project.classifiers")
+        .and_then(|node| node.as_array().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let classifiers = array
+        .items()
+        .read()
+        .iter()
+        .filter_map(|item| {
+          let classifier = item.as_str()?.value().to_string();
+
+          Some((Self::parse(&classifier)?, classifier, item.clone()))
+        })
+        .collect::<Vec<_>>();
+
+      let specifiers = context
+        .get("project.requires-python")
+        .and_then(|node| node.as_str().cloned())
+        .and_then(|string| {
+          VersionSpecifiers::from_str(string.value())
+            .ok()
+            .map(|specifiers| (string.value().to_string(), specifiers))
+        });
+
+      let only = classifiers
+        .iter()
+        .find(|(python, _, _)| python.only)
+        .map(|(python, classifier, _)| (python.major, classifier));
+
+      let mut diagnostics = Vec::new();
+
+      let mut reported_majors = Vec::new();
+
+      for (python, classifier, item) in &classifiers {
+        let span = item.span(context.content());
+
+        if let Some((requires_python, specifiers)) = &specifiers
+          && !Self::candidates(python).any(|version| specifiers.contains(&version))
+        {
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`{classifier}` contradicts `project.requires-python` `{requires_python}`, which excludes Python {}",
+              python.version()
+            ),
+            span,
+          ));
+
+          continue;
+        }
+
+        if let Some((major, only)) = only
+          && python.major != major
+        {
+          diagnostics.push(Diagnostic::warning(
+            format!("`{classifier}` contradicts `{only}`"),
+            span,
+          ));
+
+          continue;
+        }
+
+        if python.minor.is_some()
+          && !reported_majors.contains(&python.major)
+          && !classifiers.iter().any(|(other, _, _)| {
+            other.major == python.major && other.minor.is_none()
+          })
+        {
+          reported_majors.push(python.major);
+
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`{classifier}` is listed without `{}{}`",
+              Self::PREFIX,
+              python.major
+            ),
+            span,
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+struct PythonClassifier {
+  major: u64,
+  minor: Option<u64>,
+  only: bool,
+}
+
+impl PythonClassifier {
+  fn version(&self) -> String {
+    match self.minor {
+      Some(minor) => format!("{}.{minor}", self.major),
+      None => self.major.to_string(),
+    }
+  }
+}
+
+impl ProjectClassifiersPythonRule {
+  const PREFIX: &'static str = "Programming Language :: Python :: ";
+
+  fn candidates(python: &PythonClassifier) -> impl Iterator<Item = Version> {
+    let (major, minor) = (python.major, python.minor);
+
+    let minors = match minor {
+      Some(minor) => minor..=minor,
+      None => 0..=30,
+    };
+
+    minors.flat_map(move |minor| {
+      (0..=50).map(move |patch| Version::new([major, minor, patch]))
+    })
+  }
+
+  fn parse(classifier: &str) -> Option<PythonClassifier> {
+    let rest = classifier.strip_prefix(Self::PREFIX)?;
+
+    let (version, only) = match rest.strip_suffix(" :: Only") {
+      Some(version) => (version, true),
+      None => (rest, false),
+    };
+
+    let (major, minor) = match version.split_once('.') {
+      Some((major, minor)) => (major, Some(minor.parse().ok()?)),
+      None => (version, None),
+    };
+
+    Some(PythonClassifier {
+      major: major.parse().ok()?,
+      minor,
+      only,
+    })
+  }
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dependencies` declares more entries than allowed.
+  ///
+  /// A long list of runtime dependencies often means some belong in
+  /// `project.optional-dependencies` or `dependency-groups`. The limit is set
+  /// with the `max` entry of the rule's `options` table and defaults to 20.
+  /// Disabled by default.
+  ProjectDependenciesCountRule {
+    id: "project-dependencies-count",
+    message: "too many `project.dependencies`",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(project) = context.get("project") else {
+        return Vec::new();
+      };
+
+      let Some(table) = project.as_table() else {
+        return Vec::new();
+      };
+
+      let entries = table.entries().read();
+
+      let Some((key, dependencies)) =
+        entries.iter().find(|(key, _)| key.value() == "dependencies")
+      else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let max = context
+        .config()
+        .rule_config(Self.id())
+        .option("max")
+        .and_then(Value::as_u64)
+        .and_then(|max| usize::try_from(max).ok())
+        .unwrap_or(Self::DEFAULT_MAX);
+
+      let count = array.items().read().len();
+
+      if count <= max {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::warning(
+        format!(
+          "`project.dependencies` declares {count} dependencies, more than the maximum of {max}; consider moving some to `project.optional-dependencies` or `dependency-groups`"
+        ),
+        key.span(context.content()),
+      )]
+    }
+  }
+}
+
+impl ProjectDependenciesCountRule {
+  const DEFAULT_MAX: usize = 20;
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+define_rule! {
+  /// Notes development-only tools listed in `project.dependencies`.
+  ///
+  /// Test runners, formatters, linters, and type checkers are rarely needed at
+  /// runtime and belong in `[dependency-groups]` or
+  /// `[project.optional-dependencies]` instead. Disabled by default, since some
+  /// packages legitimately depend on these tools.
+  ProjectDependenciesDevToolsRule {
+    id: "project-dependencies-dev-tools",
+    message: "`project.dependencies` contains a development tool",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(dependencies) = context.get("project.dependencies") else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        if !Self::DEV_TOOLS.contains(&requirement.name.as_ref()) {
+          continue;
+        }
+
+        diagnostics.push(Diagnostic::new(
+          format!(
+            "`project.dependencies` includes development tool `{}`; consider moving it to `[dependency-groups]` or `[project.optional-dependencies]`",
+            requirement.name
+          ),
+          item.span(context.content()),
+          lsp::DiagnosticSeverity::INFORMATION,
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectDependenciesDevToolsRule {
+  const DEV_TOOLS: [&'static str; 12] = [
+    "black",
+    "coverage",
+    "flake8",
+    "isort",
+    "mypy",
+    "nox",
+    "pre-commit",
+    "pylint",
+    "pytest",
+    "pytest-cov",
+    "ruff",
+    "tox",
+  ];
+}
@@ -0,0 +1,44 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dependencies` entries are direct URL references.
+  ///
+  /// Requirements such as `pkg @ git+https://...`, `pkg @ file:///...`, or
+  /// `pkg @ https://.../pkg.tar.gz` install fine locally, but PyPI rejects
+  /// uploads whose metadata depends on them. Disabled by default.
+  ProjectDependenciesDirectReferencesRule {
+    id: "project-dependencies-direct-references",
+    message: "`project.dependencies` contains a direct URL reference",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(array) = context
+        .get("project.dependencies")
+        .and_then(|node| node.as_array().cloned())
+      else {
+        return Vec::new();
+      };
+
+      array
+        .items()
+        .read()
+        .iter()
+        .filter_map(|item| {
+          let requirement =
+            Requirement::<VerbatimUrl>::from_str(item.as_str()?.value()).ok()?;
+
+          matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))).then(
+            || {
+              Diagnostic::warning(
+                format!(
+                  "`project.dependencies` entry `{}` is a direct URL reference, which cannot be uploaded to PyPI",
+                  requirement.name
+                ),
+                item.span(context.content()),
+              )
+            },
+          )
+        })
+        .collect()
+    }
+  }
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dependencies` lists the same package more than once.
+  ///
+  /// Names are compared after normalization and regardless of extras, so
+  /// `Requests` and `requests[security]` count as the same package. Entries
+  /// with different environment markers are left alone, since splitting
+  /// constraints by platform or Python version is intentional.
+  ProjectDependenciesDuplicatesRule {
+    id: "project-dependencies-duplicates",
+    message: "`project.dependencies` contains duplicate packages",
+    run(context) {
+      let Some(array) = context
+        .get("project.dependencies")
+        .and_then(|node| node.as_array().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let mut seen = HashSet::new();
+
+      array
+        .items()
+        .read()
+        .iter()
+        .filter_map(|item| {
+          let requirement =
+            Requirement::<VerbatimUrl>::from_str(item.as_str()?.value()).ok()?;
+
+          let marker = requirement.marker.try_to_string().unwrap_or_default();
+
+          (!seen.insert((requirement.name.clone(), marker))).then(|| {
+            Diagnostic::warning(
+              format!(
+                "`project.dependencies` lists `{}` more than once; combine the constraints into a single entry",
+                requirement.name
+              ),
+              item.span(context.content()),
+            )
+          })
+        })
+        .collect()
+    }
+  }
+}
@@ -0,0 +1,136 @@
+use super::*;
+
+define_rule! {
+  /// Warns when environment markers mix single and double quotes.
+  ///
+  /// PEP 508 accepts either quote style for marker literals, but mixing them
+  /// within one dependency array is inconsistent. Entries using single quotes
+  /// are flagged with a fix that switches them to double quotes. Disabled by
+  /// default.
+  ProjectDependenciesMarkerQuotesRule {
+    id: "project-dependencies-marker-quotes",
+    message: "inconsistent quotes in environment markers",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let mut diagnostics = Vec::new();
+
+      if let Some(dependencies) = context.get("project.dependencies") {
+        diagnostics.extend(Self::check_array(
+          context.content(),
+          "project.dependencies",
+          &dependencies,
+        ));
+      }
+
+      if let Some(extras) = context
+        .get("project.optional-dependencies")
+        .and_then(|node| node.as_table().cloned())
+      {
+        for (key, value) in extras.entries().read().iter() {
+          diagnostics.extend(Self::check_array(
+            context.content(),
+            &format!("project.optional-dependencies.{}", key.value()),
+            value,
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum QuoteStyle {
+  Double,
+  Mixed,
+  Single,
+}
+
+impl ProjectDependenciesMarkerQuotesRule {
+  fn check_array(
+    content: &Rope,
+    location: &str,
+    node: &Node,
+  ) -> Vec<Diagnostic> {
+    let Some(array) = node.as_array() else {
+      return Vec::new();
+    };
+
+    let items = array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| {
+        let value = item.as_str()?.value().to_string();
+
+        Requirement::<VerbatimUrl>::from_str(&value).ok()?;
+
+        let (requirement, marker) = value.split_once(';')?;
+
+        let style = Self::quote_style(marker)?;
+
+        Some((
+          item.clone(),
+          requirement.to_string(),
+          marker.to_string(),
+          style,
+        ))
+      })
+      .collect::<Vec<_>>();
+
+    let has_double =
+      items.iter().any(|(.., style)| *style != QuoteStyle::Single);
+
+    let has_single =
+      items.iter().any(|(.., style)| *style != QuoteStyle::Double);
+
+    if !(has_double && has_single) {
+      return Vec::new();
+    }
+
+    items
+      .into_iter()
+      .filter(|(.., style)| *style != QuoteStyle::Double)
+      .map(|(item, requirement, marker, _)| {
+        let replacement = Self::toml_string(&format!(
+          "{requirement};{}",
+          marker.replace('\'', "\"")
+        ));
+
+        Diagnostic::warning(
+          format!(
+            "`{location}` environment marker uses single quotes while other entries use double quotes; prefer double quotes consistently"
+          ),
+          item.span(content),
+        )
+        .quickfix(Quickfix {
+          edits: vec![lsp::TextEdit {
+            range: item.span(content),
+            new_text: replacement,
+          }],
+          title: "Use double quotes in environment marker".to_string(),
+        })
+      })
+      .collect()
+  }
+
+  fn quote_style(marker: &str) -> Option<QuoteStyle> {
+    match (marker.contains('"'), marker.contains('\'')) {
+      (true, true) => Some(QuoteStyle::Mixed),
+      (true, false) => Some(QuoteStyle::Double),
+      (false, true) => Some(QuoteStyle::Single),
+      (false, false) => None,
+    }
+  }
+
+  /// Encodes `value` as a TOML string, preferring a literal string so that
+  /// double quotes inside the marker don't need escaping.
+  fn toml_string(value: &str) -> String {
+    if value.contains(['\'', '\n']) {
+      format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+      format!("'{value}'")
+    }
+  }
+}
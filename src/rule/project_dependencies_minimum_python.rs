@@ -0,0 +1,94 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a dependency's lower bound doesn't support the project's
+  /// Python versions.
+  ///
+  /// Queries PyPI for the `requires_python` metadata of the release named by
+  /// each dependency's `>=`, `==`, `===`, or `~=` bound, rather than the
+  /// latest release, and compares it against the lower bound of
+  /// `project.requires-python`. Disabled by default.
+  ProjectDependenciesMinimumPythonRule {
+    id: "project-dependencies-minimum-python",
+    message: "dependency lower bound does not support `project.requires-python`",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let pypi = context.pypi();
+
+      let Some(dependencies) = context.get("project.dependencies") else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let Some(minimum) = context
+        .get("project.requires-python")
+        .and_then(|node| node.as_str().map(|string| string.value().to_string()))
+        .and_then(|requires_python| {
+          ProjectDependenciesRequiresPythonRule::minimum_python(&requires_python)
+        })
+      else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+          &requirement.version_or_url
+        else {
+          continue;
+        };
+
+        let Some(floor) = specifiers
+          .iter()
+          .filter(|specifier| {
+            matches!(
+              specifier.operator(),
+              Operator::Equal
+                | Operator::ExactEqual
+                | Operator::GreaterThanEqual
+                | Operator::TildeEqual
+            )
+          })
+          .map(|specifier| specifier.version().clone())
+          .max()
+        else {
+          continue;
+        };
+
+        let Some(requires_python) =
+          pypi.requires_python_for(&requirement.name, &floor)
+        else {
+          continue;
+        };
+
+        if requires_python.contains(&minimum) {
+          continue;
+        }
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`project.dependencies` entry `{}` is bounded by `{floor}`, which requires Python `{requires_python}` and excludes the minimum supported Python `{minimum}`; adjust the bound or `project.requires-python`",
+            requirement.name
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
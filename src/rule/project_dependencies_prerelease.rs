@@ -0,0 +1,78 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dependencies` entries are bounded by pre-releases.
+  ///
+  /// A lower bound such as `requests>=2.0a1` or a pin such as `pkg==2.0rc1`
+  /// makes a runtime dependency require a pre-release, which installers only
+  /// pick when told to. Projects whose own version is a pre-release are
+  /// assumed to depend on pre-releases on purpose. Disabled by default.
+  ProjectDependenciesPrereleaseRule {
+    id: "project-dependencies-prerelease",
+    message: "`project.dependencies` requires a pre-release",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(dependencies) = context.get("project.dependencies") else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let prerelease_project = context
+        .get("project.version")
+        .and_then(|node| node.as_str().cloned())
+        .and_then(|version| Version::from_str(version.value()).ok())
+        .is_some_and(|version| version.any_prerelease());
+
+      if prerelease_project {
+        return Vec::new();
+      }
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+          &requirement.version_or_url
+        else {
+          continue;
+        };
+
+        let Some(specifier) = specifiers.iter().find(|specifier| {
+          matches!(
+            specifier.operator(),
+            Operator::Equal
+              | Operator::ExactEqual
+              | Operator::GreaterThan
+              | Operator::GreaterThanEqual
+              | Operator::TildeEqual
+          ) && specifier.version().any_prerelease()
+        }) else {
+          continue;
+        };
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`project.dependencies` entry `{}` requires pre-release `{}`; installers skip pre-releases by default, so confirm this is intentional",
+            requirement.name,
+            specifier.version()
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a `project.dependencies` marker can never be satisfied under
+  /// `project.requires-python`.
+  ///
+  /// A dependency guarded by `python_version < "3.7"` in a project requiring
+  /// `>=3.8` will never be installed, which usually indicates a stale entry.
+  ProjectDependenciesPythonMarkersRule {
+    id: "project-dependencies-python-markers",
+    message: "`project.dependencies` marker contradicts `project.requires-python`",
+    run(context) {
+      let Some(dependencies) = context.get("project.dependencies") else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let Some(requires_python) = context
+        .get("project.requires-python")
+        .and_then(|node| node.as_str().map(|string| string.value().to_string()))
+      else {
+        return Vec::new();
+      };
+
+      let Some(python_marker) = Self::python_marker(&requires_python) else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        if requirement.marker.is_true()
+          || !requirement.marker.is_disjoint(&python_marker)
+        {
+          continue;
+        }
+
+        let marker = string
+          .value()
+          .split_once(';')
+          .map(|(_, marker)| marker.trim().to_string())
+          .or_else(|| requirement.marker.try_to_string())
+          .unwrap_or_default();
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`project.dependencies` entry `{}` has marker `{marker}` that can never be satisfied under `project.requires-python` (`{}`)",
+            requirement.name,
+            requires_python.trim()
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectDependenciesPythonMarkersRule {
+  fn python_marker(requires_python: &str) -> Option<MarkerTree> {
+    let specifiers = VersionSpecifiers::from_str(requires_python).ok()?;
+
+    let clauses = specifiers
+      .iter()
+      .map(|specifier| {
+        let star = if matches!(
+          specifier.operator(),
+          Operator::EqualStar | Operator::NotEqualStar
+        ) {
+          ".*"
+        } else {
+          ""
+        };
+
+        // Markers have no arbitrary equality, so `===` is treated as `==`.
+        let operator = match specifier.operator() {
+          Operator::ExactEqual => &Operator::Equal,
+          operator => operator,
+        };
+
+        format!(
+          "python_full_version {operator} '{}{star}'",
+          specifier.version()
+        )
+      })
+      .collect::<Vec<_>>();
+
+    if clauses.is_empty() {
+      return None;
+    }
+
+    MarkerTree::parse_str::<VerbatimUrl>(&clauses.join(" and ")).ok()
+  }
+}
@@ -0,0 +1,88 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a dependency requires a newer Python than the project supports.
+  ///
+  /// Queries PyPI for the `requires_python` metadata of each dependency's
+  /// latest release and compares it against the lower bound of
+  /// `project.requires-python`. Disabled by default.
+  ProjectDependenciesRequiresPythonRule {
+    id: "project-dependencies-requires-python",
+    message: "dependency requires a newer Python than `project.requires-python`",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let pypi = context.pypi();
+
+      let Some(dependencies) = context.get("project.dependencies") else {
+        return Vec::new();
+      };
+
+      let Some(array) = dependencies.as_array() else {
+        return Vec::new();
+      };
+
+      let Some(minimum) = context
+        .get("project.requires-python")
+        .and_then(|node| node.as_str().map(|string| string.value().to_string()))
+        .and_then(|requires_python| Self::minimum_python(&requires_python))
+      else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        let Some(requires_python) =
+          pypi.requires_python(&requirement.name)
+        else {
+          continue;
+        };
+
+        if requires_python.contains(&minimum) {
+          continue;
+        }
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`project.dependencies` entry `{}` requires Python `{requires_python}` in its latest release, which excludes the minimum supported Python `{minimum}`",
+            requirement.name
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectDependenciesRequiresPythonRule {
+  pub(crate) fn minimum_python(requires_python: &str) -> Option<Version> {
+    VersionSpecifiers::from_str(requires_python)
+      .ok()?
+      .iter()
+      .filter(|specifier| {
+        matches!(
+          specifier.operator(),
+          Operator::Equal
+            | Operator::EqualStar
+            | Operator::ExactEqual
+            | Operator::GreaterThan
+            | Operator::GreaterThanEqual
+            | Operator::TildeEqual
+        )
+      })
+      .map(|specifier| specifier.version().clone())
+      .max()
+  }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dependencies` entries only allow yanked releases.
+  ///
+  /// Queries PyPI for each dependency's releases and flags pins such as
+  /// `requests==2.20.0`, or narrower specifiers, whose only matching releases
+  /// have been yanked. Installers skip yanked releases unless pinned exactly,
+  /// so these usually carry a known bug or security issue.
+  ProjectDependenciesYankedRule {
+    id: "project-dependencies-yanked",
+    message: "`project.dependencies` requires a yanked release",
+    run(context) {
+      let pypi = context.pypi();
+
+      let Some(array) = context
+        .get("project.dependencies")
+        .and_then(|node| node.as_array().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let Ok(requirement) =
+          Requirement::<VerbatimUrl>::from_str(string.value())
+        else {
+          continue;
+        };
+
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+          &requirement.version_or_url
+        else {
+          continue;
+        };
+
+        let Some(releases) = pypi.releases(&requirement.name)
+        else {
+          continue;
+        };
+
+        let matching = releases
+          .iter()
+          .filter(|release| specifiers.contains(&release.version))
+          .collect::<Vec<_>>();
+
+        if matching.iter().any(|release| release.yanked.is_none()) {
+          continue;
+        }
+
+        let Some(Release {
+          version,
+          yanked: Some(reason),
+        }) = matching.last()
+        else {
+          continue;
+        };
+
+        let verb = if matching.len() == 1
+          && specifiers.iter().all(|specifier| {
+            matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+          }) {
+          "pins"
+        } else {
+          "only allows"
+        };
+
+        let reason = if reason.trim().is_empty() {
+          String::new()
+        } else {
+          format!(" (reason: {})", reason.trim())
+        };
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`project.dependencies` entry `{}` {verb} yanked release `{version}`{reason}; pick a non-yanked version",
+            requirement.name
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
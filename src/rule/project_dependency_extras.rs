@@ -0,0 +1,133 @@
+use super::*;
+
+define_rule! {
+  /// Warns when dependencies request extras their package does not provide.
+  ///
+  /// Queries PyPI for the extras declared by each package referenced with
+  /// extras in `project.dependencies` or `project.optional-dependencies`, and
+  /// suggests the closest declared extra for likely typos. Dependencies pinned
+  /// to an exact version are checked against that release's metadata, and all
+  /// others against the latest release. Disabled by default.
+  ProjectDependencyExtrasRule {
+    id: "project-dependency-extras",
+    message: "dependency requests an unknown extra",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let pypi = context.pypi();
+
+      let mut diagnostics = Vec::new();
+
+      if let Some(dependencies) = context.get("project.dependencies") {
+        diagnostics.extend(Self::check_array(
+          pypi,
+          context.content(),
+          "project.dependencies",
+          &dependencies,
+        ));
+      }
+
+      if let Some(table) = context
+        .get("project.optional-dependencies")
+        .and_then(|node| node.as_table().cloned())
+      {
+        for (key, value) in table.entries().read().iter() {
+          diagnostics.extend(Self::check_array(
+            pypi,
+            context.content(),
+            &format!("project.optional-dependencies.{}", key.value()),
+            value,
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectDependencyExtrasRule {
+  const MAX_DISTANCE: usize = 2;
+
+  fn check_array(
+    pypi: &PyPiClient,
+    content: &Rope,
+    location: &str,
+    dependencies: &Node,
+  ) -> Vec<Diagnostic> {
+    let Some(array) = dependencies.as_array() else {
+      return Vec::new();
+    };
+
+    let mut diagnostics = Vec::new();
+
+    for item in array.items().read().iter() {
+      let Some(string) = item.as_str() else {
+        continue;
+      };
+
+      let Ok(requirement) =
+        Requirement::<VerbatimUrl>::from_str(string.value())
+      else {
+        continue;
+      };
+
+      if requirement.extras.is_empty() {
+        continue;
+      }
+
+      let available = match Self::pinned_version(&requirement) {
+        Some(version) => pypi.extras_for(&requirement.name, &version),
+        None => pypi.extras(&requirement.name),
+      };
+
+      let Some(available) = available else {
+        continue;
+      };
+
+      let available = available
+        .iter()
+        .filter_map(|extra| ExtraName::from_str(extra).ok())
+        .collect::<Vec<_>>();
+
+      for extra in &requirement.extras {
+        if available.contains(extra) {
+          continue;
+        }
+
+        let suggestion = available
+          .iter()
+          .map(|candidate| {
+            (edit_distance(extra.as_ref(), candidate.as_ref()), candidate)
+          })
+          .filter(|(distance, _)| *distance <= Self::MAX_DISTANCE)
+          .min()
+          .map(|(_, candidate)| format!(" (did you mean `{candidate}`?)"))
+          .unwrap_or_default();
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`{location}` entry `{}` requests extra `{extra}`, which the package does not provide{suggestion}",
+            requirement.name
+          ),
+          item.span(content),
+        ));
+      }
+    }
+
+    diagnostics
+  }
+
+  /// Returns the single version `requirement` pins with `==` or `===`.
+  fn pinned_version(requirement: &Requirement<VerbatimUrl>) -> Option<Version> {
+    let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
+      &requirement.version_or_url
+    else {
+      return None;
+    };
+
+    specifiers.iter().find_map(|specifier| {
+      matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+        .then(|| specifier.version().clone())
+    })
+  }
+}
@@ -3,12 +3,15 @@ use super::*;
 define_rule! {
   /// Warns when `project.dependencies` version constraints exclude the latest release.
   ///
-  /// Queries PyPI to check if the specified version constraints allow the
-  /// latest available version of each dependency.
+  /// Queries PyPI, looking up every dependency in one parallel batch, to check
+  /// if the specified version constraints allow the latest available version
+  /// of each dependency.
   ProjectDependencyUpdatesRule {
     id: "project-dependency-updates",
     message: "`project.dependencies` contains outdated package",
     run(context) {
+      let pypi = context.pypi();
+
       let Some(dependencies) = context.get("project.dependencies") else {
         return Vec::new();
       };
@@ -17,7 +20,7 @@ define_rule! {
         return Vec::new();
       };
 
-      let mut diagnostics = Vec::new();
+      let mut candidates = Vec::new();
 
       for item in array.items().read().iter() {
         let Some(string) = item.as_str() else {
@@ -31,7 +34,7 @@ define_rule! {
         };
 
         let Some(VersionOrUrl::VersionSpecifier(specifiers)) =
-          requirement.version_or_url.as_ref()
+          requirement.version_or_url
         else {
           continue;
         };
@@ -40,22 +43,36 @@ define_rule! {
           continue;
         }
 
-        let Some(latest_version) =
-          PyPiClient::shared().latest_version(&requirement.name)
-        else {
+        candidates.push((
+          requirement.name,
+          specifiers,
+          item.span(context.content()),
+        ));
+      }
+
+      let names = candidates
+        .iter()
+        .map(|(name, _, _)| name.clone())
+        .collect::<Vec<_>>();
+
+      let latest_versions = pypi.latest_versions(&names);
+
+      let mut diagnostics = Vec::new();
+
+      for (name, specifiers, range) in candidates {
+        let Some(Some(latest_version)) = latest_versions.get(&name) else {
           continue;
         };
 
-        if specifiers.contains(&latest_version) {
+        if specifiers.contains(latest_version) {
           continue;
         }
 
         diagnostics.push(Diagnostic::warning(
           format!(
-            "`project.dependencies` entry `{}` excludes the latest release `{}` (current constraint: `{}`)",
-            requirement.name, latest_version, specifiers
+            "`project.dependencies` entry `{name}` excludes the latest release `{latest_version}` (current constraint: `{specifiers}`)"
           ),
-          item.span(context.content()),
+          range,
         ));
       }
 
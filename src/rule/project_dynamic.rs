@@ -1,6 +1,6 @@
 use super::*;
 
-const ALLOWED_FIELDS: &[&str] = &[
+pub(crate) const DYNAMIC_FIELDS: &[&str] = &[
   "authors",
   "classifiers",
   "dependencies",
@@ -76,7 +76,7 @@ define_rule! {
           continue;
         }
 
-        if !ALLOWED_FIELDS.contains(&value) {
+        if !DYNAMIC_FIELDS.contains(&value) {
           diagnostics.push(Diagnostic::error(
             format!("`project.dynamic` contains unsupported field `{value}`"),
             item.span(context.content()),
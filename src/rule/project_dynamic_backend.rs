@@ -0,0 +1,99 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.dynamic` lists a field the build backend can't
+  /// compute.
+  ///
+  /// Identifies the backend from `build-system.build-backend` and checks each
+  /// `dynamic` entry against the fields it is known to fill in, bundled in
+  /// `backend_dynamic_fields.txt`. Backends that can compute any field, and
+  /// in-tree backends configured with `build-system.backend-path`, are
+  /// skipped.
+  ProjectDynamicBackendRule {
+    id: "project-dynamic-backend",
+    message: "`project.dynamic` field is not supported by the build backend",
+    run(context) {
+      if context.get("build-system.backend-path").is_some() {
+        return Vec::new();
+      }
+
+      let Some(backend) = context
+        .get("build-system.build-backend")
+        .and_then(|node| node.as_str().map(|string| string.value().to_string()))
+      else {
+        return Vec::new();
+      };
+
+      let Some(package) = BuildSystemBackendVersionRule::backend_package(&backend)
+      else {
+        return Vec::new();
+      };
+
+      let Some(fields) = Self::backend_fields(package) else {
+        return Vec::new();
+      };
+
+      let Some(array) = context
+        .get("project.dynamic")
+        .and_then(|node| node.as_array().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let supported = if fields.is_empty() {
+        format!("`{package}` does not support dynamic fields")
+      } else {
+        format!(
+          "`{package}` supports only {}",
+          fields
+            .iter()
+            .map(|field| format!("`{field}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+        )
+      };
+
+      array
+        .items()
+        .read()
+        .iter()
+        .filter_map(|item| {
+          let field = item.as_str()?.value().to_string();
+
+          (DYNAMIC_FIELDS.contains(&field.as_str())
+            && !fields.contains(&field.as_str()))
+          .then(|| {
+            Diagnostic::warning(
+              format!(
+                "`project.dynamic` field `{field}` cannot be computed by build backend `{backend}` ({supported})"
+              ),
+              item.span(context.content()),
+            )
+          })
+        })
+        .collect()
+    }
+  }
+}
+
+impl ProjectDynamicBackendRule {
+  fn backend_fields(package: &str) -> Option<&'static Vec<&'static str>> {
+    static BACKENDS: OnceLock<HashMap<&'static str, Vec<&'static str>>> =
+      OnceLock::new();
+
+    BACKENDS
+      .get_or_init(|| {
+        include_str!("backend_dynamic_fields.txt")
+          .lines()
+          .map(str::trim)
+          .filter(|line| !line.is_empty() && !line.starts_with('#'))
+          .filter_map(|line| {
+            let (package, fields) = line.split_once(':')?;
+
+            Some((package.trim(), fields.split_whitespace().collect()))
+          })
+          .collect()
+      })
+      .get(package)
+  }
+}
@@ -5,7 +5,9 @@ define_rule! {
   ///
   /// Checks that entry point names and object references follow the correct format,
   /// validates group names match the required pattern, and ensures `console_scripts`
-  /// and `gui_scripts` groups are defined in the proper sections.
+  /// and `gui_scripts` groups are defined in the proper sections. Script names
+  /// must also be usable as command names, and extras referenced by entry
+  /// points must be declared in `project.optional-dependencies`.
   ProjectEntryPointsRule {
     id: "project-entry-points",
     message: "invalid project entry points configuration",
@@ -33,12 +35,123 @@ define_rule! {
           .extend(Self::validate_entry_points_table(context.content(), &entry_points));
       }
 
+      diagnostics.extend(Self::check_declared_extras(context));
+
       diagnostics
     }
   }
 }
 
 impl ProjectEntryPointsRule {
+  fn check_declared_extras(context: &RuleContext<'_>) -> Vec<Diagnostic> {
+    let Some(declared) = Self::declared_extras(context) else {
+      return Vec::new();
+    };
+
+    let mut references = Vec::new();
+
+    for field in ["project.scripts", "project.gui-scripts"] {
+      if let Some(table) =
+        context.get(field).and_then(|node| node.as_table().cloned())
+      {
+        for (key, value) in table.entries().read().iter() {
+          references.push((format!("{field}.{}", key.value()), value.clone()));
+        }
+      }
+    }
+
+    if let Some(groups) = context
+      .get("project.entry-points")
+      .and_then(|node| node.as_table().cloned())
+    {
+      for (group_key, group) in groups.entries().read().iter() {
+        let Some(table) = group.as_table() else {
+          continue;
+        };
+
+        for (key, value) in table.entries().read().iter() {
+          references.push((
+            format!(
+              "project.entry-points.{}.{}",
+              group_key.value(),
+              key.value()
+            ),
+            value.clone(),
+          ));
+        }
+      }
+    }
+
+    let mut diagnostics = Vec::new();
+
+    for (location, value) in references {
+      let Some(string) = value.as_str() else {
+        continue;
+      };
+
+      let Some(extras) = string
+        .value()
+        .split_once('[')
+        .and_then(|(_, extras)| extras.trim_end().strip_suffix(']'))
+      else {
+        continue;
+      };
+
+      for extra in extras.split(',').map(str::trim) {
+        let Ok(name) = ExtraName::from_str(extra) else {
+          continue;
+        };
+
+        if !declared.contains(&name) {
+          diagnostics.push(Diagnostic::warning(
+            format!(
+              "`{location}` references extra `{extra}` that is not declared in `project.optional-dependencies`; installing with it won't pull in the intended dependencies"
+            ),
+            value.span(context.content()),
+          ));
+        }
+      }
+    }
+
+    diagnostics
+  }
+
+  /// Returns the extras declared in `project.optional-dependencies`, or `None`
+  /// when they can't be determined statically.
+  fn declared_extras(context: &RuleContext<'_>) -> Option<HashSet<ExtraName>> {
+    let dynamic = context
+      .get("project.dynamic")
+      .and_then(|node| node.as_array().cloned())
+      .is_some_and(|array| {
+        array.items().read().iter().any(|item| {
+          item
+            .as_str()
+            .is_some_and(|string| string.value() == "optional-dependencies")
+        })
+      });
+
+    if dynamic {
+      return None;
+    }
+
+    let Some(optional_dependencies) =
+      context.get("project.optional-dependencies")
+    else {
+      return Some(HashSet::new());
+    };
+
+    let table = optional_dependencies.as_table()?;
+
+    Some(
+      table
+        .entries()
+        .read()
+        .iter()
+        .filter_map(|(key, _)| ExtraName::from_str(key.value()).ok())
+        .collect(),
+    )
+  }
+
   fn is_group_segment(segment: &str) -> bool {
     !segment.is_empty()
       && segment
@@ -378,6 +491,35 @@ impl ProjectEntryPointsRule {
     None
   }
 
+  fn validate_script_name(
+    content: &Rope,
+    field: &str,
+    key: &Key,
+  ) -> Option<Diagnostic> {
+    let name = key.value();
+
+    if name.contains(['/', '\\']) {
+      return Some(Diagnostic::error(
+        format!(
+          "`{field}.{name}` name must not contain path separators; it is installed as a command"
+        ),
+        key.span(content),
+      ));
+    }
+
+    if name.chars().any(char::is_control) {
+      return Some(Diagnostic::error(
+        format!(
+          "`{field}.{}` name must not contain control characters; it is installed as a command",
+          name.escape_debug()
+        ),
+        key.span(content),
+      ));
+    }
+
+    None
+  }
+
   fn validate_scripts_table(
     content: &Rope,
     field: &str,
@@ -397,6 +539,7 @@ impl ProjectEntryPointsRule {
 
       if let Some(diagnostic) =
         Self::validate_entry_point_name(content, &location, key)
+          .or_else(|| Self::validate_script_name(content, field, key))
       {
         diagnostics.push(diagnostic);
       }
@@ -0,0 +1,72 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.keywords` entries contain uppercase letters.
+  ///
+  /// Keyword search on PyPI is case-insensitive, so mixed casing only makes
+  /// the list harder to scan. Each offending keyword comes with a fix that
+  /// lowercases it. Disabled by default.
+  ProjectKeywordsCaseRule {
+    id: "project-keywords-case",
+    message: "`project.keywords` entries should be lowercase",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(keywords) = context.get("project.keywords") else {
+        return Vec::new();
+      };
+
+      let Some(array) = keywords.as_array() else {
+        return Vec::new();
+      };
+
+      let content = context.content();
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let value = string.value();
+
+        let lowercase = value.to_lowercase();
+
+        if lowercase == value {
+          continue;
+        }
+
+        let range = item.span(content);
+
+        let diagnostic = Diagnostic::warning(
+          format!("`project.keywords` entry `{value}` should be lowercase"),
+          range,
+        );
+
+        let replacement_range = lsp::Range {
+          start: lsp::Position::new(range.start.line, range.start.character + 1),
+          end: lsp::Position::new(range.end.line, range.end.character - 1),
+        };
+
+        let raw = content
+          .slice(
+            content.lsp_position_to_char(replacement_range.start)
+              ..content.lsp_position_to_char(replacement_range.end),
+          )
+          .to_string();
+
+        diagnostics.push(if raw == value {
+          diagnostic.quickfix(Quickfix::replacement(
+            replacement_range,
+            value,
+            lowercase,
+          ))
+        } else {
+          diagnostic
+        });
+      }
+
+      diagnostics
+    }
+  }
+}
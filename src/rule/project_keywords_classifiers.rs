@@ -0,0 +1,161 @@
+use super::*;
+
+define_rule! {
+  /// Notes when `project.keywords` entries duplicate structured metadata.
+  ///
+  /// Keywords such as `python3` or `mit` repeat information that is better
+  /// expressed through trove classifiers or `project.license`. Disabled by
+  /// default.
+  ProjectKeywordsClassifiersRule {
+    id: "project-keywords-classifiers",
+    message: "`project.keywords` duplicates structured metadata",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(keywords) = context.get("project.keywords") else {
+        return Vec::new();
+      };
+
+      let Some(array) = keywords.as_array() else {
+        return Vec::new();
+      };
+
+      let classifiers = Self::declared_classifiers(context);
+
+      let licenses = Self::license_ids(context);
+
+      let mut diagnostics = Vec::new();
+
+      for item in array.items().read().iter() {
+        let Some(string) = item.as_str() else {
+          continue;
+        };
+
+        let keyword = string.value();
+
+        let term = Self::normalize(keyword);
+
+        if term.is_empty() {
+          continue;
+        }
+
+        let message = if let Some(classifier) = classifiers
+          .iter()
+          .find(|classifier| Self::terms(classifier).contains(&term))
+        {
+          format!(
+            "`project.keywords` entry `{keyword}` is already expressed by classifier `{classifier}`; rely on the classifier instead"
+          )
+        } else if let Some(license) =
+          licenses.iter().find(|id| Self::normalize(id) == term)
+        {
+          format!(
+            "`project.keywords` entry `{keyword}` is already expressed by `project.license` (`{license}`); rely on the license instead"
+          )
+        } else if let Some(classifier) = Self::known_classifier(&term) {
+          format!(
+            "`project.keywords` entry `{keyword}` can be expressed as classifier `{classifier}`; prefer the classifier instead"
+          )
+        } else {
+          continue;
+        };
+
+        diagnostics.push(Diagnostic::new(
+          message,
+          item.span(context.content()),
+          lsp::DiagnosticSeverity::INFORMATION,
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectKeywordsClassifiersRule {
+  const KNOWN_CATEGORIES: [&'static str; 2] =
+    ["Framework", "Programming Language"];
+
+  fn declared_classifiers(context: &RuleContext<'_>) -> Vec<String> {
+    let Some(classifiers) = context.get("project.classifiers") else {
+      return Vec::new();
+    };
+
+    let Some(array) = classifiers.as_array() else {
+      return Vec::new();
+    };
+
+    array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| item.as_str().map(|string| string.value().to_string()))
+      .collect()
+  }
+
+  fn known_classifier(term: &str) -> Option<&'static str> {
+    let mut matches = ProjectClassifiersRule::classifiers()
+      .iter()
+      .copied()
+      .filter(|classifier| {
+        let segments = classifier.split(" :: ").collect::<Vec<_>>();
+
+        segments.len() == 2
+          && Self::KNOWN_CATEGORIES.contains(&segments[0])
+          && Self::normalize(segments[1]) == term
+      })
+      .collect::<Vec<_>>();
+
+    matches.sort_unstable();
+
+    matches.first().copied()
+  }
+
+  fn license_ids(context: &RuleContext<'_>) -> Vec<String> {
+    let Some(license) = context.get("project.license") else {
+      return Vec::new();
+    };
+
+    let Some(string) = license.as_str() else {
+      return Vec::new();
+    };
+
+    let Ok(expression) =
+      spdx::Expression::parse_mode(string.value(), spdx::ParseMode::LAX)
+    else {
+      return Vec::new();
+    };
+
+    expression
+      .requirements()
+      .filter_map(|requirement| requirement.req.license.id())
+      .map(|id| id.name.to_string())
+      .collect()
+  }
+
+  fn normalize(value: &str) -> String {
+    value
+      .chars()
+      .filter(char::is_ascii_alphanumeric)
+      .map(|character| character.to_ascii_lowercase())
+      .collect()
+  }
+
+  fn terms(classifier: &str) -> Vec<String> {
+    let segments = classifier.split(" :: ").collect::<Vec<_>>();
+
+    let mut terms = Vec::new();
+
+    if let Some(last) = segments.last() {
+      terms.push(Self::normalize(last));
+    }
+
+    if let [.., parent, last] = segments.as_slice() {
+      terms.push(Self::normalize(&format!("{parent}{last}")));
+    }
+
+    terms
+      .retain(|term| !term.chars().all(|character| character.is_ascii_digit()));
+
+    terms
+  }
+}
@@ -4,7 +4,9 @@ define_rule! {
   /// Warns when `project.classifiers` contains license classifiers.
   ///
   /// License classifiers are deprecated in favor of the `project.license`
-  /// SPDX expression string introduced in PEP 639.
+  /// SPDX expression string introduced in PEP 639. Classifiers alongside
+  /// `project.license` or `project.license-files` are reported by
+  /// `project-license-mechanisms` instead.
   ProjectLicenseClassifiersDeprecatedRule {
     id: "project-license-classifiers-deprecated",
     message: "deprecated license classifiers in `project.classifiers`",
@@ -17,9 +19,11 @@ define_rule! {
         return Vec::new();
       };
 
-      let license_is_string = context
-        .get("project.license")
-        .is_some_and(|node| node.is_str());
+      if context.get("project.license").is_some()
+        || context.get("project.license-files").is_some()
+      {
+        return Vec::new();
+      }
 
       let mut diagnostics = Vec::new();
 
@@ -30,11 +34,7 @@ define_rule! {
 
         if value.value().starts_with("License ::") {
           diagnostics.push(Diagnostic::warning(
-            if license_is_string {
-              "`project.classifiers` license classifiers are deprecated when `project.license` is present (use only `project.license`)"
-            } else {
-              "`project.classifiers` license classifiers are deprecated; use `project.license` instead"
-            },
+            "`project.classifiers` license classifiers are deprecated; use `project.license` instead",
             item.span(context.content()),
           ));
         }
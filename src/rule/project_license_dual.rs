@@ -0,0 +1,51 @@
+use super::*;
+
+define_rule! {
+  /// Notes when `project.license` offers a choice between licenses.
+  ///
+  /// SPDX expressions using `OR` let consumers pick any of the alternatives,
+  /// which some compliance tools treat specially, so maintainers should
+  /// confirm the dual license is intentional. Disabled by default.
+  ProjectLicenseDualRule {
+    id: "project-license-dual",
+    message: "`project.license` offers a choice of licenses",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(license) = context.get("project.license") else {
+        return Vec::new();
+      };
+
+      let Some(string) = license.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(expression) =
+        spdx::Expression::parse_mode(string.value(), spdx::ParseMode::LAX)
+      else {
+        return Vec::new();
+      };
+
+      let is_choice = expression
+        .iter()
+        .any(|node| matches!(node, spdx::expression::ExprNode::Op(spdx::expression::Operator::Or)));
+
+      if !is_choice {
+        return Vec::new();
+      }
+
+      let alternatives = expression
+        .requirements()
+        .map(|requirement| format!("`{}`", requirement.req))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+      vec![Diagnostic::new(
+        format!(
+          "`project.license` lets users choose between {alternatives}; confirm the dual license is intentional"
+        ),
+        license.span(context.content()),
+        lsp::DiagnosticSeverity::INFORMATION,
+      )]
+    }
+  }
+}
@@ -4,7 +4,8 @@ define_rule! {
   /// Validates `project.license-files` glob patterns per PEP 639.
   ///
   /// Ensures patterns are valid, checks that they match existing files,
-  /// and verifies matched files are valid UTF-8 text.
+  /// verifies matched files are valid UTF-8 text, and notes patterns whose
+  /// matches overlap with another pattern's.
   ProjectLicenseFilesRule {
     id: "project-license-files",
     message: "invalid `project.license-files` configuration",
@@ -49,6 +50,8 @@ impl ProjectLicenseFilesRule {
       return diagnostics;
     };
 
+    let mut matched = Vec::new();
+
     for item in items.iter() {
       let Some(pattern) = item.as_str() else {
         diagnostics.push(Diagnostic::error(
@@ -91,16 +94,24 @@ impl ProjectLicenseFilesRule {
             item.span(content),
           ),
         ),
-        Ok(matches) => diagnostics.extend(
-          matches
-            .into_iter()
-            .filter_map(|path| Self::ensure_utf8_file(&path).err().map(|message| {
-              Diagnostic::error(
-                message,
-                item.span(content),
-              )
-            })),
-        ),
+        Ok(matches) => {
+          diagnostics.extend(
+            matches
+              .iter()
+              .filter_map(|path| Self::ensure_utf8_file(path).err().map(|message| {
+                Diagnostic::error(
+                  message,
+                  item.span(content),
+                )
+              })),
+          );
+
+          matched.push((
+            item.clone(),
+            pattern_value.to_string(),
+            matches.into_iter().collect::<HashSet<_>>(),
+          ));
+        }
         Err(error) => diagnostics.push(Diagnostic::error(
           format!(
             "failed to evaluate `project.license-files` pattern `{pattern_value}`: {error}"
@@ -110,6 +121,8 @@ impl ProjectLicenseFilesRule {
       }
     }
 
+    diagnostics.extend(Self::overlapping_patterns(content, &matched));
+
     diagnostics
   }
 
@@ -136,7 +149,10 @@ impl ProjectLicenseFilesRule {
     )
   }
 
-  fn matched_files(root: &Path, pattern: &str) -> Result<Vec<PathBuf>, String> {
+  pub(crate) fn matched_files(
+    root: &Path,
+    pattern: &str,
+  ) -> Result<Vec<PathBuf>, String> {
     let mut builder =
       GlobWalkerBuilder::from_patterns(root, &[pattern]).follow_links(false);
 
@@ -155,6 +171,51 @@ impl ProjectLicenseFilesRule {
     Ok(paths)
   }
 
+  /// Notes patterns that match files already matched by another pattern,
+  /// anchored on the more specific of the two.
+  fn overlapping_patterns(
+    content: &Rope,
+    matched: &[(Node, String, HashSet<PathBuf>)],
+  ) -> Vec<Diagnostic> {
+    let mut redundant = vec![None; matched.len()];
+
+    for (index, (_, _, files)) in matched.iter().enumerate() {
+      for (other, (_, _, other_files)) in
+        matched.iter().enumerate().skip(index + 1)
+      {
+        if files.is_disjoint(other_files) {
+          continue;
+        }
+
+        let (specific, general) = if files.len() < other_files.len() {
+          (index, other)
+        } else {
+          (other, index)
+        };
+
+        redundant[specific].get_or_insert(general);
+      }
+    }
+
+    redundant
+      .into_iter()
+      .enumerate()
+      .filter_map(|(specific, general)| Some((specific, general?)))
+      .map(|(specific, general)| {
+        let (item, pattern, _) = &matched[specific];
+
+        Diagnostic::new(
+          format!(
+            "`project.license-files` pattern `{pattern}` matches files already matched by `{}`; consider removing the duplicate pattern",
+            matched[general].1
+          ),
+          item.span(content),
+          lsp::DiagnosticSeverity::INFORMATION,
+        )
+      })
+      .collect()
+  }
+
   fn validate_license_files_pattern(pattern: &str) -> Result<(), String> {
     if pattern.starts_with('/') {
       return Err(
@@ -0,0 +1,72 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.license` contradicts the declared license intent.
+  ///
+  /// Compares the SPDX expression against `tool.pyproject.license-policy`'s
+  /// `intent`, flagging copyleft licenses on projects meant to be permissive
+  /// and vice versa. Disabled by default.
+  ProjectLicenseIntentRule {
+    id: "project-license-intent",
+    message: "`project.license` contradicts the license policy",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(intent) = context.config().license_policy.intent else {
+        return Vec::new();
+      };
+
+      let Some(license) = context.get("project.license") else {
+        return Vec::new();
+      };
+
+      let Some(string) = license.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(expression) =
+        spdx::Expression::parse_mode(string.value(), spdx::ParseMode::LAX)
+      else {
+        return Vec::new();
+      };
+
+      Self::check_intent(&expression, intent)
+        .map(|message| vec![Diagnostic::warning(message, license.span(context.content()))])
+        .unwrap_or_default()
+    }
+  }
+}
+
+impl ProjectLicenseIntentRule {
+  fn check_intent(
+    expression: &spdx::Expression,
+    intent: LicenseIntent,
+  ) -> Option<String> {
+    let copyleft = expression
+      .requirements()
+      .filter_map(|requirement| requirement.req.license.id())
+      .find(|id| id.is_copyleft());
+
+    match (intent, copyleft) {
+      (LicenseIntent::Permissive, Some(id))
+        if !expression
+          .evaluate(|requirement| !Self::is_copyleft(requirement)) =>
+      {
+        Some(format!(
+          "`project.license` requires copyleft license `{}`, but `tool.pyproject.license-policy` declares the project as permissive",
+          id.name
+        ))
+      }
+      (LicenseIntent::Copyleft, None) => Some(format!(
+        "`project.license` `{expression}` contains no copyleft license, but `tool.pyproject.license-policy` declares the project as copyleft"
+      )),
+      _ => None,
+    }
+  }
+
+  fn is_copyleft(requirement: &spdx::LicenseReq) -> bool {
+    requirement
+      .license
+      .id()
+      .is_some_and(spdx::LicenseId::is_copyleft)
+  }
+}
@@ -0,0 +1,284 @@
+use super::*;
+
+define_rule! {
+  /// Reports a project that declares its license through more than one
+  /// mechanism.
+  ///
+  /// PEP 639 replaces `project.license` tables and `License ::` classifiers
+  /// with an SPDX expression string plus `project.license-files`. Mixing a
+  /// legacy mechanism with anything else leaves tools guessing which one is
+  /// authoritative, so every mechanism in play is summarized in one
+  /// diagnostic. Combinations PEP 639 forbids outright are errors, and
+  /// classifiers naming a different license than the SPDX expression are
+  /// called out in the same diagnostic.
+  ProjectLicenseMechanismsRule {
+    id: "project-license-mechanisms",
+    message: "license is declared through multiple mechanisms",
+    run(context) {
+      let license = context.get("project.license");
+
+      let license_files = context.get("project.license-files");
+
+      let classifiers = Self::license_classifiers(context);
+
+      let mut mechanisms = Vec::new();
+
+      match &license {
+        Some(Node::Str(_)) => mechanisms.push(("an SPDX `project.license` string", license.clone())),
+        Some(Node::Table(_)) => mechanisms.push(("a `project.license` table", license.clone())),
+        _ => {}
+      }
+
+      if license_files.is_some() {
+        mechanisms.push(("`project.license-files`", license_files.clone()));
+      }
+
+      if classifiers.is_some() {
+        mechanisms.push(("`License ::` classifiers", classifiers.clone()));
+      }
+
+      let legacy = matches!(license, Some(Node::Table(_))) || classifiers.is_some();
+
+      if !legacy || mechanisms.len() < 2 {
+        return Vec::new();
+      }
+
+      let forbidden = match &license {
+        Some(Node::Str(_)) => classifiers.is_some(),
+        Some(Node::Table(_)) => license_files.is_some(),
+        _ => false,
+      };
+
+      let content = context.content();
+
+      let names = mechanisms
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+
+      let (mismatches, disagreement) = match (&license, &classifiers) {
+        (Some(Node::Str(string)), Some(classifiers)) => {
+          let mismatches = Self::mismatches(string.value(), classifiers);
+
+          let disagreement = if mismatches.is_empty() {
+            String::new()
+          } else {
+            format!(
+              " ({} {} `{}`)",
+              Self::list(
+                &mismatches
+                  .iter()
+                  .map(|(classifier, _)| format!("`{classifier}`"))
+                  .collect::<Vec<_>>()
+              ),
+              if mismatches.len() == 1 {
+                "disagrees with"
+              } else {
+                "disagree with"
+              },
+              string.value()
+            )
+          };
+
+          (mismatches, disagreement)
+        }
+        _ => (Vec::new(), String::new()),
+      };
+
+      let message = format!(
+        "license is declared through {}; use only an SPDX `project.license` string with `project.license-files` as described in PEP 639{disagreement}",
+        Self::list(&names)
+      );
+
+      let mut nodes = mechanisms.into_iter().filter_map(|(_, node)| node);
+
+      let Some(anchor) = nodes.next() else {
+        return Vec::new();
+      };
+
+      let diagnostic = if forbidden {
+        Diagnostic::error(message, anchor.span(content))
+      } else {
+        Diagnostic::warning(message, anchor.span(content))
+      };
+
+      let diagnostic = nodes.fold(diagnostic, |diagnostic, node| {
+        diagnostic.related(
+          lsp::Location {
+            range: node.span(content),
+            uri: context.document().uri.clone(),
+          },
+          "also declares the license",
+        )
+      });
+
+      vec![mismatches.into_iter().fold(diagnostic, |diagnostic, (_, node)| {
+        diagnostic.related(
+          lsp::Location {
+            range: node.span(content),
+            uri: context.document().uri.clone(),
+          },
+          "names a different license than `project.license`",
+        )
+      })]
+    }
+  }
+}
+
+impl ProjectLicenseMechanismsRule {
+  const CLASSIFIER_LICENSES: [(&'static str, &'static [&'static str]); 25] = [
+    (
+      "License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+      &["CC0-1.0"],
+    ),
+    (
+      "License :: OSI Approved :: Apache Software License",
+      &["Apache-1.0", "Apache-1.1", "Apache-2.0"],
+    ),
+    (
+      "License :: OSI Approved :: Artistic License",
+      &["Artistic-1.0", "Artistic-2.0"],
+    ),
+    (
+      "License :: OSI Approved :: BSD License",
+      &["0BSD", "BSD-2-Clause", "BSD-3-Clause", "BSD-4-Clause"],
+    ),
+    (
+      "License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)",
+      &["BSL-1.0"],
+    ),
+    (
+      "License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)",
+      &["EPL-2.0"],
+    ),
+    (
+      "License :: OSI Approved :: GNU Affero General Public License v3",
+      &["AGPL-3.0-only", "AGPL-3.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)",
+      &["AGPL-3.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+      &["GPL-2.0-only", "GPL-2.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)",
+      &["GPL-2.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU General Public License v3 (GPLv3)",
+      &["GPL-3.0-only", "GPL-3.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)",
+      &["GPL-3.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)",
+      &[
+        "LGPL-2.0-only",
+        "LGPL-2.0-or-later",
+        "LGPL-2.1-only",
+        "LGPL-2.1-or-later",
+      ],
+    ),
+    (
+      "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)",
+      &["LGPL-2.0-or-later", "LGPL-2.1-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)",
+      &["LGPL-3.0-only", "LGPL-3.0-or-later"],
+    ),
+    (
+      "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)",
+      &["LGPL-3.0-or-later"],
+    ),
+    ("License :: OSI Approved :: ISC License (ISCL)", &["ISC"]),
+    ("License :: OSI Approved :: MIT License", &["MIT"]),
+    (
+      "License :: OSI Approved :: MIT No Attribution License (MIT-0)",
+      &["MIT-0"],
+    ),
+    (
+      "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+      &["MPL-2.0"],
+    ),
+    (
+      "License :: OSI Approved :: Python Software Foundation License",
+      &["PSF-2.0", "Python-2.0"],
+    ),
+    (
+      "License :: OSI Approved :: The Unlicense (Unlicense)",
+      &["Unlicense"],
+    ),
+    (
+      "License :: OSI Approved :: Universal Permissive License (UPL)",
+      &["UPL-1.0"],
+    ),
+    ("License :: OSI Approved :: zlib/libpng License", &["Zlib"]),
+    ("License :: Public Domain", &["CC0-1.0", "Unlicense"]),
+  ];
+
+  /// Returns `project.classifiers` when it contains a `License ::` classifier.
+  pub(crate) fn license_classifiers(context: &RuleContext<'_>) -> Option<Node> {
+    context.get("project.classifiers").filter(|classifiers| {
+      classifiers.as_array().is_some_and(|array| {
+        array.items().read().iter().any(|item| {
+          item
+            .as_str()
+            .is_some_and(|string| string.value().starts_with("License ::"))
+        })
+      })
+    })
+  }
+
+  fn list<T: AsRef<str>>(names: &[T]) -> String {
+    let names = names.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+    match names.as_slice() {
+      [] => String::new(),
+      [name] => (*name).to_string(),
+      [first, second] => format!("{first} and {second}"),
+      [rest @ .., last] => format!("{}, and {last}", rest.join(", ")),
+    }
+  }
+
+  /// Returns the known license classifiers that name none of the licenses in
+  /// `expression`.
+  fn mismatches(expression: &str, classifiers: &Node) -> Vec<(String, Node)> {
+    let Ok(parsed) =
+      spdx::Expression::parse_mode(expression, spdx::ParseMode::LAX)
+    else {
+      return Vec::new();
+    };
+
+    let ids = parsed
+      .requirements()
+      .filter_map(|requirement| requirement.req.license.id())
+      .map(|id| id.name)
+      .collect::<Vec<_>>();
+
+    let Some(array) = classifiers.as_array() else {
+      return Vec::new();
+    };
+
+    array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| {
+        let classifier = item.as_str()?.value();
+
+        let (_, licenses) = Self::CLASSIFIER_LICENSES
+          .iter()
+          .find(|(name, _)| *name == classifier)?;
+
+        (!licenses.iter().any(|license| ids.contains(license)))
+          .then(|| (classifier.to_string(), item.clone()))
+      })
+      .collect()
+  }
+}
@@ -3,9 +3,11 @@ use super::*;
 define_rule! {
   /// Validates `project.license` value format.
   ///
-  /// When a string, ensures it's a valid, case-normalized SPDX expression.
-  /// When a table, validates `file`/`text` keys and checks that referenced
-  /// license files exist.
+  /// When a string, ensures it's a valid, case-normalized SPDX expression
+  /// that satisfies any configured `tool.pyproject.license-policy`, and that
+  /// custom `LicenseRef-*` licenses ship their text in
+  /// `project.license-files`. When a table, validates `file`/`text` keys and
+  /// checks that referenced license files exist.
   ProjectLicenseValueRule {
     id: "project-license",
     message: "project.license value is invalid",
@@ -14,34 +16,70 @@ define_rule! {
         return Vec::new();
       };
 
-      let license_files_present =
-        context.get("project.license-files").is_some();
-
       Self::check_license(
         context.document(),
         context.content(),
         &license,
-        license_files_present,
+        context.get("project.license-files").as_ref(),
+        &context.config().license_policy,
       )
     }
   }
 }
 
 impl ProjectLicenseValueRule {
+  const SUPPORTED_KEYS: [&'static str; 2] = ["file", "text"];
+
+  fn canonical_quickfix(
+    content: &Rope,
+    license: &Node,
+    value: &str,
+    canonical: String,
+  ) -> Quickfix {
+    let range = license.span(content);
+
+    Quickfix::replacement(
+      lsp::Range {
+        start: lsp::Position::new(range.start.line, range.start.character + 1),
+        end: lsp::Position::new(range.end.line, range.end.character - 1),
+      },
+      value,
+      canonical,
+    )
+  }
+
   fn check_license(
     document: &Document,
     content: &Rope,
     license: &Node,
-    license_files_present: bool,
+    license_files: Option<&Node>,
+    policy: &LicensePolicy,
   ) -> Vec<Diagnostic> {
     match license {
       Node::Str(string) => {
-        Self::check_license_string(content, license, string.value())
+        let mut diagnostics =
+          Self::check_license_string(content, license, string.value());
+
+        diagnostics.extend(Self::check_license_refs(
+          document,
+          content,
+          license,
+          string.value(),
+          license_files,
+        ));
+
+        if !policy.is_empty() {
+          diagnostics.extend(Self::check_policy(
+            content,
+            license,
+            string.value(),
+            policy,
+          ));
+        }
+
+        diagnostics
       }
-      Node::Table(_) if license_files_present => vec![Diagnostic::error(
-        "`project.license` must be a string SPDX expression when `project.license-files` is present",
-        license.span(content),
-      )],
+      Node::Table(_) if license_files.is_some() => Vec::new(),
       Node::Table(_) => Self::check_table(document, content, license),
       _ => vec![Diagnostic::error(
         "`project.license` must be a string or table",
@@ -50,6 +88,91 @@ impl ProjectLicenseValueRule {
     }
   }
 
+  fn check_license_refs(
+    document: &Document,
+    content: &Rope,
+    license: &Node,
+    value: &str,
+    license_files: Option<&Node>,
+  ) -> Vec<Diagnostic> {
+    let Ok(expression) =
+      spdx::Expression::parse_mode(value, spdx::ParseMode::LAX)
+    else {
+      return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+
+    let references = expression
+      .requirements()
+      .filter_map(|requirement| match &requirement.req.license {
+        spdx::LicenseItem::Other { lic_ref, .. } => {
+          Some((lic_ref.clone(), requirement.req.license.to_string()))
+        }
+        spdx::LicenseItem::Spdx { .. } => None,
+      })
+      .filter(|(lic_ref, _)| seen.insert(lic_ref.clone()))
+      .collect::<Vec<_>>();
+
+    if references.is_empty() {
+      return Vec::new();
+    }
+
+    let Some(license_files) = license_files else {
+      return references
+        .into_iter()
+        .map(|(_, reference)| {
+          Diagnostic::new(
+            format!(
+              "`project.license` uses custom license `{reference}`; add its text to `project.license-files` so it ships with the distribution"
+            ),
+            license.span(content),
+            lsp::DiagnosticSeverity::INFORMATION,
+          )
+        })
+        .collect();
+    };
+
+    let (Some(root), Some(array)) = (document.root(), license_files.as_array())
+    else {
+      return Vec::new();
+    };
+
+    let names = array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| item.as_str().map(|string| string.value().to_string()))
+      .filter_map(|pattern| {
+        ProjectLicenseFilesRule::matched_files(&root, &pattern).ok()
+      })
+      .flatten()
+      .filter_map(|path| {
+        path
+          .file_name()
+          .map(|name| name.to_string_lossy().to_lowercase())
+      })
+      .collect::<Vec<_>>();
+
+    references
+      .into_iter()
+      .filter(|(lic_ref, _)| {
+        let lic_ref = lic_ref.to_lowercase();
+
+        !names.iter().any(|name| name.contains(&lic_ref))
+      })
+      .map(|(_, reference)| {
+        Diagnostic::new(
+          format!(
+            "`project.license` uses custom license `{reference}`, but no file matched by `project.license-files` is named after it (e.g. `LICENSES/{reference}.txt`)"
+          ),
+          license.span(content),
+          lsp::DiagnosticSeverity::INFORMATION,
+        )
+      })
+      .collect()
+  }
+
   fn check_license_string(
     content: &Rope,
     license: &Node,
@@ -67,12 +190,17 @@ impl ProjectLicenseValueRule {
     match spdx::Expression::parse(value) {
       Ok(_) => {
         if let Ok(Some(canonical)) = spdx::Expression::canonicalize(value) {
-          diagnostics.push(Diagnostic::error(
-            format!(
-              "`project.license` must use a case-normalized SPDX expression (use `{canonical}`)"
-            ),
-            license.span(content),
-          ));
+          diagnostics.push(
+            Diagnostic::error(
+              format!(
+                "`project.license` must use a case-normalized SPDX expression (use `{canonical}`)"
+              ),
+              license.span(content),
+            )
+            .quickfix(Self::canonical_quickfix(
+              content, license, value, canonical,
+            )),
+          );
         }
       }
       Err(error)
@@ -83,18 +211,26 @@ impl ProjectLicenseValueRule {
       {
         let reason = error.reason.to_string();
 
-        let suggestion = spdx::Expression::canonicalize(value)
-          .ok()
-          .flatten()
+        let canonical = spdx::Expression::canonicalize(value).ok().flatten();
+
+        let suggestion = canonical
+          .as_ref()
           .map(|canonical| format!(" (did you mean `{canonical}`?)"))
           .unwrap_or_default();
 
-        diagnostics.push(Diagnostic::error(
+        let diagnostic = Diagnostic::error(
           format!(
             "`project.license` must be a valid SPDX expression: {reason}{suggestion}"
           ),
           license.span(content),
-        ));
+        );
+
+        diagnostics.push(match canonical {
+          Some(canonical) => diagnostic.quickfix(Self::canonical_quickfix(
+            content, license, value, canonical,
+          )),
+          None => diagnostic,
+        });
       }
       _ => {}
     }
@@ -102,6 +238,25 @@ impl ProjectLicenseValueRule {
     diagnostics
   }
 
+  fn check_policy(
+    content: &Rope,
+    license: &Node,
+    value: &str,
+    policy: &LicensePolicy,
+  ) -> Vec<Diagnostic> {
+    let Ok(expression) =
+      spdx::Expression::parse_mode(value, spdx::ParseMode::LAX)
+    else {
+      return Vec::new();
+    };
+
+    policy
+      .violations(&expression)
+      .into_iter()
+      .map(|message| Diagnostic::error(message, license.span(content)))
+      .collect()
+  }
+
   fn check_table(
     document: &Document,
     content: &Rope,
@@ -109,6 +264,17 @@ impl ProjectLicenseValueRule {
   ) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
 
+    if let Some(table) = license.as_table() {
+      for (key, _) in table.entries().read().iter() {
+        if !Self::SUPPORTED_KEYS.contains(&key.value()) {
+          diagnostics.push(Diagnostic::error(
+            "`project.license` only supports `file` and `text` keys",
+            key.span(content),
+          ));
+        }
+      }
+    }
+
     let file = license.try_get("file").ok();
     let text = license.try_get("text").ok();
 
@@ -6,6 +6,8 @@ define_rule! {
   ///
   /// Detects deprecated SPDX license IDs and exceptions, and warns that
   /// `project.license` tables are deprecated in favor of SPDX expression strings.
+  /// Tables mixed with another license mechanism are left to
+  /// `project-license-mechanisms`.
   ProjectLicenseValueDeprecationsRule {
     id: "project-license-deprecations",
     message: "deprecated `project.license` value",
@@ -14,10 +16,10 @@ define_rule! {
         return Vec::new();
       };
 
-      let license_files_present =
-        context.get("project.license-files").is_some();
+      let mixed = context.get("project.license-files").is_some()
+        || ProjectLicenseMechanismsRule::license_classifiers(context).is_some();
 
-      Self::warnings(context.content(), &license, license_files_present)
+      Self::warnings(context.content(), &license, mixed)
     }
   }
 }
@@ -65,11 +67,7 @@ impl ProjectLicenseValueDeprecationsRule {
     diagnostics
   }
 
-  fn warnings(
-    content: &Rope,
-    license: &Node,
-    license_files_present: bool,
-  ) -> Vec<Diagnostic> {
+  fn warnings(content: &Rope, license: &Node, mixed: bool) -> Vec<Diagnostic> {
     match license {
       Node::Str(string) => {
         let value = string.value();
@@ -99,7 +97,7 @@ impl ProjectLicenseValueDeprecationsRule {
           Err(_) => Vec::new(),
         }
       }
-      Node::Table(_) if license_files_present => Vec::new(),
+      Node::Table(_) if mixed => Vec::new(),
       Node::Table(_) => vec![Diagnostic::warning(
         "`project.license` tables are deprecated; prefer a SPDX expression string and `project.license-files`",
         license.span(content),
@@ -3,8 +3,8 @@ use super::*;
 define_rule! {
   /// Validates `project.name` is present and a valid distribution name.
   ///
-  /// Ensures the project name exists, is a non-empty string, and follows the
-  /// distribution name grammar.
+  /// Ensures the project name exists, is a non-empty string, follows the
+  /// distribution name grammar, and fits within PyPI's length limit.
   ProjectNameRule {
     id: "project-name",
     message: "invalid value for `project.name`",
@@ -29,7 +29,26 @@ define_rule! {
               name.span(content),
             ))
           } else if PROJECT_NAME.is_match(value) {
-            None
+            PackageName::from_str(value)
+              .ok()
+              .map(|normalized| normalized.as_ref().len())
+              .filter(|length| *length > Self::MAX_LENGTH)
+              .map(|length| {
+                Diagnostic::error(
+                  format!(
+                    "`project.name` must be at most {} characters once normalized, but is {length}; PyPI rejects longer names",
+                    Self::MAX_LENGTH
+                  ),
+                  name.span(content),
+                )
+              })
+          } else if value.starts_with(Self::SEPARATORS)
+            || value.ends_with(Self::SEPARATORS)
+          {
+            Some(Diagnostic::error(
+              "`project.name` must start and end with a letter or digit; PyPI rejects names beginning or ending with `.`, `-`, or `_`",
+              name.span(content),
+            ))
           } else {
             Some(Diagnostic::error(
               "`project.name` must be a valid distribution name",
@@ -50,3 +69,9 @@ define_rule! {
     }
   }
 }
+
+impl ProjectNameRule {
+  const MAX_LENGTH: usize = 214;
+
+  const SEPARATORS: [char; 3] = ['-', '.', '_'];
+}
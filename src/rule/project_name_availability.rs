@@ -0,0 +1,142 @@
+use super::*;
+
+define_rule! {
+  /// Notes when `project.name` is already registered on PyPI by someone else.
+  ///
+  /// Looks the name up on PyPI and compares the published author emails and
+  /// URLs against `project.authors`, `project.maintainers`, and
+  /// `project.urls`. A taken name is informational rather than an error, since
+  /// private indexes and unpublished projects can legitimately reuse it.
+  /// Disabled by default, and skipped in offline mode.
+  ProjectNameAvailabilityRule {
+    id: "project-name-availability",
+    message: "`project.name` is already registered on PyPI",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let pypi = context.pypi();
+
+      let Some(name) = context.get("project.name") else {
+        return Vec::new();
+      };
+
+      let Some(string) = name.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(package) = PackageName::from_str(string.value()) else {
+        return Vec::new();
+      };
+
+      let Some(owners) = pypi.owners(&package) else {
+        return Vec::new();
+      };
+
+      let local = Self::local_owners(context);
+
+      if owners
+        .iter()
+        .flat_map(|owner| Self::identifiers(owner))
+        .any(|identifier| local.contains(&identifier))
+      {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::new(
+        format!(
+          "`project.name` `{package}` is already registered on PyPI by another project; choose a different name before publishing"
+        ),
+        name.span(context.content()),
+        lsp::DiagnosticSeverity::INFORMATION,
+      )]
+    }
+  }
+}
+
+impl ProjectNameAvailabilityRule {
+  /// Splits a published metadata value into normalized emails or URLs, so that
+  /// `Jane <jane@example.com>` and `https://example.com/` compare equal to
+  /// `jane@example.com` and `http://example.com`.
+  fn identifiers(value: &str) -> Vec<String> {
+    value
+      .split(',')
+      .map(|part| {
+        let part = part.trim();
+
+        let part = part
+          .split_once('<')
+          .and_then(|(_, email)| email.strip_suffix('>'))
+          .unwrap_or(part);
+
+        let part = part.to_lowercase();
+
+        let part = part
+          .strip_prefix("https://")
+          .or_else(|| part.strip_prefix("http://"))
+          .unwrap_or(&part);
+
+        part
+          .strip_prefix("www.")
+          .unwrap_or(part)
+          .trim_end_matches('/')
+          .to_string()
+      })
+      .filter(|identifier| !identifier.is_empty())
+      .collect()
+  }
+
+  fn local_owners(context: &RuleContext<'_>) -> HashSet<String> {
+    let mut owners = HashSet::new();
+
+    for field in ["project.authors", "project.maintainers"] {
+      let Some(array) =
+        context.get(field).and_then(|node| node.as_array().cloned())
+      else {
+        continue;
+      };
+
+      for item in array.items().read().iter() {
+        if let Ok(email) = item.try_get("email")
+          && let Some(email) = email.as_str()
+        {
+          owners.extend(Self::identifiers(email.value()));
+        }
+      }
+    }
+
+    if let Some(urls) = context
+      .get("project.urls")
+      .and_then(|node| node.as_table().cloned())
+    {
+      for (_, url) in urls.entries().read().iter() {
+        if let Some(url) = url.as_str() {
+          owners.extend(Self::identifiers(url.value()));
+        }
+      }
+    }
+
+    owners
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn identifiers() {
+    #[track_caller]
+    fn case(value: &str, expected: &[&str]) {
+      assert_eq!(ProjectNameAvailabilityRule::identifiers(value), expected);
+    }
+
+    case("jane@example.com", &["jane@example.com"]);
+    case("Jane <Jane@Example.com>", &["jane@example.com"]);
+    case(
+      "Jane <jane@example.com>, bob@example.com",
+      &["jane@example.com", "bob@example.com"],
+    );
+    case("https://www.github.com/jane/foo/", &["github.com/jane/foo"]);
+    case("http://github.com/jane/foo", &["github.com/jane/foo"]);
+    case("", &[]);
+  }
+}
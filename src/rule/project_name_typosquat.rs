@@ -0,0 +1,68 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.name` is one edit away from a popular PyPI package.
+  ///
+  /// Names such as `reqeusts` or `numpyy` are easy to mistake for the package
+  /// they resemble, and PyPI may reject them as too similar to an existing
+  /// project. Disabled by default.
+  ProjectNameTyposquatRule {
+    id: "project-name-typosquat",
+    message: "`project.name` resembles a popular package",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(name) = context.get("project.name") else {
+        return Vec::new();
+      };
+
+      let Some(string) = name.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(normalized) = PackageName::from_str(string.value()) else {
+        return Vec::new();
+      };
+
+      let normalized = normalized.as_ref();
+
+      let packages = Self::popular_packages();
+
+      if packages.contains(&normalized) {
+        return Vec::new();
+      }
+
+      packages
+        .iter()
+        .find(|package| {
+          package.len() >= Self::MINIMUM_LENGTH
+            && package.len().abs_diff(normalized.len()) <= 1
+            && edit_distance(normalized, package) == 1
+        })
+        .map(|package| {
+          vec![Diagnostic::warning(
+            format!(
+              "`project.name` `{normalized}` is one edit away from popular package `{package}`"
+            ),
+            name.span(context.content()),
+          )]
+        })
+        .unwrap_or_default()
+    }
+  }
+}
+
+impl ProjectNameTyposquatRule {
+  const MINIMUM_LENGTH: usize = 4;
+
+  fn popular_packages() -> &'static Vec<&'static str> {
+    static PACKAGES: OnceLock<Vec<&'static str>> = OnceLock::new();
+
+    PACKAGES.get_or_init(|| {
+      include_str!("popular_packages.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+    })
+  }
+}
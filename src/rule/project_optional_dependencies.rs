@@ -3,8 +3,9 @@ use super::*;
 define_rule! {
   /// Validates `project.optional-dependencies` configuration.
   ///
-  /// Ensures extra names are valid PEP 508 identifiers, dependency arrays
-  /// contain valid PEP 508 strings, and package names are normalized.
+  /// Ensures extra names are valid PEP 508 identifiers normalized per PEP 685,
+  /// dependency arrays contain valid PEP 508 strings, and package names are
+  /// normalized.
   ProjectOptionalDependenciesRule {
     id: "project-optional-dependencies",
     message: "invalid `project.optional-dependencies` configuration",
@@ -31,7 +32,7 @@ define_rule! {
 
         let location = format!("project.optional-dependencies.{extra_name}");
 
-        if ExtraName::from_str(extra_name).is_err() {
+        let Ok(normalized) = ExtraName::from_str(extra_name) else {
           diagnostics.push(Diagnostic::error(
             format!(
               "`{location}` key `{extra_name}` must be a valid PEP 508 extra name"
@@ -40,6 +41,24 @@ define_rule! {
           ));
 
           continue;
+        };
+
+        if extra_name != normalized.as_ref() {
+          let range = extra_key.span(content);
+
+          diagnostics.push(
+            Diagnostic::warning(
+              format!(
+                "`{location}` key `{extra_name}` should be normalized per PEP 685 (use `{normalized}`)"
+              ),
+              range,
+            )
+            .quickfix(Quickfix::replacement(
+              range,
+              extra_name,
+              normalized.to_string(),
+            )),
+          );
         }
 
         let Some(array) = extra_value.as_array() else {
@@ -0,0 +1,157 @@
+use super::*;
+
+define_rule! {
+  /// Notes extras that duplicate a dependency group of the same purpose.
+  ///
+  /// Projects migrating to PEP 735 often keep a `dev` or `test` extra next to
+  /// the equivalent `[dependency-groups]` entry, and the two lists drift apart
+  /// over time. An extra is reported when a group with the same or an
+  /// equivalent name shares most of its packages. Disabled by default.
+  ProjectOptionalDependenciesGroupsOverlapRule {
+    id: "project-optional-dependencies-groups-overlap",
+    message: "`project.optional-dependencies` duplicates a dependency group",
+    default_level: RuleLevel::Off,
+    run(context) {
+      Self::overlaps(context)
+        .into_iter()
+        .map(|overlap| {
+          Diagnostic::new(
+            format!(
+              "`project.optional-dependencies.{}` shares {} of its {} packages with dependency group `{}`; consider keeping a single list to avoid drift",
+              overlap.extra, overlap.shared, overlap.packages, overlap.group
+            ),
+            overlap.extra_key.span(context.content()),
+            lsp::DiagnosticSeverity::INFORMATION,
+          )
+          .related(
+            lsp::Location {
+              range: overlap.group_key.span(context.content()),
+              uri: context.document().uri.clone(),
+            },
+            format!("dependency group `{}` defined here", overlap.group),
+          )
+        })
+        .collect()
+    }
+  }
+}
+
+/// An extra and a dependency group of the same purpose that share most of
+/// their packages.
+pub(crate) struct Overlap {
+  pub(crate) extra: ExtraName,
+  pub(crate) extra_key: Key,
+  pub(crate) group: ExtraName,
+  pub(crate) group_key: Key,
+  pub(crate) packages: usize,
+  pub(crate) shared: usize,
+}
+
+impl ProjectOptionalDependenciesGroupsOverlapRule {
+  const PURPOSES: [(&'static str, &'static [&'static str]); 4] = [
+    ("dev", &["dev", "develop", "development"]),
+    ("docs", &["doc", "docs", "documentation"]),
+    ("lint", &["lint", "linting"]),
+    ("test", &["test", "testing", "tests"]),
+  ];
+
+  /// Returns the extras that share most of their packages with a dependency
+  /// group of the same purpose.
+  pub(crate) fn overlaps(context: &RuleContext<'_>) -> Vec<Overlap> {
+    let (Some(optional_dependencies), Some(dependency_groups)) = (
+      context.get("project.optional-dependencies"),
+      context.get("dependency-groups"),
+    ) else {
+      return Vec::new();
+    };
+
+    let (Some(extras), Some(groups)) = (
+      optional_dependencies.as_table(),
+      dependency_groups.as_table(),
+    ) else {
+      return Vec::new();
+    };
+
+    let groups = groups
+      .entries()
+      .read()
+      .iter()
+      .filter_map(|(key, value)| {
+        let name = ExtraName::from_str(key.value()).ok()?;
+
+        Some((key.clone(), name, Self::packages(value)))
+      })
+      .collect::<Vec<_>>();
+
+    let mut overlaps = Vec::new();
+
+    for (key, value) in extras.entries().read().iter() {
+      let Ok(extra) = ExtraName::from_str(key.value()) else {
+        continue;
+      };
+
+      let packages = Self::packages(value);
+
+      for (group_key, group, group_packages) in &groups {
+        if Self::purpose(&extra) != Self::purpose(group) {
+          continue;
+        }
+
+        let shared = packages.intersection(group_packages).count();
+
+        let smaller = packages.len().min(group_packages.len());
+
+        if smaller == 0 || shared * 4 < smaller * 3 {
+          continue;
+        }
+
+        overlaps.push(Overlap {
+          extra: extra.clone(),
+          extra_key: key.clone(),
+          group: group.clone(),
+          group_key: group_key.clone(),
+          packages: packages.len(),
+          shared,
+        });
+      }
+    }
+
+    overlaps
+  }
+
+  fn packages(node: &Node) -> HashSet<PackageName> {
+    Self::requirements(node)
+      .into_iter()
+      .map(|(name, _)| name)
+      .collect()
+  }
+
+  fn purpose(name: &ExtraName) -> &str {
+    Self::PURPOSES
+      .iter()
+      .find(|(_, aliases)| aliases.contains(&name.as_ref()))
+      .map_or(name.as_ref(), |(purpose, _)| purpose)
+  }
+
+  /// Returns the package name and node of each requirement in the array
+  /// `node`, skipping direct URL references, whose names say nothing about
+  /// the version being installed.
+  pub(crate) fn requirements(node: &Node) -> Vec<(PackageName, Node)> {
+    let Some(array) = node.as_array() else {
+      return Vec::new();
+    };
+
+    array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| {
+        let requirement =
+          Requirement::<VerbatimUrl>::from_str(item.as_str()?.value()).ok()?;
+
+        (!matches!(requirement.version_or_url, Some(VersionOrUrl::Url(_))))
+          .then(|| (requirement.name, item.clone()))
+      })
+      .collect()
+  }
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+define_rule! {
+  /// Notes extras that nothing in the same file refers to.
+  ///
+  /// An extra counts as used when an entry point requests it, or when a
+  /// dependency list or dependency group depends on the project itself with
+  /// that extra (e.g. `all = ["demo[docs,test]"]`). Extras installed only from
+  /// outside the file look unused too, so the check is a heuristic for dead
+  /// extras rather than a proof. Disabled by default.
+  ProjectOptionalDependenciesUnusedRule {
+    id: "project-optional-dependencies-unused",
+    message: "`project.optional-dependencies` extra is not referenced",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(extras) = context
+        .get("project.optional-dependencies")
+        .and_then(|node| node.as_table().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let project = context
+        .get("project.name")
+        .and_then(|node| node.as_str().cloned())
+        .and_then(|name| PackageName::from_str(name.value()).ok());
+
+      let mut referenced = HashSet::new();
+
+      for field in ["project.scripts", "project.gui-scripts"] {
+        if let Some(scripts) = context.get(field) {
+          referenced.extend(Self::entry_point_extras(&scripts));
+        }
+      }
+
+      if let Some(table) = context
+        .get("project.entry-points")
+        .and_then(|node| node.as_table().cloned())
+      {
+        for (_, group) in table.entries().read().iter() {
+          referenced.extend(Self::entry_point_extras(group));
+        }
+      }
+
+      if let Some(project) = &project {
+        if let Some(dependencies) = context.get("project.dependencies") {
+          referenced.extend(Self::self_extras(project, &dependencies, None));
+        }
+
+        for (key, value) in extras.entries().read().iter() {
+          let extra = ExtraName::from_str(key.value()).ok();
+
+          referenced.extend(Self::self_extras(project, value, extra.as_ref()));
+        }
+
+        if let Some(groups) = context
+          .get("dependency-groups")
+          .and_then(|node| node.as_table().cloned())
+        {
+          for (_, value) in groups.entries().read().iter() {
+            referenced.extend(Self::self_extras(project, value, None));
+          }
+        }
+      }
+
+      let mut diagnostics = Vec::new();
+
+      for (key, _) in extras.entries().read().iter() {
+        let Ok(extra) = ExtraName::from_str(key.value()) else {
+          continue;
+        };
+
+        if referenced.contains(&extra) {
+          continue;
+        }
+
+        diagnostics.push(Diagnostic::new(
+          format!(
+            "`project.optional-dependencies.{}` is not referenced by any entry point or self-dependency in this file; remove it if it is no longer used",
+            key.value()
+          ),
+          key.span(context.content()),
+          lsp::DiagnosticSeverity::INFORMATION,
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectOptionalDependenciesUnusedRule {
+  fn entry_point_extras(node: &Node) -> Vec<ExtraName> {
+    let Some(table) = node.as_table() else {
+      return Vec::new();
+    };
+
+    table
+      .entries()
+      .read()
+      .iter()
+      .filter_map(|(_, value)| {
+        value.as_str().map(|string| string.value().to_string())
+      })
+      .filter_map(|value| {
+        let (_, rest) = value.split_once('[')?;
+        let (extras, _) = rest.split_once(']')?;
+        Some(extras.to_string())
+      })
+      .flat_map(|extras| {
+        extras
+          .split(',')
+          .filter_map(|extra| ExtraName::from_str(extra.trim()).ok())
+          .collect::<Vec<_>>()
+      })
+      .collect()
+  }
+
+  fn self_extras(
+    project: &PackageName,
+    node: &Node,
+    owner: Option<&ExtraName>,
+  ) -> Vec<ExtraName> {
+    let Some(array) = node.as_array() else {
+      return Vec::new();
+    };
+
+    array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| {
+        Requirement::<VerbatimUrl>::from_str(item.as_str()?.value()).ok()
+      })
+      .filter(|requirement| requirement.name == *project)
+      .flat_map(|requirement| requirement.extras)
+      .filter(|extra| Some(extra) != owner)
+      .collect()
+  }
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+define_rule! {
+  /// Notes `project.authors` and `project.maintainers` emails that use a
+  /// placeholder domain.
+  ///
+  /// Addresses at `example.com`, `test.com`, `localhost`, and similar
+  /// reserved domains are usually template values nobody replaced. Disabled
+  /// by default, since some projects use a reserved domain on purpose.
+  ProjectPeoplePlaceholderEmailRule {
+    id: "project-people-placeholder-email",
+    message: "`project.authors` / `project.maintainers` uses a placeholder email",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let mut diagnostics = Vec::new();
+
+      for field in ["project.authors", "project.maintainers"] {
+        let Some(people) = context.get(field) else {
+          continue;
+        };
+
+        let Some(array) = people.as_array() else {
+          continue;
+        };
+
+        for person in array.items().read().iter() {
+          let Ok(email) = person.try_get("email") else {
+            continue;
+          };
+
+          let Some(domain) = email
+            .as_str()
+            .and_then(|string| Self::placeholder_domain(string.value()))
+          else {
+            continue;
+          };
+
+          diagnostics.push(Diagnostic::new(
+            format!(
+              "`{field}` email uses placeholder domain `{domain}`; replace it with a real contact address"
+            ),
+            email.span(context.content()),
+            lsp::DiagnosticSeverity::INFORMATION,
+          ));
+        }
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ProjectPeoplePlaceholderEmailRule {
+  const PLACEHOLDER_DOMAINS: [&'static str; 5] = [
+    "example.com",
+    "example.net",
+    "example.org",
+    "localhost",
+    "test.com",
+  ];
+
+  const RESERVED_SUFFIXES: [&'static str; 4] =
+    [".example", ".invalid", ".localhost", ".test"];
+
+  fn placeholder_domain(email: &str) -> Option<String> {
+    let (_, domain) = email.trim().trim_end_matches('>').rsplit_once('@')?;
+
+    let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+
+    let is_placeholder = Self::PLACEHOLDER_DOMAINS.iter().any(|placeholder| {
+      domain == *placeholder || domain.ends_with(&format!(".{placeholder}"))
+    }) || Self::RESERVED_SUFFIXES
+      .iter()
+      .any(|suffix| domain.ends_with(suffix));
+
+    is_placeholder.then_some(domain)
+  }
+}
@@ -0,0 +1,120 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `[project]` lacks metadata recommended for publishing to PyPI.
+  ///
+  /// Checks for a description, readme, license, authors or maintainers, and a
+  /// homepage or repository URL. Fields listed in `project.dynamic` are
+  /// considered present. Disabled by default.
+  ProjectPublishReadinessRule {
+    id: "project-publish-readiness",
+    message: "`[project]` is missing recommended metadata",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(project) = context.get("project") else {
+        return Vec::new();
+      };
+
+      if !project.is_table() {
+        return Vec::new();
+      }
+
+      let dynamic = Self::dynamic_fields(&project);
+
+      let has = |field: &str| {
+        dynamic.contains(field) || context.get(&format!("project.{field}")).is_some()
+      };
+
+      let mut missing = Vec::new();
+
+      if !has("description") {
+        missing.push("`project.description`");
+      }
+
+      if !has("readme") {
+        missing.push("`project.readme`");
+      }
+
+      if !has("license") && !Self::has_license_classifier(context) {
+        missing.push("`project.license`");
+      }
+
+      if !has("authors") && !has("maintainers") {
+        missing.push("`project.authors` or `project.maintainers`");
+      }
+
+      if !dynamic.contains("urls") && !Self::has_project_url(context) {
+        missing.push("a `Homepage` or `Repository` entry in `project.urls`");
+      }
+
+      missing
+        .into_iter()
+        .map(|field| {
+          Diagnostic::warning(
+            format!("`[project]` should declare {field} before publishing to PyPI"),
+            project.span(context.content()),
+          )
+        })
+        .collect()
+    }
+  }
+}
+
+impl ProjectPublishReadinessRule {
+  const URL_LABELS: [&'static str; 6] = [
+    "github",
+    "gitlab",
+    "homepage",
+    "repository",
+    "source",
+    "sourcecode",
+  ];
+
+  fn dynamic_fields(project: &Node) -> HashSet<String> {
+    let Ok(dynamic) = project.try_get("dynamic") else {
+      return HashSet::new();
+    };
+
+    let Some(array) = dynamic.as_array() else {
+      return HashSet::new();
+    };
+
+    array
+      .items()
+      .read()
+      .iter()
+      .filter_map(|item| item.as_str().map(|string| string.value().to_string()))
+      .collect()
+  }
+
+  fn has_license_classifier(context: &RuleContext<'_>) -> bool {
+    context
+      .get("project.classifiers")
+      .and_then(|classifiers| classifiers.as_array().cloned())
+      .is_some_and(|array| {
+        array.items().read().iter().any(|item| {
+          item
+            .as_str()
+            .is_some_and(|string| string.value().starts_with("License ::"))
+        })
+      })
+  }
+
+  fn has_project_url(context: &RuleContext<'_>) -> bool {
+    context
+      .get("project.urls")
+      .and_then(|urls| urls.as_table().cloned())
+      .is_some_and(|table| {
+        table.entries().read().iter().any(|(key, _)| {
+          let label = key
+            .value()
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+          Self::URL_LABELS.contains(&label.as_str())
+        })
+      })
+  }
+}
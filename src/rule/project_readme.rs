@@ -140,6 +140,16 @@ impl ProjectReadmeRule {
           text.span(content),
         ));
       }
+      Some(text)
+        if text
+          .as_str()
+          .is_some_and(|string| string.value().trim().is_empty()) =>
+      {
+        diagnostics.push(Diagnostic::warning(
+          "`project.readme.text` should not be empty; the project description would render blank on package indexes",
+          text.span(content),
+        ));
+      }
       _ => {}
     }
 
@@ -1,10 +1,12 @@
 use super::*;
 
 define_rule! {
-  /// Warns when `project.readme.content-type` is `text/plain`.
+  /// Flags `project.readme.content-type` values of `text/plain`.
   ///
   /// Suggests using `text/markdown` or `text/x-rst` for better rendering
-  /// on package indexes like PyPI.
+  /// on package indexes like PyPI. The suggestion is informational, unless the
+  /// readme text or file looks like Markdown or reStructuredText, in which case
+  /// the matching content type is suggested with a warning.
   ProjectReadmeContentTypeRule {
     id: "project-readme-content-type",
     message: "suboptimal `project.readme` content type",
@@ -27,14 +29,88 @@ define_rule! {
 
       let value = string.value();
 
-      if value.eq_ignore_ascii_case("text/plain") {
-        return vec![Diagnostic::warning(
+      if !value.eq_ignore_ascii_case("text/plain") {
+        return Vec::new();
+      }
+
+      let range = content_type.span(context.content());
+
+      let Some((syntax, suggestion)) = Self::readme_text(context, &readme)
+        .and_then(|text| Self::sniff(&text))
+      else {
+        return vec![Diagnostic::new(
           "`project.readme.content-type` is `text/plain`; consider `text/markdown` or `text/x-rst` for better rendering on package indexes",
-          content_type.span(context.content()),
+          range,
+          lsp::DiagnosticSeverity::INFORMATION,
         )];
-      }
+      };
+
+      let replacement_range = lsp::Range {
+        start: lsp::Position::new(range.start.line, range.start.character + 1),
+        end: lsp::Position::new(range.end.line, range.end.character - 1),
+      };
+
+      vec![
+        Diagnostic::warning(
+          format!(
+            "`project.readme.content-type` is `text/plain`, but the readme looks like {syntax}; use `{suggestion}`"
+          ),
+          range,
+        )
+        .quickfix(Quickfix::replacement(replacement_range, value, suggestion)),
+      ]
+    }
+  }
+}
+
+impl ProjectReadmeContentTypeRule {
+  fn is_rst_underline(line: &str) -> bool {
+    let line = line.trim_end();
 
-      Vec::new()
+    line.len() >= 3
+      && line.chars().next().is_some_and(|first| {
+        matches!(first, '=' | '-' | '~' | '^' | '*')
+          && line.chars().all(|character| character == first)
+      })
+  }
+
+  fn readme_text(context: &RuleContext<'_>, readme: &Node) -> Option<String> {
+    if let Some(text) = readme
+      .try_get("text")
+      .ok()
+      .and_then(|text| text.as_str().map(|string| string.value().to_string()))
+    {
+      return Some(text);
     }
+
+    let file = readme.try_get("file").ok()?;
+
+    let path = context.document().resolve_path(file.as_str()?.value())?;
+
+    fs::read_to_string(path).ok()
+  }
+
+  fn sniff(text: &str) -> Option<(&'static str, &'static str)> {
+    let lines = text.lines().collect::<Vec<_>>();
+
+    let markdown = lines.iter().any(|line| {
+      let line = line.trim_start();
+
+      line.starts_with("```")
+        || (line.starts_with('#')
+          && line.trim_start_matches('#').starts_with(' '))
+    }) || text.contains("](");
+
+    if markdown {
+      return Some(("Markdown", "text/markdown"));
+    }
+
+    let rst = lines.iter().any(|line| line.starts_with(".. "))
+      || text.contains(">`_")
+      || lines.windows(2).any(|pair| {
+        !pair[0].trim().is_empty() && Self::is_rst_underline(pair[1])
+      });
+
+    rst.then_some(("reStructuredText", "text/x-rst"))
   }
 }
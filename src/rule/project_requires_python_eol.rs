@@ -0,0 +1,64 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.requires-python` admits an end-of-life Python.
+  ///
+  /// Determines the lowest Python minor version the specifier allows and
+  /// flags floors that no longer receive security updates, since new
+  /// releases rarely need to support them.
+  ProjectRequiresPythonEolRule {
+    id: "project-requires-python-eol",
+    message: "`project.requires-python` allows an end-of-life Python",
+    run(context) {
+      let Some(requires_python) = context.get("project.requires-python") else {
+        return Vec::new();
+      };
+
+      let Some(string) = requires_python.as_str() else {
+        return Vec::new();
+      };
+
+      let Some(minimum) =
+        ProjectDependenciesRequiresPythonRule::minimum_python(string.value())
+      else {
+        return Vec::new();
+      };
+
+      let release = minimum.release();
+
+      let floor = (
+        release.first().copied().unwrap_or_default(),
+        release.get(1).copied().unwrap_or_default(),
+      );
+
+      if !Self::EOL_MINORS.iter().any(|eol| floor <= *eol) {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::warning(
+        format!(
+          "`project.requires-python` allows Python {}.{}, which is end-of-life; consider raising the lower bound",
+          floor.0, floor.1
+        ),
+        requires_python.span(context.content()),
+      )]
+    }
+  }
+}
+
+impl ProjectRequiresPythonEolRule {
+  /// Python minor versions past end-of-life, as of 2026-10-17.
+  const EOL_MINORS: &[(u64, u64)] = &[
+    (2, 7),
+    (3, 0),
+    (3, 1),
+    (3, 2),
+    (3, 3),
+    (3, 4),
+    (3, 5),
+    (3, 6),
+    (3, 7),
+    (3, 8),
+    (3, 9),
+  ];
+}
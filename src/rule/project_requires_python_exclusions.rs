@@ -0,0 +1,46 @@
+use super::*;
+
+define_rule! {
+  /// Notes `project.requires-python` clauses that exclude a whole minor
+  /// version.
+  ///
+  /// A specifier such as `>=3.8,!=3.9.*` leaves a gap in the supported range,
+  /// which is unusual and often left over from a since-fixed incompatibility.
+  /// Disabled by default, since some exclusions are deliberate.
+  ProjectRequiresPythonExclusionsRule {
+    id: "project-requires-python-exclusions",
+    message: "`project.requires-python` excludes a Python minor version",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(requires_python) = context.get("project.requires-python") else {
+        return Vec::new();
+      };
+
+      let Some(string) = requires_python.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(specifiers) = VersionSpecifiers::from_str(string.value()) else {
+        return Vec::new();
+      };
+
+      specifiers
+        .iter()
+        .filter(|specifier| {
+          *specifier.operator() == Operator::NotEqualStar
+            && specifier.version().release().len() == 2
+        })
+        .map(|specifier| {
+          Diagnostic::new(
+            format!(
+              "`project.requires-python` excludes every Python {} release with `{specifier}`; confirm this exclusion is intentional",
+              specifier.version()
+            ),
+            requires_python.span(context.content()),
+            lsp::DiagnosticSeverity::INFORMATION,
+          )
+        })
+        .collect()
+    }
+  }
+}
@@ -9,7 +9,8 @@ define_rule! {
   /// Validates `project.urls` entries.
   ///
   /// Ensures URLs are valid HTTP/HTTPS URLs and labels do not exceed
-  /// 32 characters.
+  /// 32 characters, and notes URLs pointing at `localhost` or private
+  /// network addresses, which are usually leftover development values.
   ProjectUrlsRule {
     id: "project-urls",
     message: "invalid project url(s)",
@@ -40,6 +41,28 @@ impl ProjectUrlsRule {
     matches!(scheme, "http" | "https")
   }
 
+  fn is_local_host(host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+
+    if host.eq_ignore_ascii_case("localhost")
+      || host.to_ascii_lowercase().ends_with(".localhost")
+    {
+      return true;
+    }
+
+    match host.parse::<IpAddr>() {
+      Ok(IpAddr::V4(address)) => {
+        address.is_loopback()
+          || address.is_private()
+          || address.is_unspecified()
+      }
+      Ok(IpAddr::V6(address)) => {
+        address.is_loopback() || address.is_unspecified()
+      }
+      Err(_) => false,
+    }
+  }
+
   fn locations() -> &'static [UrlLocation] {
     &[
       UrlLocation {
@@ -111,7 +134,20 @@ impl ProjectUrlsRule {
     location: &str,
   ) -> Vec<Diagnostic> {
     match lsp::Url::parse(value) {
-      Ok(url) if Self::is_browsable_scheme(url.scheme()) => Vec::new(),
+      Ok(url) if Self::is_browsable_scheme(url.scheme()) => url
+        .host_str()
+        .filter(|host| Self::is_local_host(host))
+        .map(|host| {
+          Diagnostic::new(
+            format!(
+              "`{location}` entry `{label}` points at local host `{host}`, which is unreachable for anyone installing the package"
+            ),
+            node.span(content),
+            lsp::DiagnosticSeverity::INFORMATION,
+          )
+        })
+        .into_iter()
+        .collect(),
       Ok(_) => vec![Diagnostic::error(
         format!(
           "`{location}` entry `{label}` must use an `http` or `https` URL"
@@ -0,0 +1,56 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `project.urls` entries cannot be reached.
+  ///
+  /// Sends a `HEAD` request to each HTTP or HTTPS URL and reports error
+  /// responses, timeouts, and connection failures. Requests are skipped when
+  /// `PYPROJECT_OFFLINE` is set. Disabled by default.
+  ProjectUrlsReachableRule {
+    id: "project-urls-reachable",
+    message: "`project.urls` contains an unreachable URL",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(client) = context.urls() else {
+        return Vec::new();
+      };
+
+      let Some(urls) = context.get("project.urls") else {
+        return Vec::new();
+      };
+
+      let Some(table) = urls.as_table() else {
+        return Vec::new();
+      };
+
+      let entries = table
+        .entries()
+        .read()
+        .iter()
+        .filter_map(|(key, value)| {
+          let url = value.as_str()?.value().to_string();
+
+          let parsed = lsp::Url::parse(&url).ok()?;
+
+          if !matches!(parsed.scheme(), "http" | "https") {
+            return None;
+          }
+
+          Some((key.value().to_string(), url, value.span(context.content())))
+        })
+        .collect::<Vec<_>>();
+
+      entries
+        .par_iter()
+        .filter_map(|(label, url, range)| {
+          client.failure(url).map(|failure| {
+            Diagnostic::warning(
+              format!("`project.urls` entry `{label}` URL `{url}` {failure}"),
+              *range,
+            )
+          })
+        })
+        .collect()
+    }
+  }
+}
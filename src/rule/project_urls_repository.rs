@@ -0,0 +1,117 @@
+use super::*;
+
+define_rule! {
+  /// Warns when an open source project does not link to its repository.
+  ///
+  /// Projects whose `project.license` is an OSI-approved or FSF free SPDX
+  /// expression should point users at their source code. A `project.urls`
+  /// entry counts as a repository link when its label is one of the
+  /// well-known source labels, such as `Repository` or `Source`, or when its
+  /// URL is on a known code host. Disabled by default.
+  ProjectUrlsRepositoryRule {
+    id: "project-urls-repository",
+    message: "`project.urls` is missing a repository link",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let (Some(project), Some(license)) =
+        (context.get("project"), context.get("project.license"))
+      else {
+        return Vec::new();
+      };
+
+      let Some(string) = license.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(expression) =
+        spdx::Expression::parse_mode(string.value(), spdx::ParseMode::LAX)
+      else {
+        return Vec::new();
+      };
+
+      if !expression.evaluate(Self::is_open_source) {
+        return Vec::new();
+      }
+
+      let urls = context.get("project.urls");
+
+      let linked = urls
+        .as_ref()
+        .and_then(Node::as_table)
+        .is_some_and(|table| {
+          table
+            .entries()
+            .read()
+            .iter()
+            .any(|(key, value)| Self::is_repository(key.value(), value))
+        });
+
+      if linked {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::warning(
+        format!(
+          "`project.license` `{}` is open source, but `project.urls` has no repository link; add a `Source` or `Repository` URL so users can find the code",
+          string.value()
+        ),
+        urls.as_ref().unwrap_or(&project).span(context.content()),
+      )]
+    }
+  }
+}
+
+impl ProjectUrlsRepositoryRule {
+  const CODE_HOSTS: [&'static str; 8] = [
+    "bitbucket.org",
+    "codeberg.org",
+    "git.sr.ht",
+    "github.com",
+    "gitlab.com",
+    "launchpad.net",
+    "salsa.debian.org",
+    "sourceforge.net",
+  ];
+
+  const REPOSITORY_LABELS: [&'static str; 7] = [
+    "code",
+    "github",
+    "gitlab",
+    "repository",
+    "source",
+    "sourcecode",
+    "sources",
+  ];
+
+  fn is_open_source(requirement: &spdx::LicenseReq) -> bool {
+    requirement
+      .license
+      .id()
+      .is_some_and(|id| id.is_osi_approved() || id.is_fsf_free_libre())
+  }
+
+  fn is_repository(label: &str, value: &Node) -> bool {
+    let label = label
+      .chars()
+      .filter(|character| character.is_alphanumeric())
+      .flat_map(char::to_lowercase)
+      .collect::<String>();
+
+    if Self::REPOSITORY_LABELS.contains(&label.as_str()) {
+      return true;
+    }
+
+    value
+      .as_str()
+      .and_then(|string| lsp::Url::parse(string.value()).ok())
+      .and_then(|url| url.host_str().map(str::to_lowercase))
+      .is_some_and(|host| {
+        Self::CODE_HOSTS.iter().any(|code_host| {
+          host == *code_host
+            || host
+              .strip_suffix(code_host)
+              .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+      })
+  }
+}
@@ -2,6 +2,10 @@ use super::*;
 
 define_rule! {
   /// Validates `project.version` is present (unless dynamic) and PEP 440 compliant.
+  ///
+  /// Versions that parse but differ from their normalized form, such as
+  /// `1.0-1` or `01.0`, are reported with a suggestion to use the canonical
+  /// spelling.
   ProjectVersionRule {
     id: "project-version",
     message: "invalid `project.version` value",
@@ -29,13 +33,16 @@ define_rule! {
               "`project.version` must not be empty",
               version.span(content),
             ))
-          } else if let Err(error) = Version::from_str(value) {
-            Some(Diagnostic::error(
-              error.to_string(),
-              version.span(content),
-            ))
           } else {
-            None
+            match Version::from_str(value) {
+              Ok(parsed) => {
+                Self::check_canonical(content, version, value, &parsed)
+              }
+              Err(error) => Some(Diagnostic::error(
+                error.to_string(),
+                version.span(content),
+              )),
+            }
           }
         }
         None => Some(Diagnostic::error(
@@ -53,6 +60,49 @@ define_rule! {
 }
 
 impl ProjectVersionRule {
+  fn check_canonical(
+    content: &Rope,
+    node: &Node,
+    value: &str,
+    version: &Version,
+  ) -> Option<Diagnostic> {
+    let canonical = version.to_string();
+
+    if value == canonical {
+      return None;
+    }
+
+    let range = node.span(content);
+
+    let diagnostic = Diagnostic::new(
+      format!("`project.version` is not normalized (use `{canonical}`)"),
+      range,
+      lsp::DiagnosticSeverity::INFORMATION,
+    );
+
+    // The replacement covers the text between single-character quotes, so
+    // multi-line strings, with their triple quotes, get no quickfix.
+    let single_line = node.syntax().is_some_and(|syntax| {
+      matches!(
+        syntax.kind(),
+        SyntaxKind::STRING | SyntaxKind::STRING_LITERAL
+      )
+    });
+
+    if !single_line {
+      return Some(diagnostic);
+    }
+
+    Some(diagnostic.quickfix(Quickfix::replacement(
+      lsp::Range {
+        start: lsp::Position::new(range.start.line, range.start.character + 1),
+        end: lsp::Position::new(range.end.line, range.end.character - 1),
+      },
+      value,
+      canonical,
+    )))
+  }
+
   fn version_listed_in_dynamic(project: &Node) -> bool {
     let Some(dynamic) = project.try_get("dynamic").ok() else {
       return false;
@@ -0,0 +1,40 @@
+use super::*;
+
+define_rule! {
+  /// Notes when `project.version` uses a PEP 440 epoch.
+  ///
+  /// An epoch such as `1!2.0` makes the version sort after every version
+  /// without one, which is only useful when resetting a versioning scheme and
+  /// is otherwise usually a typo. Disabled by default.
+  ProjectVersionEpochRule {
+    id: "project-version-epoch",
+    message: "`project.version` uses an epoch",
+    default_level: RuleLevel::Off,
+    run(context) {
+      let Some(version) = context.get("project.version") else {
+        return Vec::new();
+      };
+
+      let Some(string) = version.as_str() else {
+        return Vec::new();
+      };
+
+      let Ok(parsed) = Version::from_str(string.value()) else {
+        return Vec::new();
+      };
+
+      if parsed.epoch() == 0 {
+        return Vec::new();
+      }
+
+      vec![Diagnostic::new(
+        format!(
+          "`project.version` uses epoch `{}`, which sorts it after every version without one; confirm the versioning scheme reset is intentional",
+          parsed.epoch()
+        ),
+        version.span(context.content()),
+        lsp::DiagnosticSeverity::INFORMATION,
+      )]
+    }
+  }
+}
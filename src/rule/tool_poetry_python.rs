@@ -0,0 +1,200 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `tool.poetry.dependencies.python` disagrees with
+  /// `project.requires-python`.
+  ///
+  /// Projects migrating from Poetry often keep the old `python = "^3.8"`
+  /// constraint next to `requires-python`, and the two drift apart. Poetry's
+  /// caret and tilde constraints are translated to PEP 440 ranges and both
+  /// sides are compared across released Python minor versions.
+  ToolPoetryPythonRule {
+    id: "tool-poetry-python",
+    message: "`tool.poetry.dependencies.python` disagrees with `project.requires-python`",
+    run(context) {
+      let (Some(poetry), Some(requires_python)) = (
+        context.get("tool.poetry.dependencies.python"),
+        context.get("project.requires-python"),
+      ) else {
+        return Vec::new();
+      };
+
+      let (Some(poetry_value), Some(requires_python_value)) =
+        (poetry.as_str(), requires_python.as_str())
+      else {
+        return Vec::new();
+      };
+
+      let (poetry_value, requires_python_value) =
+        (poetry_value.value(), requires_python_value.value());
+
+      let (Some(alternatives), Ok(specifiers)) = (
+        Self::translate(poetry_value),
+        VersionSpecifiers::from_str(requires_python_value),
+      ) else {
+        return Vec::new();
+      };
+
+      let Some((version, allowed_by_poetry)) =
+        Self::candidates().find_map(|version| {
+          let allowed_by_poetry = alternatives
+            .iter()
+            .any(|alternative| alternative.contains(&version));
+
+          (allowed_by_poetry != specifiers.contains(&version))
+            .then_some((version, allowed_by_poetry))
+        })
+      else {
+        return Vec::new();
+      };
+
+      let (allowed, disallowed) = if allowed_by_poetry {
+        ("Poetry", "`requires-python`")
+      } else {
+        ("`requires-python`", "Poetry")
+      };
+
+      vec![
+        Diagnostic::warning(
+          format!(
+            "`tool.poetry.dependencies.python` `{poetry_value}` disagrees with `project.requires-python` `{requires_python_value}`; Python {version} is allowed by {allowed} but not by {disallowed}"
+          ),
+          poetry.span(context.content()),
+        )
+        .related(
+          lsp::Location {
+            range: requires_python.span(context.content()),
+            uri: context.document().uri.clone(),
+          },
+          "`project.requires-python` declared here",
+        ),
+      ]
+    }
+  }
+}
+
+impl ToolPoetryPythonRule {
+  fn candidates() -> impl Iterator<Item = Version> {
+    (0..=7)
+      .map(|minor| Version::new([2, minor]))
+      .chain((0..=30).map(|minor| Version::new([3, minor])))
+      .chain(iter::once(Version::new([4, 0])))
+  }
+
+  fn caret(version: &str) -> Option<String> {
+    let parts = Self::parts(version)?;
+
+    let position = parts
+      .iter()
+      .position(|part| *part != 0)
+      .unwrap_or(parts.len() - 1);
+
+    Some(format!(">={version},<{}", Self::upper(&parts[..=position])))
+  }
+
+  fn parts(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|part| part.parse().ok()).collect()
+  }
+
+  fn tilde(version: &str) -> Option<String> {
+    let parts = Self::parts(version)?;
+
+    let position = usize::from(parts.len() != 1);
+
+    Some(format!(">={version},<{}", Self::upper(&parts[..=position])))
+  }
+
+  /// Translates a Poetry version constraint into one PEP 440 specifier set
+  /// per `||` alternative.
+  pub(crate) fn translate(constraint: &str) -> Option<Vec<VersionSpecifiers>> {
+    constraint
+      .split("||")
+      .map(|alternative| {
+        let mut clauses = Vec::<String>::new();
+
+        for token in alternative.split([',', ' ']).map(str::trim) {
+          match clauses.last_mut() {
+            Some(last)
+              if !token.is_empty()
+                && last
+                  .chars()
+                  .all(|character| "<>=!~^".contains(character)) =>
+            {
+              last.push_str(token);
+            }
+            _ if !token.is_empty() && token != "*" => {
+              clauses.push(token.to_string());
+            }
+            _ => {}
+          }
+        }
+
+        let clauses = clauses
+          .iter()
+          .map(|clause| {
+            if let Some(version) = clause.strip_prefix('^') {
+              Self::caret(version.trim())
+            } else if clause.starts_with("~=") {
+              Some(clause.clone())
+            } else if let Some(version) = clause.strip_prefix('~') {
+              Self::tilde(version.trim())
+            } else if clause
+              .starts_with(|character: char| character.is_ascii_digit())
+            {
+              Some(format!("=={clause}"))
+            } else {
+              Some(clause.clone())
+            }
+          })
+          .collect::<Option<Vec<_>>>()?;
+
+        VersionSpecifiers::from_str(&clauses.join(",")).ok()
+      })
+      .collect()
+  }
+
+  fn upper(parts: &[u64]) -> String {
+    let mut parts = parts.to_vec();
+
+    if let Some(last) = parts.last_mut() {
+      *last += 1;
+    }
+
+    parts
+      .iter()
+      .map(u64::to_string)
+      .collect::<Vec<_>>()
+      .join(".")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn translate() {
+    #[track_caller]
+    fn case(constraint: &str, expected: &[&str]) {
+      assert_eq!(
+        ToolPoetryPythonRule::translate(constraint)
+          .unwrap()
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>(),
+        expected
+      );
+    }
+
+    case("^3.8", &[">=3.8, <4"]);
+    case("^0.2.3", &[">=0.2.3, <0.3"]);
+    case("~3.9", &[">=3.9, <3.10"]);
+    case("~3", &[">=3, <4"]);
+    case(">=3.8,<3.13", &[">=3.8, <3.13"]);
+    case(">=3.8 <3.13", &[">=3.8, <3.13"]);
+    case(">= 3.8, < 3.13", &[">=3.8, <3.13"]);
+    case("~2.7 || ^3.6", &[">=2.7, <2.8", ">=3.6, <4"]);
+    case("3.11.*", &["==3.11.*"]);
+    case("*", &[""]);
+  }
+}
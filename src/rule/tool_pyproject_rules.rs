@@ -0,0 +1,99 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `tool.pyproject.rules` configures a rule that does not exist,
+  /// or gives a rule table a setting other than `level` and `options`.
+  ///
+  /// A misspelled rule id silently has no effect, so unknown keys are reported
+  /// along with the closest known rule id when one is similar enough. Unknown
+  /// settings are ignored when loading the configuration, so they are
+  /// reported the same way.
+  ToolPyprojectRulesRule {
+    id: "tool-pyproject-rules",
+    message: "`tool.pyproject.rules` references an unknown rule",
+    run(context) {
+      let Some(rules) = context.get("tool.pyproject.rules") else {
+        return Vec::new();
+      };
+
+      let Some(table) = rules.as_table() else {
+        return Vec::new();
+      };
+
+      let ids = inventory::iter::<&dyn Rule>
+        .into_iter()
+        .map(|rule| rule.id())
+        .collect::<Vec<_>>();
+
+      let mut diagnostics = Vec::new();
+
+      for (key, value) in table.entries().read().iter() {
+        let name = key.value();
+
+        if ids.contains(&name) {
+          diagnostics.extend(Self::settings(context, name, value));
+          continue;
+        }
+
+        let suggestion = Self::closest(name, &ids)
+          .map(|id| format!(" (did you mean `{id}`?)"))
+          .unwrap_or_default();
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`tool.pyproject.rules` references unknown rule `{name}`{suggestion}"
+          ),
+          key.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ToolPyprojectRulesRule {
+  const MAX_DISTANCE: usize = 3;
+
+  const SETTINGS: [&'static str; 2] = ["level", "options"];
+
+  fn closest<'a>(name: &str, ids: &[&'a str]) -> Option<&'a str> {
+    ids
+      .iter()
+      .map(|id| (edit_distance(name, id), *id))
+      .filter(|(distance, _)| *distance <= Self::MAX_DISTANCE)
+      .min()
+      .map(|(_, id)| id)
+  }
+
+  fn settings(
+    context: &RuleContext<'_>,
+    rule: &str,
+    value: &Node,
+  ) -> Vec<Diagnostic> {
+    let Some(table) = value.as_table() else {
+      return Vec::new();
+    };
+
+    table
+      .entries()
+      .read()
+      .iter()
+      .filter(|(key, _)| !Self::SETTINGS.contains(&key.value()))
+      .map(|(key, _)| {
+        let name = key.value();
+
+        let suggestion = Self::closest(name, &Self::SETTINGS)
+          .map(|setting| format!(" (did you mean `{setting}`?)"))
+          .unwrap_or_default();
+
+        Diagnostic::warning(
+          format!(
+            "`tool.pyproject.rules.{rule}` has unknown setting `{name}`{suggestion}"
+          ),
+          key.span(context.content()),
+        )
+      })
+      .collect()
+  }
+}
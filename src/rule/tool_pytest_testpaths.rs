@@ -0,0 +1,62 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `tool.pytest.ini_options.testpaths` lists missing directories.
+  ///
+  /// pytest silently collects nothing from a `testpaths` entry that does not
+  /// exist, which usually surfaces as a confusing "no tests ran". Entries
+  /// containing glob characters are skipped, as are documents without a
+  /// resolvable root on disk.
+  ToolPytestTestpathsRule {
+    id: "tool-pytest-testpaths",
+    message: "`tool.pytest.ini_options.testpaths` references a missing directory",
+    run(context) {
+      if context.get("tool.pytest").is_none() {
+        return Vec::new();
+      }
+
+      let Some(testpaths) = context.get("tool.pytest.ini_options.testpaths")
+      else {
+        return Vec::new();
+      };
+
+      let document = context.document();
+
+      let Some(root) = document.root() else {
+        return Vec::new();
+      };
+
+      let items = match &testpaths {
+        Node::Array(array) => array.items().read().iter().cloned().collect(),
+        Node::Str(_) => vec![testpaths.clone()],
+        _ => return Vec::new(),
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for item in items {
+        let Some(path) = item.as_str().map(|string| string.value().to_string())
+        else {
+          continue;
+        };
+
+        if path.trim().is_empty() || path.contains(['*', '?', '[']) {
+          continue;
+        }
+
+        if root.join(&path).is_dir() {
+          continue;
+        }
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`tool.pytest.ini_options.testpaths` entry `{path}` does not exist; pytest will not collect any tests from it"
+          ),
+          item.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+define_rule! {
+  /// Warns when `tool.setuptools` carries package metadata.
+  ///
+  /// Projects migrating from `setup.py` or `setup.cfg` often carry keys such
+  /// as `install_requires` or `project_urls` over to `[tool.setuptools]`,
+  /// where setuptools ignores them. Each such key is reported along with the
+  /// `[project]` field it belongs in.
+  ToolSetuptoolsMetadataRule {
+    id: "tool-setuptools-metadata",
+    message: "`tool.setuptools` contains package metadata",
+    run(context) {
+      let Some(setuptools) = context
+        .get("tool.setuptools")
+        .and_then(|node| node.as_table().cloned())
+      else {
+        return Vec::new();
+      };
+
+      let mut diagnostics = Vec::new();
+
+      for (key, _) in setuptools.entries().read().iter() {
+        let normalized = key.value().replace('-', "_");
+
+        let Some((_, field)) = Self::FIELDS
+          .iter()
+          .find(|(legacy, _)| *legacy == normalized)
+        else {
+          continue;
+        };
+
+        diagnostics.push(Diagnostic::warning(
+          format!(
+            "`tool.setuptools.{}` is package metadata, which setuptools reads from `[project]`; move it to `project.{field}`",
+            key.value()
+          ),
+          key.span(context.content()),
+        ));
+      }
+
+      diagnostics
+    }
+  }
+}
+
+impl ToolSetuptoolsMetadataRule {
+  const FIELDS: [(&'static str, &'static str); 20] = [
+    ("author", "authors"),
+    ("author_email", "authors"),
+    ("classifiers", "classifiers"),
+    ("description", "description"),
+    ("entry_points", "entry-points"),
+    ("extras_require", "optional-dependencies"),
+    ("home_page", "urls"),
+    ("install_requires", "dependencies"),
+    ("keywords", "keywords"),
+    ("license", "license"),
+    ("long_description", "readme"),
+    ("long_description_content_type", "readme"),
+    ("maintainer", "maintainers"),
+    ("maintainer_email", "maintainers"),
+    ("name", "name"),
+    ("project_urls", "urls"),
+    ("python_requires", "requires-python"),
+    ("summary", "description"),
+    ("url", "urls"),
+    ("version", "version"),
+  ];
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+define_rule! {
+  /// Warns when a setuptools project uses a `src/` layout its configuration
+  /// doesn't account for.
+  ///
+  /// Explicit `tool.setuptools.packages` lists and `packages.find` tables
+  /// resolve against the project root unless `package-dir` or `where` says
+  /// otherwise, so packages that only exist under `src/` are silently left
+  /// out of the build. Projects relying on auto-discovery, or with packages
+  /// in both places, are skipped.
+  ToolSetuptoolsSrcLayoutRule {
+    id: "tool-setuptools-src-layout",
+    message: "`tool.setuptools` package discovery misses the `src/` layout",
+    run(context) {
+      let is_setuptools = context
+        .get("build-system.build-backend")
+        .and_then(|backend| {
+          backend
+            .as_str()
+            .map(|string| string.value().starts_with("setuptools.build_meta"))
+        })
+        .unwrap_or(false);
+
+      if !is_setuptools || context.get("tool.setuptools.package-dir").is_some()
+      {
+        return Vec::new();
+      }
+
+      let Some(packages) = context.get("tool.setuptools.packages") else {
+        return Vec::new();
+      };
+
+      let Some(root) = context.document().root() else {
+        return Vec::new();
+      };
+
+      let src = root.join("src");
+
+      if !src.is_dir() {
+        return Vec::new();
+      }
+
+      match &packages {
+        Node::Array(array) => array
+          .items()
+          .read()
+          .iter()
+          .filter_map(|item| {
+            let name = item.as_str()?.value();
+
+            let top_level = name.split('.').next()?;
+
+            (src.join(top_level).is_dir() && !root.join(top_level).is_dir())
+              .then(|| {
+                Diagnostic::warning(
+                  format!(
+                    "`tool.setuptools.packages` entry `{name}` only exists under `src/`; set `tool.setuptools.package-dir = {{\"\" = \"src\"}}` or remove `packages` to use auto-discovery"
+                  ),
+                  item.span(context.content()),
+                )
+              })
+          })
+          .collect(),
+        Node::Table(_) => {
+          let Some(find) = context.get("tool.setuptools.packages.find") else {
+            return Vec::new();
+          };
+
+          if context.get("tool.setuptools.packages.find.where").is_some()
+            || !Self::contains_package(&src)
+            || Self::contains_package(&root)
+          {
+            return Vec::new();
+          }
+
+          vec![Diagnostic::warning(
+            "`tool.setuptools.packages.find` searches the project root, but packages live under `src/`; set `where = [\"src\"]`",
+            find.span(context.content()),
+          )]
+        }
+        _ => Vec::new(),
+      }
+    }
+  }
+}
+
+impl ToolSetuptoolsSrcLayoutRule {
+  fn contains_package(directory: &Path) -> bool {
+    fs::read_dir(directory).is_ok_and(|entries| {
+      entries
+        .filter_map(Result::ok)
+        .any(|entry| entry.path().join("__init__.py").is_file())
+    })
+  }
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+define_rule! {
+  /// Reports `# pyproject: ignore` comments that suppress nothing.
+  ///
+  /// Unused suppressions are found by the analyzer once every other rule has
+  /// run, since they depend on the diagnostics those rules produce, so this
+  /// rule only exists to be configured and selected like any other.
+  UnusedIgnoreRule {
+    id: "unused-ignore",
+    message: "unused suppression comment",
+    run(_context) {
+      Vec::new()
+    }
+  }
+}
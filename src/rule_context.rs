@@ -2,9 +2,15 @@ use super::*;
 
 pub struct RuleContext<'a> {
   document: &'a Document,
+  offline: bool,
 }
 
 impl<'a> RuleContext<'a> {
+  #[must_use]
+  pub fn config(&self) -> &Config {
+    &self.document.config
+  }
+
   #[must_use]
   pub fn content(&self) -> &Rope {
     &self.document.content
@@ -40,13 +46,35 @@ impl<'a> RuleContext<'a> {
 
   #[must_use]
   pub fn new(document: &'a Document) -> Self {
-    Self { document }
+    Self {
+      document,
+      offline: false,
+    }
+  }
+
+  /// Disables network access for rules, so rules that query package indexes
+  /// rely on cached results only and rules that fetch URLs report nothing.
+  #[must_use]
+  pub fn offline(self, offline: bool) -> Self {
+    Self { offline, ..self }
+  }
+
+  pub(crate) fn pypi(&self) -> &'static PyPiClient {
+    if self.offline {
+      PyPiClient::shared_offline()
+    } else {
+      PyPiClient::shared()
+    }
   }
 
   #[must_use]
   pub fn tree(&self) -> &Parse {
     &self.document.tree
   }
+
+  pub(crate) fn urls(&self) -> Option<&'static UrlClient> {
+    (!self.offline).then(UrlClient::shared)
+  }
 }
 
 #[cfg(test)]
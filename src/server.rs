@@ -24,8 +24,25 @@ impl Server {
       code_action_provider: Some(lsp::CodeActionProviderCapability::Simple(
         true,
       )),
+      definition_provider: Some(lsp::OneOf::Left(true)),
+      diagnostic_provider: Some(lsp::DiagnosticServerCapabilities::Options(
+        lsp::DiagnosticOptions {
+          identifier: Some(env!("CARGO_PKG_NAME").to_string()),
+          inter_file_dependencies: false,
+          workspace_diagnostics: false,
+          work_done_progress_options: lsp::WorkDoneProgressOptions::default(),
+        },
+      )),
       hover_provider: Some(lsp::HoverProviderCapability::Simple(true)),
+      inlay_hint_provider: Some(lsp::OneOf::Left(true)),
+      rename_provider: Some(lsp::OneOf::Right(lsp::RenameOptions {
+        prepare_provider: Some(true),
+        work_done_progress_options: lsp::WorkDoneProgressOptions::default(),
+      })),
+      document_highlight_provider: Some(lsp::OneOf::Left(true)),
       document_formatting_provider: Some(lsp::OneOf::Left(true)),
+      document_symbol_provider: Some(lsp::OneOf::Left(true)),
+      workspace_symbol_provider: Some(lsp::OneOf::Left(true)),
       text_document_sync: Some(lsp::TextDocumentSyncCapability::Options(
         lsp::TextDocumentSyncOptions {
           open_close: Some(true),
@@ -76,6 +93,13 @@ impl LanguageServer for Server {
     self.0.completion(params).await
   }
 
+  async fn diagnostic(
+    &self,
+    params: lsp::DocumentDiagnosticParams,
+  ) -> Result<lsp::DocumentDiagnosticReportResult, jsonrpc::Error> {
+    self.0.diagnostic(params).await
+  }
+
   async fn did_change(&self, params: lsp::DidChangeTextDocumentParams) {
     if let Err(error) = self.0.did_change(params).await {
       self
@@ -86,6 +110,19 @@ impl LanguageServer for Server {
     }
   }
 
+  async fn did_change_watched_files(
+    &self,
+    _: lsp::DidChangeWatchedFilesParams,
+  ) {
+    if let Err(error) = self.0.reload_config().await {
+      self
+        .0
+        .client
+        .log_message(lsp::MessageType::ERROR, error)
+        .await;
+    }
+  }
+
   async fn did_close(&self, params: lsp::DidCloseTextDocumentParams) {
     self.0.did_close(params).await;
   }
@@ -100,6 +137,30 @@ impl LanguageServer for Server {
     }
   }
 
+  async fn did_save(&self, _: lsp::DidSaveTextDocumentParams) {
+    if let Err(error) = self.0.reload_config().await {
+      self
+        .0
+        .client
+        .log_message(lsp::MessageType::ERROR, error)
+        .await;
+    }
+  }
+
+  async fn document_highlight(
+    &self,
+    params: lsp::DocumentHighlightParams,
+  ) -> Result<Option<Vec<lsp::DocumentHighlight>>, jsonrpc::Error> {
+    self.0.document_highlight(params).await
+  }
+
+  async fn document_symbol(
+    &self,
+    params: lsp::DocumentSymbolParams,
+  ) -> Result<Option<lsp::DocumentSymbolResponse>, jsonrpc::Error> {
+    self.0.document_symbol(params).await
+  }
+
   async fn formatting(
     &self,
     params: lsp::DocumentFormattingParams,
@@ -107,6 +168,13 @@ impl LanguageServer for Server {
     self.0.formatting(params).await
   }
 
+  async fn goto_definition(
+    &self,
+    params: lsp::GotoDefinitionParams,
+  ) -> Result<Option<lsp::GotoDefinitionResponse>, jsonrpc::Error> {
+    self.0.goto_definition(params).await
+  }
+
   async fn hover(
     &self,
     params: lsp::HoverParams,
@@ -126,9 +194,38 @@ impl LanguageServer for Server {
     self.0.initialized(params).await;
   }
 
+  async fn inlay_hint(
+    &self,
+    params: lsp::InlayHintParams,
+  ) -> Result<Option<Vec<lsp::InlayHint>>, jsonrpc::Error> {
+    self.0.inlay_hint(params).await
+  }
+
+  async fn prepare_rename(
+    &self,
+    params: lsp::TextDocumentPositionParams,
+  ) -> Result<Option<lsp::PrepareRenameResponse>, jsonrpc::Error> {
+    self.0.prepare_rename(params).await
+  }
+
+  async fn rename(
+    &self,
+    params: lsp::RenameParams,
+  ) -> Result<Option<lsp::WorkspaceEdit>, jsonrpc::Error> {
+    self.0.rename(params).await
+  }
+
   async fn shutdown(&self) -> Result<(), jsonrpc::Error> {
+    self.0.shutdown().await;
     Ok(())
   }
+
+  async fn symbol(
+    &self,
+    params: lsp::WorkspaceSymbolParams,
+  ) -> Result<Option<Vec<lsp::SymbolInformation>>, jsonrpc::Error> {
+    self.0.symbol(params).await
+  }
 }
 
 #[derive(Debug)]
@@ -136,9 +233,25 @@ struct Inner {
   client: Client,
   documents: RwLock<BTreeMap<lsp::Url, Document>>,
   initialized: AtomicBool,
+  watch_config: AtomicBool,
+  workspace_roots: RwLock<Vec<PathBuf>>,
 }
 
 impl Inner {
+  /// Analyzes `document` on the blocking thread pool, since rules may wait on
+  /// the network, keeping the runtime free to serve other requests meanwhile.
+  /// The analysis itself runs to completion; callers discard its result if the
+  /// document changed or was dropped in the meantime.
+  async fn analyze(mut document: Document) -> Result<Document> {
+    Ok(
+      tokio::task::spawn_blocking(move || {
+        document.analyze();
+        document
+      })
+      .await?,
+    )
+  }
+
   async fn code_action(
     &self,
     params: lsp::CodeActionParams,
@@ -158,14 +271,25 @@ impl Inner {
     &self,
     params: lsp::CompletionParams,
   ) -> Result<Option<lsp::CompletionResponse>, jsonrpc::Error> {
-    let uri = params.text_document_position.text_document.uri;
+    let lsp::TextDocumentPositionParams {
+      position,
+      text_document,
+    } = params.text_document_position;
 
     let documents = self.documents.read().await;
 
-    let Some(_) = documents.get(&uri) else {
+    let Some(document) = documents.get(&text_document.uri) else {
       return Ok(None);
     };
 
+    let resolver = Resolver::new(document);
+
+    if let Some(items) = resolver.resolve_completions(position) {
+      return Ok(Some(lsp::CompletionResponse::Array(items)));
+    }
+
+    let present = resolver.resolve_array_items(position);
+
     let mut items = BUILTINS
       .iter()
       .map(|builtin| builtin.completion_item())
@@ -185,26 +309,77 @@ impl Inner {
         }),
     );
 
+    items.extend(resolver.resolve_project_scaffold(position));
+
+    items.retain(|item| !present.contains(&item.label));
+
     Ok(Some(lsp::CompletionResponse::Array(items)))
   }
 
+  async fn diagnostic(
+    &self,
+    params: lsp::DocumentDiagnosticParams,
+  ) -> Result<lsp::DocumentDiagnosticReportResult, jsonrpc::Error> {
+    let documents = self.documents.read().await;
+
+    let items = documents
+      .get(&params.text_document.uri)
+      .map(|document| {
+        document
+          .diagnostics
+          .iter()
+          .map(lsp::Diagnostic::from)
+          .collect::<Vec<lsp::Diagnostic>>()
+      })
+      .unwrap_or_default();
+
+    Ok(lsp::DocumentDiagnosticReportResult::Report(
+      lsp::DocumentDiagnosticReport::Full(
+        lsp::RelatedFullDocumentDiagnosticReport {
+          related_documents: None,
+          full_document_diagnostic_report: lsp::FullDocumentDiagnosticReport {
+            result_id: None,
+            items,
+          },
+        },
+      ),
+    ))
+  }
+
   async fn did_change(
     &self,
     params: lsp::DidChangeTextDocumentParams,
   ) -> Result {
     let uri = params.text_document.uri.clone();
 
-    let mut documents = self.documents.write().await;
+    // The stored document is updated in place and analyzed as a copy, so it
+    // survives a failed analysis and other requests aren't blocked on it.
+    let document = {
+      let mut documents = self.documents.write().await;
+
+      let Some(document) = documents.get_mut(&uri) else {
+        return Ok(());
+      };
+
+      document.apply_change(params);
 
-    let Some(document) = documents.get_mut(&uri) else {
-      return Ok(());
+      document.clone()
     };
 
-    document.apply_change(params);
+    let document = Self::analyze(document).await?;
 
-    document.analyze();
+    {
+      let mut documents = self.documents.write().await;
 
-    drop(documents);
+      if documents
+        .get(&uri)
+        .is_none_or(|current| current.version != document.version)
+      {
+        return Ok(());
+      }
+
+      documents.insert(uri.clone(), document);
+    }
 
     self.publish_diagnostics(&uri).await;
 
@@ -229,15 +404,69 @@ impl Inner {
 
     let mut document = Document::from(params);
 
-    document.analyze();
+    document.apply_workspace_config(&self.workspace_roots.read().await);
+
+    // The document is stored before it is analyzed so that changes arriving
+    // during the analysis are applied to it rather than dropped.
+    self
+      .documents
+      .write()
+      .await
+      .insert(uri.clone(), document.clone());
+
+    let document = Self::analyze(document).await?;
 
-    self.documents.write().await.insert(uri.clone(), document);
+    {
+      let mut documents = self.documents.write().await;
+
+      if documents
+        .get(&uri)
+        .is_none_or(|current| current.version != document.version)
+      {
+        return Ok(());
+      }
+
+      documents.insert(uri.clone(), document);
+    }
 
     self.publish_diagnostics(&uri).await;
 
     Ok(())
   }
 
+  async fn document_highlight(
+    &self,
+    params: lsp::DocumentHighlightParams,
+  ) -> Result<Option<Vec<lsp::DocumentHighlight>>, jsonrpc::Error> {
+    let lsp::TextDocumentPositionParams {
+      position,
+      text_document,
+    } = params.text_document_position_params;
+
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(&text_document.uri) else {
+      return Ok(None);
+    };
+
+    Ok(Resolver::new(document).resolve_document_highlights(position))
+  }
+
+  async fn document_symbol(
+    &self,
+    params: lsp::DocumentSymbolParams,
+  ) -> Result<Option<lsp::DocumentSymbolResponse>, jsonrpc::Error> {
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(&params.text_document.uri) else {
+      return Ok(None);
+    };
+
+    Ok(Some(lsp::DocumentSymbolResponse::Nested(
+      Resolver::new(document).resolve_document_symbols(),
+    )))
+  }
+
   async fn formatting(
     &self,
     params: lsp::DocumentFormattingParams,
@@ -273,6 +502,28 @@ impl Inner {
     Ok(Some(vec![edit]))
   }
 
+  async fn goto_definition(
+    &self,
+    params: lsp::GotoDefinitionParams,
+  ) -> Result<Option<lsp::GotoDefinitionResponse>, jsonrpc::Error> {
+    let lsp::TextDocumentPositionParams {
+      position,
+      text_document,
+    } = params.text_document_position_params;
+
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(&text_document.uri) else {
+      return Ok(None);
+    };
+
+    Ok(
+      Resolver::new(document)
+        .resolve_definition(position)
+        .map(lsp::GotoDefinitionResponse::Scalar),
+    )
+  }
+
   async fn hover(
     &self,
     params: lsp::HoverParams,
@@ -286,22 +537,46 @@ impl Inner {
       ..
     } = params;
 
-    let documents = self.documents.read().await;
-
-    let Some(document) = documents.get(&text_document.uri) else {
-      return Ok(None);
-    };
-
-    Ok(Resolver::new(document).resolve_hover(position))
+    Ok(
+      self
+        .resolve_blocking(&text_document.uri, move |resolver| {
+          resolver.resolve_hover(position)
+        })
+        .await?
+        .flatten(),
+    )
   }
 
-  #[allow(clippy::unused_async)]
   async fn initialize(
     &self,
-    _params: lsp::InitializeParams,
+    params: lsp::InitializeParams,
   ) -> Result<lsp::InitializeResult, jsonrpc::Error> {
     log::info!("Starting pyproject language server...");
 
+    self.watch_config.store(
+      params
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.did_change_watched_files)
+        .and_then(|watched_files| watched_files.dynamic_registration)
+        .unwrap_or_default(),
+      Ordering::Relaxed,
+    );
+
+    #[allow(deprecated)]
+    let uris = match params.workspace_folders {
+      Some(folders) if !folders.is_empty() => {
+        folders.into_iter().map(|folder| folder.uri).collect()
+      }
+      _ => params.root_uri.into_iter().collect::<Vec<_>>(),
+    };
+
+    *self.workspace_roots.write().await = uris
+      .iter()
+      .filter_map(|uri| uri.to_file_path().ok())
+      .collect();
+
     Ok(lsp::InitializeResult {
       capabilities: Server::capabilities(),
       server_info: Some(lsp::ServerInfo {
@@ -321,6 +596,53 @@ impl Inner {
       .await;
 
     self.initialized.store(true, Ordering::Relaxed);
+
+    if !self.watch_config.load(Ordering::Relaxed) {
+      return;
+    }
+
+    let watchers = [
+      "pyproject.toml",
+      "pyproject-lint.toml",
+      ".pyproject-lint.toml",
+    ]
+    .into_iter()
+    .map(|name| lsp::FileSystemWatcher {
+      glob_pattern: lsp::GlobPattern::String(format!("**/{name}")),
+      kind: None,
+    })
+    .collect();
+
+    let registration = lsp::Registration {
+      id: "config-watcher".to_string(),
+      method: "workspace/didChangeWatchedFiles".to_string(),
+      register_options: Some(json!(
+        lsp::DidChangeWatchedFilesRegistrationOptions { watchers }
+      )),
+    };
+
+    if let Err(error) =
+      self.client.register_capability(vec![registration]).await
+    {
+      self
+        .client
+        .log_message(
+          lsp::MessageType::WARNING,
+          format!("failed to watch configuration files: {error}"),
+        )
+        .await;
+    }
+  }
+
+  async fn inlay_hint(
+    &self,
+    params: lsp::InlayHintParams,
+  ) -> Result<Option<Vec<lsp::InlayHint>>, jsonrpc::Error> {
+    self
+      .resolve_blocking(&params.text_document.uri, move |resolver| {
+        resolver.resolve_inlay_hints(params.range)
+      })
+      .await
   }
 
   fn new(client: Client) -> Self {
@@ -328,9 +650,24 @@ impl Inner {
       client,
       documents: RwLock::new(BTreeMap::new()),
       initialized: AtomicBool::new(false),
+      watch_config: AtomicBool::new(false),
+      workspace_roots: RwLock::new(Vec::new()),
     }
   }
 
+  async fn prepare_rename(
+    &self,
+    params: lsp::TextDocumentPositionParams,
+  ) -> Result<Option<lsp::PrepareRenameResponse>, jsonrpc::Error> {
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(&params.text_document.uri) else {
+      return Ok(None);
+    };
+
+    Ok(Resolver::new(document).resolve_prepare_rename(params.position))
+  }
+
   async fn publish_diagnostics(&self, uri: &lsp::Url) {
     if !self.initialized.load(Ordering::Relaxed) {
       return;
@@ -357,6 +694,112 @@ impl Inner {
       .publish_diagnostics(uri.clone(), diagnostics, Some(version))
       .await;
   }
+
+  /// Rereads the configuration files of every open document and analyzes
+  /// them again, since a saved or changed file may be the standalone or
+  /// workspace configuration that another document falls back to.
+  async fn reload_config(&self) -> Result {
+    let documents = self
+      .documents
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<Document>>();
+
+    let workspace_roots = self.workspace_roots.read().await.clone();
+
+    for mut document in documents {
+      document.apply_workspace_config(&workspace_roots);
+
+      let document = Self::analyze(document).await?;
+
+      let uri = document.uri.clone();
+
+      {
+        let mut documents = self.documents.write().await;
+
+        if documents
+          .get(&uri)
+          .is_none_or(|current| current.version != document.version)
+        {
+          continue;
+        }
+
+        documents.insert(uri.clone(), document);
+      }
+
+      self.publish_diagnostics(&uri).await;
+    }
+
+    Ok(())
+  }
+
+  async fn rename(
+    &self,
+    params: lsp::RenameParams,
+  ) -> Result<Option<lsp::WorkspaceEdit>, jsonrpc::Error> {
+    let lsp::TextDocumentPositionParams {
+      position,
+      text_document,
+    } = params.text_document_position;
+
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(&text_document.uri) else {
+      return Ok(None);
+    };
+
+    Resolver::new(document)
+      .resolve_rename(position, &params.new_name)
+      .map_err(jsonrpc::Error::invalid_params)
+  }
+
+  /// Runs `resolve` against a snapshot of the document at `uri` on the
+  /// blocking thread pool, since hovers and inlay hints may wait on the
+  /// package index.
+  async fn resolve_blocking<T: Send + 'static>(
+    &self,
+    uri: &lsp::Url,
+    resolve: impl FnOnce(&Resolver) -> T + Send + 'static,
+  ) -> Result<Option<T>, jsonrpc::Error> {
+    let documents = self.documents.read().await;
+
+    let Some(document) = documents.get(uri).cloned() else {
+      return Ok(None);
+    };
+
+    drop(documents);
+
+    tokio::task::spawn_blocking(move || resolve(&Resolver::new(&document)))
+      .await
+      .map(Some)
+      .map_err(|_| jsonrpc::Error::internal_error())
+  }
+
+  /// Drops all document state, so results of analyses still in flight when
+  /// the client shuts down find no document to update and are discarded.
+  async fn shutdown(&self) {
+    self.documents.write().await.clear();
+
+    self.workspace_roots.write().await.clear();
+  }
+
+  async fn symbol(
+    &self,
+    params: lsp::WorkspaceSymbolParams,
+  ) -> Result<Option<Vec<lsp::SymbolInformation>>, jsonrpc::Error> {
+    let documents = self.documents.read().await;
+
+    Ok(Some(
+      documents
+        .values()
+        .flat_map(|document| {
+          Resolver::new(document).resolve_workspace_symbols(&params.query)
+        })
+        .collect(),
+    ))
+  }
 }
 
 #[cfg(test)]
@@ -683,6 +1126,343 @@ mod tests {
       .await
   }
 
+  #[tokio::test]
+  async fn shutdown_rejects_further_requests() -> Result {
+    let uri = "file:///pyproject.toml";
+
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri,
+        text: "[project]\nname = \"demo\"\n",
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "shutdown",
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": null
+      }))
+      .request(HoverRequest {
+        id: 3,
+        uri,
+        line: 1,
+        character: 1,
+      })
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "error": {
+          "code": -32600,
+          "message": "Invalid request"
+        }
+      }))
+      .run()
+      .await
+  }
+
+  #[tokio::test]
+  async fn code_action_references_originating_diagnostic() -> Result {
+    let uri = "file:///pyproject.toml";
+
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri,
+        text: indoc! {
+          r#"[project]
+          name = "My_Package"
+          version = "1.0.0"
+
+          [tool.pyproject.rules]
+          project-name-normalization = "warning"
+          "#
+        },
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "textDocument/codeAction",
+        "params": {
+          "textDocument": { "uri": uri },
+          "range": {
+            "start": { "line": 1, "character": 8 },
+            "end": { "line": 1, "character": 18 }
+          },
+          "context": {
+            "diagnostics": [{
+              "code": "project-name-normalization",
+              "message": "`project.name` is not normalized (use `my-package`)",
+              "range": {
+                "start": { "line": 1, "character": 7 },
+                "end": { "line": 1, "character": 19 }
+              },
+              "severity": 2,
+              "source": "pyproject"
+            }]
+          }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": [{
+          "title": "Replace `My_Package` with `my-package`",
+          "kind": "quickfix",
+          "diagnostics": [{
+              "code": "project-name-normalization",
+              "message": "`project.name` is not normalized (use `my-package`)",
+              "range": {
+                "start": { "line": 1, "character": 7 },
+                "end": { "line": 1, "character": 19 }
+              },
+              "severity": 2,
+              "source": "pyproject"
+            }],
+          "edit": {
+            "changes": {
+              uri: [{
+                "range": {
+                  "start": { "line": 1, "character": 8 },
+                  "end": { "line": 1, "character": 18 }
+                },
+                "newText": "my-package"
+              }]
+            }
+          }
+        }]
+      }))
+      .run()
+      .await
+  }
+
+  #[tokio::test]
+  async fn diagnostic_returns_full_report() -> Result {
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri: "file:///pyproject.toml",
+        text: "[project]\nname = \"a\"\nversion = 1\n",
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "textDocument/diagnostic",
+        "params": {
+          "textDocument": { "uri": "file:///pyproject.toml" }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": {
+          "kind": "full",
+          "items": [
+            {
+              "code": "project-version",
+              "message": "`project.version` must be a string",
+              "range": {
+                "start": { "line": 2, "character": 10 },
+                "end": { "line": 2, "character": 11 }
+              },
+              "severity": 1,
+              "source": "pyproject"
+            }
+          ]
+        }
+      }))
+      .run()
+      .await
+  }
+
+  #[tokio::test]
+  async fn document_symbol_returns_nested_outline() -> Result {
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri: "file:///pyproject.toml",
+        text: "[project]\nname = \"a\"\nversion = \"1.0.0\"\n",
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "textDocument/documentSymbol",
+        "params": {
+          "textDocument": { "uri": "file:///pyproject.toml" }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": [
+          {
+            "name": "project",
+            "kind": 19,
+            "range": {
+              "start": { "line": 0, "character": 0 },
+              "end": { "line": 2, "character": 17 }
+            },
+            "selectionRange": {
+              "start": { "line": 0, "character": 1 },
+              "end": { "line": 0, "character": 8 }
+            },
+            "children": [
+              {
+                "name": "name",
+                "kind": 8,
+                "range": {
+                  "start": { "line": 1, "character": 0 },
+                  "end": { "line": 1, "character": 10 }
+                },
+                "selectionRange": {
+                  "start": { "line": 1, "character": 0 },
+                  "end": { "line": 1, "character": 4 }
+                }
+              },
+              {
+                "name": "version",
+                "kind": 8,
+                "range": {
+                  "start": { "line": 2, "character": 0 },
+                  "end": { "line": 2, "character": 17 }
+                },
+                "selectionRange": {
+                  "start": { "line": 2, "character": 0 },
+                  "end": { "line": 2, "character": 7 }
+                }
+              }
+            ]
+          }
+        ]
+      }))
+      .run()
+      .await
+  }
+
+  #[tokio::test]
+  async fn did_change_counts_bom_on_first_line() -> Result {
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri: "file:///pyproject.toml",
+        text: "\u{feff}[project]\nname = \"a\"\n",
+      })
+      .notification(json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/didChange",
+        "params": {
+          "textDocument": {
+            "uri": "file:///pyproject.toml",
+            "version": 2
+          },
+          "contentChanges": [{
+            "range": {
+              "start": { "line": 0, "character": 2 },
+              "end": { "line": 0, "character": 9 }
+            },
+            "text": "tool"
+          }]
+        }
+      }))
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "textDocument/documentSymbol",
+        "params": {
+          "textDocument": { "uri": "file:///pyproject.toml" }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": [
+          {
+            "name": "tool",
+            "kind": 19,
+            "range": {
+              "start": { "line": 0, "character": 1 },
+              "end": { "line": 1, "character": 10 }
+            },
+            "selectionRange": {
+              "start": { "line": 0, "character": 2 },
+              "end": { "line": 0, "character": 6 }
+            },
+            "children": [
+              {
+                "name": "name",
+                "kind": 8,
+                "range": {
+                  "start": { "line": 1, "character": 0 },
+                  "end": { "line": 1, "character": 10 }
+                },
+                "selectionRange": {
+                  "start": { "line": 1, "character": 0 },
+                  "end": { "line": 1, "character": 4 }
+                }
+              }
+            ]
+          }
+        ]
+      }))
+      .run()
+      .await
+  }
+
+  #[tokio::test]
+  async fn goto_definition_jumps_to_included_group() -> Result {
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri: "file:///pyproject.toml",
+        text: "[dependency-groups]\nLint = [\"ruff\"]\ndev = [{ include-group = \"lint\" }]\n",
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "textDocument/definition",
+        "params": {
+          "textDocument": { "uri": "file:///pyproject.toml" },
+          "position": { "line": 2, "character": 28 }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": {
+          "uri": "file:///pyproject.toml",
+          "range": {
+            "start": { "line": 1, "character": 0 },
+            "end": { "line": 1, "character": 4 }
+          }
+        }
+      }))
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "textDocument/definition",
+        "params": {
+          "textDocument": { "uri": "file:///pyproject.toml" },
+          "position": { "line": 1, "character": 1 }
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "result": null
+      }))
+      .run()
+      .await
+  }
+
   #[tokio::test]
   async fn hover_returns_schema_description() -> Result {
     let uri = "file:///pyproject.toml";
@@ -716,4 +1496,59 @@ mod tests {
       .run()
       .await
   }
+
+  #[tokio::test]
+  async fn workspace_symbol_searches_open_documents() -> Result {
+    Test::new()?
+      .request(InitializeRequest { id: 1 })
+      .response(InitializeResponse { id: 1 })
+      .notification(DidOpenNotification {
+        uri: "file:///a/pyproject.toml",
+        text: "[project]\nname = \"a\"\n",
+      })
+      .notification(DidOpenNotification {
+        uri: "file:///b/pyproject.toml",
+        text: "[project]\nversion = \"1.0.0\"\nname = \"b\"\n",
+      })
+      .request(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "workspace/symbol",
+        "params": {
+          "query": "project.name"
+        }
+      }))
+      .response(json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "result": [
+          {
+            "name": "project.name",
+            "kind": 7,
+            "location": {
+              "uri": "file:///a/pyproject.toml",
+              "range": {
+                "start": { "line": 1, "character": 0 },
+                "end": { "line": 1, "character": 4 }
+              }
+            },
+            "containerName": "project"
+          },
+          {
+            "name": "project.name",
+            "kind": 7,
+            "location": {
+              "uri": "file:///b/pyproject.toml",
+              "range": {
+                "start": { "line": 2, "character": 0 },
+                "end": { "line": 2, "character": 4 }
+              }
+            },
+            "containerName": "project"
+          }
+        ]
+      }))
+      .run()
+      .await
+  }
 }
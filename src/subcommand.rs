@@ -1,7 +1,9 @@
-use {super::*, check::Check, format::Format};
+use {super::*, check::Check, fix::Fix, format::Format, migrate::Migrate};
 
 mod check;
+mod fix;
 mod format;
+mod migrate;
 mod server;
 
 #[derive(Debug, Parser)]
@@ -11,13 +13,52 @@ pub(crate) enum Subcommand {
     visible_alias = "lint"
   )]
   Check(Check),
+  #[command(about = "Apply automatic fixes to a pyproject.toml file")]
+  Fix(Fix),
   #[command(about = "Format a pyproject.toml file", visible_alias = "fmt")]
   Format(Format),
+  #[command(
+    about = "Convert `[tool.poetry]` metadata into a `[project]` table"
+  )]
+  Migrate(Migrate),
   #[command(about = "Start the language server", visible_alias = "lsp")]
   Server,
 }
 
 impl Subcommand {
+  /// Renders a unified diff from `original` to `modified`, colored when
+  /// `color` is set.
+  fn diff(
+    original: &str,
+    modified: &str,
+    old_header: &str,
+    new_header: &str,
+    color: bool,
+  ) -> String {
+    TextDiff::from_lines(original, modified)
+      .unified_diff()
+      .context_radius(3)
+      .header(old_header, new_header)
+      .to_string()
+      .lines()
+      .map(|line| {
+        if color {
+          match line.chars().next() {
+            Some('+') => line.green().to_string(),
+            Some('-') => line.red().to_string(),
+            Some('@') => line.blue().to_string(),
+            Some(' ') => line.dimmed().to_string(),
+            Some('\\') => line.yellow().to_string(),
+            _ => line.to_string(),
+          }
+        } else {
+          line.to_string()
+        }
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
   fn find_pyproject_toml() -> Result<PathBuf> {
     let mut current_dir = env::current_dir()?;
 
@@ -36,10 +77,19 @@ impl Subcommand {
     }
   }
 
-  pub(crate) async fn run(self) -> Result {
+  fn read_pyproject_toml(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+
+    String::from_utf8(bytes)
+      .map_err(|_| anyhow!("`{}` is not valid UTF-8", path.display()))
+  }
+
+  pub(crate) async fn run(self, color: bool) -> Result {
     match self {
-      Self::Check(check) => check.run(),
-      Self::Format(format) => format.run(),
+      Self::Check(check) => check.run(color),
+      Self::Fix(fix) => fix.run(color),
+      Self::Format(format) => format.run(color),
+      Self::Migrate(migrate) => migrate.run(),
       Self::Server => server::run().await,
     }
   }
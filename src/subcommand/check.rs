@@ -2,22 +2,149 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(crate) struct Check {
+  #[arg(
+    long,
+    value_name = "PATH",
+    conflicts_with = "write_baseline",
+    help = "Suppress diagnostics recorded in a baseline file",
+    value_hint = clap::ValueHint::FilePath
+  )]
+  baseline: Option<PathBuf>,
+  #[arg(
+    long,
+    value_name = "RULE",
+    value_delimiter = ',',
+    help = "Drop diagnostics from these rules, applied after `--select`"
+  )]
+  ignore: Vec<String>,
+  #[arg(
+    long,
+    short = 'j',
+    value_name = "N",
+    help = "Number of threads to analyze with [default: number of logical CPUs]"
+  )]
+  jobs: Option<NonZeroUsize>,
+  #[arg(
+    long,
+    value_name = "N",
+    help = "Exit with a non-zero status when more than N warnings are reported"
+  )]
+  max_warnings: Option<usize>,
+  #[arg(
+    long,
+    help = "Skip network requests, using only cached PyPI results, as with `PYPROJECT_OFFLINE=1`"
+  )]
+  offline: bool,
+  #[arg(
+    long,
+    visible_alias = "format",
+    value_enum,
+    default_value_t,
+    help = "Report diagnostics as annotated snippets, one `path:line:column:` line each with `grep`, a JSON array with `json`, or a SARIF log with `sarif`"
+  )]
+  output_format: OutputFormat,
+  #[arg(
+    long,
+    value_name = "PATH",
+    help = "Merge a pyproject.toml fragment over the checked file before analyzing",
+    value_hint = clap::ValueHint::FilePath
+  )]
+  overlay: Option<PathBuf>,
   #[arg(
     value_name = "PATH",
     help = "Path to the pyproject.toml file to check",
     value_hint = clap::ValueHint::FilePath
   )]
   path: Option<PathBuf>,
+  #[arg(long, help = "Report how long each rule took to run, slowest first")]
+  profile: bool,
+  #[arg(
+    long,
+    short = 'q',
+    visible_alias = "silent",
+    conflicts_with = "verbose",
+    help = "Only report errors, suppressing warnings, information, and hints"
+  )]
+  quiet: bool,
+  #[arg(
+    long,
+    value_name = "RULE",
+    value_delimiter = ',',
+    help = "Only report diagnostics from these rules"
+  )]
+  select: Vec<String>,
+  #[arg(
+    long,
+    short = 'v',
+    help = "Also report information and hints, along with how long each file took to check"
+  )]
+  verbose: bool,
+  #[arg(
+    long,
+    conflicts_with = "max_warnings",
+    help = "Exit with a non-zero status when any warnings are reported"
+  )]
+  warnings_as_errors: bool,
+  #[arg(
+    long,
+    value_name = "PATH",
+    help = "Record current diagnostics to a baseline file and exit successfully",
+    value_hint = clap::ValueHint::FilePath
+  )]
+  write_baseline: Option<PathBuf>,
 }
 
 impl Check {
-  pub(crate) fn run(self) -> Result<()> {
+  /// Returns the 1-based line and column of the character at `offset`.
+  fn line_column(source: &str, offset: usize) -> (usize, usize) {
+    source
+      .chars()
+      .take(offset)
+      .fold((1, 1), |(line, column), character| {
+        if character == '\n' {
+          (line + 1, 1)
+        } else {
+          (line, column + 1)
+        }
+      })
+  }
+
+  pub(crate) fn run(self, color: bool) -> Result<()> {
+    self.validate_rules()?;
+
     let path = match self.path {
       Some(path) => path,
       None => Subcommand::find_pyproject_toml()?,
     };
 
-    let content = fs::read_to_string(&path)?;
+    let content = Subcommand::read_pyproject_toml(&path)?;
+
+    let overlay = self
+      .overlay
+      .map(|overlay_path| {
+        let (bom, base) = content
+          .strip_prefix('\u{feff}')
+          .map_or(("", content.as_str()), |base| ("\u{feff}", base));
+
+        let overlay = Overlay::merge(
+          base,
+          &Subcommand::read_pyproject_toml(&overlay_path)?,
+        )
+        .map_err(|error| {
+          anyhow!(
+            "failed to parse overlay `{}`: {error}",
+            overlay_path.display()
+          )
+        })?;
+
+        Ok::<_, Error>((bom, overlay_path, overlay))
+      })
+      .transpose()?;
+
+    let content = match &overlay {
+      Some((bom, _, overlay)) => format!("{bom}{}", overlay.content()),
+      None => content,
+    };
 
     let absolute_path = if path.is_absolute() {
       path.clone()
@@ -32,17 +159,102 @@ impl Check {
     let document = Document::from(lsp::DidOpenTextDocumentParams {
       text_document: lsp::TextDocumentItem {
         language_id: "toml".to_string(),
-        text: content.clone(),
+        text: content,
         uri,
         version: 1,
       },
     });
 
-    let analyzer = Analyzer::new(&document);
+    let start = Instant::now();
+
+    let analyzer = Analyzer::new(&document).offline(self.offline);
+
+    let jobs = self
+      .jobs
+      .or_else(|| thread::available_parallelism().ok())
+      .map_or(1, NonZeroUsize::get);
+
+    let pool = ThreadPoolBuilder::new()
+      .num_threads(jobs)
+      .build()
+      .map_err(|error| anyhow!("failed to build thread pool: {error}"))?;
+
+    let (mut diagnostics, timings) = pool.install(|| analyzer.profile());
+
+    diagnostics.retain(|diagnostic| {
+      let id = diagnostic.id.trim();
+
+      (self.select.is_empty() || self.select.iter().any(|rule| rule == id))
+        && !self.ignore.iter().any(|rule| rule == id)
+    });
+
+    if self.verbose {
+      eprintln!(
+        "checked {} in {:.2?} using {} threads",
+        path.display(),
+        start.elapsed(),
+        pool.current_num_threads()
+      );
+    }
+
+    if self.profile {
+      for (id, elapsed) in &timings {
+        eprintln!("{:>10}  {id}", format!("{elapsed:.2?}"));
+      }
+    }
+
+    if let Some(baseline_path) = &self.write_baseline {
+      let mut baseline = Baseline::default();
+
+      baseline.record(
+        &Baseline::source(baseline_path, &path)?,
+        &document,
+        &diagnostics,
+      );
+
+      return baseline.save(baseline_path);
+    }
+
+    if let Some(baseline_path) = &self.baseline {
+      Baseline::load(baseline_path)?.filter(
+        &Baseline::source(baseline_path, &path)?,
+        &document,
+        &mut diagnostics,
+      );
+    }
+
+    let warnings = diagnostics
+      .iter()
+      .filter(|diagnostic| {
+        diagnostic.severity == lsp::DiagnosticSeverity::WARNING
+      })
+      .count();
 
-    let mut diagnostics = analyzer.analyze();
+    let warning_limit = if self.warnings_as_errors {
+      Some(0)
+    } else {
+      self.max_warnings
+    };
 
-    if diagnostics.is_empty() {
+    let too_many_warnings = warning_limit.is_some_and(|limit| warnings > limit);
+
+    if self.quiet {
+      diagnostics.retain(|diagnostic| {
+        diagnostic.severity == lsp::DiagnosticSeverity::ERROR
+      });
+    } else if !self.verbose {
+      diagnostics.retain(|diagnostic| {
+        matches!(
+          diagnostic.severity,
+          lsp::DiagnosticSeverity::ERROR | lsp::DiagnosticSeverity::WARNING
+        )
+      });
+    }
+
+    if diagnostics.is_empty()
+      && !too_many_warnings
+      && matches!(self.output_format, OutputFormat::Grep | OutputFormat::Human)
+    {
       return Ok(());
     }
 
@@ -61,12 +273,31 @@ impl Check {
 
     let source_id = path.to_string_lossy().to_string();
 
-    let mut cache = sources(vec![(source_id.clone(), content.as_str())]);
+    // Reported positions don't count a leading byte order mark, which the
+    // document keeps so that editor positions line up.
+    let bom = usize::from(document.bom);
+
+    let files = match &overlay {
+      Some((_, overlay_path, overlay)) => vec![
+        (source_id.clone(), overlay.source(Origin::Base).to_string()),
+        (
+          overlay_path.to_string_lossy().to_string(),
+          overlay.source(Origin::Overlay).to_string(),
+        ),
+      ],
+      None => {
+        vec![(source_id.clone(), document.content.slice(bom..).to_string())]
+      }
+    };
+
+    let mut cache = sources(files.clone());
 
     let source_len = document.content.len_chars();
 
+    let mut json = Vec::new();
+
     for diagnostic in diagnostics {
-      let (severity_label, color) =
+      let (severity_label, label_color) =
         Self::severity_to_style(diagnostic.severity)?;
 
       let kind_label = format!("{severity_label}[{}]", diagnostic.id.trim());
@@ -81,19 +312,75 @@ impl Check {
         .lsp_position_to_char(diagnostic.range.end)
         .min(source_len);
 
-      let (start, end) = (start.min(end), start.max(end));
+      let (start, end) = (
+        start.min(end).saturating_sub(bom),
+        start.max(end).saturating_sub(bom),
+      );
+
+      let span = match &overlay {
+        Some((_, overlay_path, overlay)) => match overlay.resolve(start..end) {
+          (Origin::Base, range) => (source_id.clone(), range),
+          (Origin::Overlay, range) => {
+            (overlay_path.to_string_lossy().to_string(), range)
+          }
+        },
+        None => (source_id.clone(), start..end),
+      };
+
+      let source = files
+        .iter()
+        .find(|(id, _)| *id == span.0)
+        .map_or("", |(_, source)| source.as_str());
+
+      match self.output_format {
+        OutputFormat::Grep => {
+          let (line, column) = Self::line_column(source, span.1.start);
 
-      let span = (source_id.clone(), start..end);
+          println!(
+            "{}:{line}:{column}: [{severity_label}] [{}] {}",
+            span.0,
+            diagnostic.id.trim(),
+            diagnostic.message.trim()
+          );
+
+          continue;
+        }
+        OutputFormat::Json | OutputFormat::Sarif => {
+          let position = |offset| {
+            let (line, column) = Self::line_column(source, offset);
+
+            lsp::Position::new(
+              u32::try_from(line - 1).unwrap_or(u32::MAX),
+              u32::try_from(column - 1).unwrap_or(u32::MAX),
+            )
+          };
+
+          json.push(JsonDiagnostic {
+            message: diagnostic.message.trim().to_string(),
+            path: span.0.clone(),
+            range: lsp::Range::new(
+              position(span.1.start),
+              position(span.1.end),
+            ),
+            rule: diagnostic.id.trim().to_string(),
+            severity: severity_label.to_string(),
+          });
+
+          continue;
+        }
+        OutputFormat::Human => {}
+      }
 
       let report = Report::build(
-        ReportKind::Custom(kind_label.as_str(), color),
+        ReportKind::Custom(kind_label.as_str(), label_color),
         span.clone(),
       )
+      .with_config(Config::default().with_color(color))
       .with_message(&diagnostic.display)
       .with_label(
         Label::new(span.clone())
           .with_message(diagnostic.message.trim().to_string())
-          .with_color(color),
+          .with_color(label_color),
       );
       let report = report.finish();
 
@@ -102,7 +389,26 @@ impl Check {
         .map_err(|error| anyhow!("failed to render diagnostic: {error}"))?;
     }
 
-    if any_error {
+    match self.output_format {
+      OutputFormat::Json => {
+        println!("{}", serde_json::to_string_pretty(&json)?);
+      }
+      OutputFormat::Sarif => {
+        println!(
+          "{}",
+          serde_json::to_string_pretty(&JsonDiagnostic::sarif(&json))?
+        );
+      }
+      OutputFormat::Grep | OutputFormat::Human => {}
+    }
+
+    if let Some(limit) = warning_limit.filter(|_| too_many_warnings) {
+      eprintln!(
+        "error: {warnings} warning(s) reported, exceeding the limit of {limit}"
+      );
+    }
+
+    if any_error || too_many_warnings {
       process::exit(1);
     }
 
@@ -120,4 +426,21 @@ impl Check {
       _ => bail!("failed to map unknown severity {severity:?}"),
     }
   }
+
+  fn validate_rules(&self) -> Result {
+    let mut ids = inventory::iter::<&dyn Rule>
+      .into_iter()
+      .map(|rule| rule.id())
+      .collect::<Vec<_>>();
+
+    ids.sort_unstable();
+
+    for rule in self.select.iter().chain(&self.ignore) {
+      if !ids.contains(&rule.as_str()) {
+        bail!("unknown rule `{rule}`, valid rules are: {}", ids.join(", "));
+      }
+    }
+
+    Ok(())
+  }
 }
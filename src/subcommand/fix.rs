@@ -0,0 +1,131 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Fix {
+  #[arg(
+    long,
+    help = "Print a diff of the fixes without modifying the file",
+    display_order = 1
+  )]
+  dry_run: bool,
+  #[arg(
+    value_name = "PATH",
+    help = "Path to the pyproject.toml file to fix",
+    value_hint = clap::ValueHint::FilePath,
+    display_order = 0
+  )]
+  path: Option<PathBuf>,
+}
+
+impl Fix {
+  /// Applies the quickfixes attached to `diagnostics` to `document`, skipping
+  /// any quickfix whose edits overlap one that was already accepted, or
+  /// returns `None` if there was nothing to apply.
+  fn apply(document: &Document, diagnostics: &[Diagnostic]) -> Option<String> {
+    let content = &document.content;
+
+    let mut accepted = Vec::<(Range<usize>, &str)>::new();
+
+    for quickfix in diagnostics
+      .iter()
+      .filter_map(|diagnostic| diagnostic.quickfix.as_ref())
+    {
+      let edits = quickfix
+        .edits
+        .iter()
+        .map(|edit| {
+          (
+            content.lsp_position_to_char(edit.range.start)
+              ..content.lsp_position_to_char(edit.range.end),
+            edit.new_text.as_str(),
+          )
+        })
+        .collect::<Vec<_>>();
+
+      let overlaps = edits.iter().any(|(range, _)| {
+        accepted.iter().any(|(other, _)| {
+          range.start < other.end && other.start < range.end
+            || range.start == other.start
+        })
+      });
+
+      if !overlaps {
+        accepted.extend(edits);
+      }
+    }
+
+    if accepted.is_empty() {
+      return None;
+    }
+
+    accepted.sort_by_key(|(range, _)| Reverse(range.start));
+
+    let mut fixed = content.clone();
+
+    for (range, text) in accepted {
+      fixed.apply_edit(&Edit {
+        end_char: range.end,
+        start_char: range.start,
+        text,
+      });
+    }
+
+    Some(fixed.to_string())
+  }
+
+  pub(crate) fn run(self, color: bool) -> Result<()> {
+    let path = match self.path {
+      Some(path) => path,
+      None => Subcommand::find_pyproject_toml()?,
+    };
+
+    let content = Subcommand::read_pyproject_toml(&path)?;
+
+    let absolute_path = if path.is_absolute() {
+      path.clone()
+    } else {
+      env::current_dir()?.join(&path)
+    };
+
+    let uri = lsp::Url::from_file_path(&absolute_path).map_err(|()| {
+      anyhow!("failed to convert `{}` to file url", path.display())
+    })?;
+
+    let document = Document::from(lsp::DidOpenTextDocumentParams {
+      text_document: lsp::TextDocumentItem {
+        language_id: "toml".to_string(),
+        text: content.clone(),
+        uri,
+        version: 1,
+      },
+    });
+
+    let Some(fixed) =
+      Self::apply(&document, &Analyzer::new(&document).analyze())
+        .filter(|fixed| *fixed != content)
+    else {
+      return Ok(());
+    };
+
+    if self.dry_run {
+      let display_path = path.display().to_string();
+
+      println!(
+        "{}",
+        Subcommand::diff(
+          &content,
+          &fixed,
+          &display_path,
+          &format!("{display_path} (fixed)"),
+          color,
+        )
+      );
+
+      return Ok(());
+    }
+
+    fs::write(&path, fixed)?;
+
+    Ok(())
+  }
+}
@@ -27,13 +27,13 @@ pub(crate) struct Format {
 }
 
 impl Format {
-  pub(crate) fn run(self) -> Result<()> {
+  pub(crate) fn run(self, color: bool) -> Result<()> {
     let path = match self.path {
       Some(path) => path,
       None => Subcommand::find_pyproject_toml()?,
     };
 
-    let content = fs::read_to_string(&path)?;
+    let content = Subcommand::read_pyproject_toml(&path)?;
 
     let formatted =
       taplo::formatter::format(&content, taplo::formatter::Options::default());
@@ -42,32 +42,13 @@ impl Format {
       if formatted != content {
         let display_path = path.display().to_string();
 
-        let diff = TextDiff::from_lines(&content, &formatted)
-          .unified_diff()
-          .context_radius(3)
-          .header(&display_path, &format!("{display_path} (formatted)"))
-          .to_string();
-
-        let color = env::var_os("NO_COLOR").is_none();
-
-        let colored_diff = diff
-          .lines()
-          .map(|line| {
-            if color {
-              match line.chars().next() {
-                Some('+') => line.green().to_string(),
-                Some('-') => line.red().to_string(),
-                Some('@') => line.blue().to_string(),
-                Some(' ') => line.dimmed().to_string(),
-                Some('\\') => line.yellow().to_string(),
-                _ => line.to_string(),
-              }
-            } else {
-              line.to_string()
-            }
-          })
-          .collect::<Vec<_>>()
-          .join("\n");
+        let colored_diff = Subcommand::diff(
+          &content,
+          &formatted,
+          &display_path,
+          &format!("{display_path} (formatted)"),
+          color,
+        );
 
         println!("{colored_diff}");
 
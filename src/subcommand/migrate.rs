@@ -0,0 +1,55 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Migrate {
+  #[arg(
+    value_name = "PATH",
+    help = "Path to the pyproject.toml file to migrate",
+    value_hint = clap::ValueHint::FilePath,
+    display_order = 0
+  )]
+  path: Option<PathBuf>,
+  #[arg(
+    long,
+    short = 'w',
+    help = "Write the migrated output back to the file",
+    display_order = 1
+  )]
+  write: bool,
+}
+
+impl Migrate {
+  pub(crate) fn run(self) -> Result<()> {
+    let path = match self.path {
+      Some(path) => path,
+      None => Subcommand::find_pyproject_toml()?,
+    };
+
+    let content = Subcommand::read_pyproject_toml(&path)?;
+
+    let (bom, source) = content
+      .strip_prefix('\u{feff}')
+      .map_or(("", content.as_str()), |source| ("\u{feff}", source));
+
+    let migrated =
+      Migrator::new(&Document::from(source))
+        .migrate()
+        .map_err(|error| {
+          anyhow!("failed to migrate `{}`: {error}", path.display())
+        })?;
+
+    let migrated = format!("{bom}{migrated}");
+
+    if self.write {
+      if migrated != content {
+        fs::write(&path, migrated)?;
+      }
+
+      return Ok(());
+    }
+
+    print!("{migrated}");
+
+    Ok(())
+  }
+}
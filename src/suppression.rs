@@ -0,0 +1,83 @@
+use super::*;
+
+/// A `# pyproject: ignore` comment suppressing diagnostics on its line.
+#[derive(Debug)]
+pub(crate) struct Suppression {
+  /// Rule ids to suppress, or empty to suppress every diagnostic.
+  pub(crate) ids: Vec<String>,
+  /// The range of the comment.
+  pub(crate) range: lsp::Range,
+}
+
+impl Suppression {
+  const PREFIX: &'static str = "pyproject:";
+
+  pub(crate) fn matches(&self, diagnostic: &Diagnostic) -> bool {
+    self.range.start.line == diagnostic.range.start.line
+      && (self.ids.is_empty() || self.ids.contains(&diagnostic.id))
+  }
+
+  fn parse(comment: &str, range: lsp::Range) -> Option<Self> {
+    let rest = comment
+      .strip_prefix('#')?
+      .trim_start()
+      .strip_prefix(Self::PREFIX)?
+      .trim_start();
+
+    let ids = rest.strip_prefix("ignore")?;
+
+    if !(ids.is_empty() || ids.starts_with(char::is_whitespace)) {
+      return None;
+    }
+
+    Some(Self {
+      ids: ids
+        .split([',', ' ', '\t'])
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect(),
+      range,
+    })
+  }
+
+  pub(crate) fn parse_all(document: &Document) -> Vec<Self> {
+    document
+      .tree
+      .clone()
+      .into_syntax()
+      .descendants_with_tokens()
+      .filter_map(SyntaxElement::into_token)
+      .filter(|token| token.kind() == SyntaxKind::COMMENT)
+      .filter_map(|token| {
+        Self::parse(token.text(), token.text_range().span(&document.content))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, pretty_assertions::assert_eq};
+
+  #[test]
+  fn parse() {
+    #[track_caller]
+    fn case(comment: &str, expected: Option<&[&str]>) {
+      assert_eq!(
+        Suppression::parse(comment, lsp::Range::default())
+          .map(|suppression| suppression.ids),
+        expected.map(|ids| ids.iter().map(ToString::to_string).collect())
+      );
+    }
+
+    case("# pyproject: ignore", Some(&[]));
+    case("#pyproject:ignore", Some(&[]));
+    case("# pyproject: ignore project-name", Some(&["project-name"]));
+    case(
+      "# pyproject: ignore project-name, project-version",
+      Some(&["project-name", "project-version"]),
+    );
+    case("# pyproject: ignored", None);
+    case("# noqa", None);
+  }
+}
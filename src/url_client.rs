@@ -0,0 +1,139 @@
+use super::*;
+
+pub(crate) struct UrlClient {
+  cache: Mutex<HashMap<String, Option<String>>>,
+  http: ReqwestClient,
+  offline: bool,
+}
+
+impl UrlClient {
+  /// Returns a description of why `url` is unreachable, or `None` if it
+  /// responded successfully or checks are disabled by `PYPROJECT_OFFLINE`.
+  pub(crate) fn failure(&self, url: &str) -> Option<String> {
+    if self.offline {
+      return None;
+    }
+
+    if let Some(failure) = self
+      .cache
+      .lock()
+      .inspect_err(|error| debug!("failed to lock URL cache: {error}"))
+      .ok()
+      .and_then(|cache| cache.get(url).cloned())
+    {
+      return failure;
+    }
+
+    let failure = self.request(url);
+
+    if let Ok(mut cache) = self.cache.lock() {
+      cache.insert(url.to_string(), failure.clone());
+    } else {
+      debug!("failed to lock URL cache for insert");
+    }
+
+    failure
+  }
+
+  fn new() -> Self {
+    let http = ReqwestClient::builder()
+      .timeout(Duration::from_secs(5))
+      .user_agent(format!(
+        "{}/{}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+      ))
+      .build()
+      .unwrap_or_else(|error| {
+        debug!("failed to configure HTTP client: {error}");
+        ReqwestClient::new()
+      });
+
+    Self {
+      cache: Mutex::new(HashMap::new()),
+      http,
+      offline: env::var_os("PYPROJECT_OFFLINE")
+        .is_some_and(|value| !value.is_empty()),
+    }
+  }
+
+  fn request(&self, url: &str) -> Option<String> {
+    let response = match self.http.head(url).send() {
+      Ok(response)
+        if response.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED =>
+      {
+        self.http.get(url).send()
+      }
+      result => result,
+    };
+
+    match response {
+      Ok(response) if response.status().is_success() => None,
+      Ok(response) if response.status().is_redirection() => None,
+      Ok(response) => Some(format!("returned HTTP {}", response.status())),
+      Err(error) if error.is_timeout() => Some("timed out".to_string()),
+      Err(_) => Some("could not be reached".to_string()),
+    }
+  }
+
+  pub(crate) fn shared() -> &'static Self {
+    static INSTANCE: OnceLock<UrlClient> = OnceLock::new();
+
+    INSTANCE.get_or_init(Self::new)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, mockito::Server};
+
+  #[test]
+  fn failure() {
+    let mut server = Server::new();
+
+    let ok = server.mock("HEAD", "/ok").create();
+
+    let missing = server.mock("HEAD", "/missing").with_status(404).create();
+
+    let head_not_allowed =
+      server.mock("HEAD", "/get").with_status(405).create();
+
+    let get = server.mock("GET", "/get").create();
+
+    let client = UrlClient {
+      cache: Mutex::new(HashMap::new()),
+      http: ReqwestClient::new(),
+      offline: false,
+    };
+
+    assert_eq!(client.failure(&format!("{}/ok", server.url())), None);
+
+    assert_eq!(
+      client.failure(&format!("{}/missing", server.url())),
+      Some("returned HTTP 404 Not Found".to_string())
+    );
+
+    assert_eq!(
+      client.failure(&format!("{}/missing", server.url())),
+      Some("returned HTTP 404 Not Found".to_string())
+    );
+
+    assert_eq!(client.failure(&format!("{}/get", server.url())), None);
+
+    ok.assert();
+    missing.assert();
+    head_not_allowed.assert();
+    get.assert();
+  }
+
+  #[test]
+  fn failure_offline() {
+    let client = UrlClient {
+      cache: Mutex::new(HashMap::new()),
+      http: ReqwestClient::new(),
+      offline: true,
+    };
+
+    assert_eq!(client.failure("http://127.0.0.1:1/"), None);
+  }
+}
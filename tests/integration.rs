@@ -217,6 +217,55 @@ fn check_accepts_absolute_pyproject_path() -> Result {
     .run()
 }
 
+#[test]
+fn check_baseline_suppresses_recorded_diagnostics() -> Result {
+  Test::new()?
+    .file(
+      "baseline.json",
+      indoc! {
+        r#"
+        {
+          "diagnostics": [
+            {
+              "context": "\"Foo!Bar\"",
+              "id": "project-name",
+              "message": "`project.name` must be a valid distribution name",
+              "path": "pyproject.toml"
+            }
+          ]
+        }
+        "#
+      },
+    )
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        description = "A project"
+        name = "Foo!Bar"
+        version = "foo"
+        "#
+      },
+    )
+    .argument("--baseline")
+    .argument("baseline.json")
+    .argument("pyproject.toml")
+    .expected_status(1)
+    .expected_stdout(indoc! {
+      r#"
+      error[project-version]: invalid `project.version` value
+         ╭─[ pyproject.toml:4:11 ]
+         │
+       4 │ version = "foo"
+         │           ──┬──
+         │             ╰──── expected version to start with a number, but no leading ASCII digits were found
+      ───╯
+      "#
+    })
+    .run()
+}
+
 #[test]
 fn check_configured_rule_severities() -> Result {
   #[track_caller]
@@ -232,7 +281,7 @@ fn check_configured_rule_severities() -> Result {
       "#
     };
 
-    let expected_stdout = if level == "off" {
+    let expected_stdout = if matches!(level, "off" | "hint" | "info") {
       String::new()
     } else {
       formatdoc! {
@@ -271,6 +320,22 @@ fn check_errors_when_pyproject_cannot_be_found() -> Result {
     .run()
 }
 
+#[test]
+fn check_errors_on_invalid_utf8() -> Result {
+  let test = Test::new()?;
+
+  fs::write(
+    test.tempdir.path().join("pyproject.toml"),
+    b"name = \"\xff\"\n",
+  )?;
+
+  test
+    .argument("pyproject.toml")
+    .expected_status(1)
+    .expected_stderr("error: `pyproject.toml` is not valid UTF-8\n")
+    .run()
+}
+
 #[test]
 fn check_finds_pyproject_in_parent_directory() -> Result {
   Test::new()?
@@ -288,6 +353,93 @@ fn check_finds_pyproject_in_parent_directory() -> Result {
     .run()
 }
 
+#[test]
+fn check_jobs_sets_analysis_thread_count() -> Result {
+  let test = Test::new()?;
+
+  fs::write(
+    test.tempdir.path().join("pyproject.toml"),
+    indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      "#
+    },
+  )?;
+
+  for jobs in ["1", "3"] {
+    let output = test
+      .command()
+      .args(["--verbose", "--jobs", jobs, "pyproject.toml"])
+      .output()?;
+
+    assert_eq!(output.status.code(), Some(0));
+
+    let stderr = str::from_utf8(&output.stderr)?;
+
+    assert!(
+      stderr
+        .trim_end()
+        .ends_with(&format!(" using {jobs} threads")),
+      "{stderr}"
+    );
+  }
+
+  Ok(())
+}
+
+#[test]
+fn check_jobs_must_be_positive() -> Result {
+  Test::new()?
+    .argument("--jobs")
+    .argument("0")
+    .expected_status(2)
+    .expected_stderr(indoc! {
+      "
+      error: invalid value '0' for '--jobs <N>': number would be zero for non-zero type
+
+      For more information, try '--help'.
+      "
+    })
+    .run()
+}
+
+#[test]
+fn check_max_warnings_fails_when_exceeded() -> Result {
+  #[track_caller]
+  fn case(max: &str, status: i32, stderr: &str) -> Result {
+    Test::new()?
+      .file(
+        "pyproject.toml",
+        indoc! {
+          r#"
+          [project]
+          name = "Foo!Bar"
+          version = "1.0.0"
+
+          [tool.pyproject.rules]
+          project-name = "warning"
+          "#
+        },
+      )
+      .argument("--quiet")
+      .argument("--max-warnings")
+      .argument(max)
+      .argument("pyproject.toml")
+      .expected_status(status)
+      .expected_stderr(stderr)
+      .run()
+  }
+
+  case("1", 0, "")?;
+  case(
+    "0",
+    1,
+    "error: 1 warning(s) reported, exceeding the limit of 0\n",
+  )
+}
+
 #[test]
 fn check_multiple_diagnostics_are_sorted_and_fail() -> Result {
   Test::new()?
@@ -328,7 +480,73 @@ fn check_multiple_diagnostics_are_sorted_and_fail() -> Result {
 }
 
 #[test]
-fn check_reports_errors_and_fails() -> Result {
+fn check_offline_skips_network_requests() -> Result {
+  let mut server = mockito::Server::new();
+
+  let mock = server.mock("GET", mockito::Matcher::Any).expect(0).create();
+
+  let test = Test::new()?.argument("--offline");
+
+  fs::write(
+    test.tempdir.path().join("pyproject.toml"),
+    indoc! {
+      r#"
+      [project]
+      name = "demo"
+      version = "1.0.0"
+      dependencies = ["requests>=1,<2"]
+
+      [tool.pyproject.rules]
+      project-name-availability = "info"
+      "#
+    },
+  )?;
+
+  let output = test
+    .command()
+    .env("PYPROJECT_CACHE_DIR", test.tempdir.path().join("cache"))
+    .env("PYPROJECT_PYPI_BASE_URL", server.url())
+    .output()?;
+
+  assert_eq!(output.status.code(), Some(0));
+  assert_eq!(str::from_utf8(&output.stdout)?, "");
+
+  mock.assert();
+
+  Ok(())
+}
+
+#[test]
+fn check_output_format_grep_prints_one_line_per_diagnostic() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "Foo!Bar"
+        version = "foo"
+
+        [tool.pyproject.rules]
+        project-name = "warning"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .argument("--output-format")
+    .argument("grep")
+    .expected_status(1)
+    .expected_stdout(indoc! {
+      "
+      pyproject.toml:2:8: [warning] [project-name] `project.name` must be a valid distribution name
+      pyproject.toml:3:11: [error] [project-version] expected version to start with a number, but no leading ASCII digits were found
+      "
+    })
+    .run()
+}
+
+#[test]
+fn check_output_format_json_reports_diagnostics() -> Result {
   Test::new()?
     .file(
       "pyproject.toml",
@@ -341,46 +559,100 @@ fn check_reports_errors_and_fails() -> Result {
       },
     )
     .argument("pyproject.toml")
+    .argument("--output-format")
+    .argument("json")
     .expected_status(1)
     .expected_stdout(indoc! {
       r#"
-      error[project-name]: invalid value for `project.name`
-         ╭─[ pyproject.toml:2:8 ]
-         │
-       2 │ name = "Foo!Bar"
-         │        ────┬────
-         │            ╰────── `project.name` must be a valid distribution name
-      ───╯
+      [
+        {
+          "message": "`project.name` must be a valid distribution name",
+          "path": "pyproject.toml",
+          "range": {
+            "start": {
+              "line": 1,
+              "character": 7
+            },
+            "end": {
+              "line": 1,
+              "character": 16
+            }
+          },
+          "rule": "project-name",
+          "severity": "error"
+        }
+      ]
       "#
     })
     .run()
 }
 
 #[test]
-fn check_reports_warnings_without_failing() -> Result {
+fn check_output_format_json_reports_empty_array() -> Result {
   Test::new()?
     .file(
       "pyproject.toml",
       indoc! {
         r#"
         [project]
-        name = "Foo!Bar"
+        name = "demo"
+        version = "1.0.0"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .argument("--output-format")
+    .argument("json")
+    .expected_stdout("[]\n")
+    .run()
+}
+
+#[test]
+fn check_overlay_attributes_diagnostics_to_source_files() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "demo"
         version = "1.0.0"
+        classifiers = ["Bogus :: Thing"]
+        "#
+      },
+    )
+    .file(
+      "ci.toml",
+      indoc! {
+        r#"
+        [project]
+        version = "not a version"
+        dependencies = ["requests>=2"]
 
-        [tool.pyproject.rules]
-        project-name = "warning"
+        [tool.mytool]
+        flag = true
         "#
       },
     )
     .argument("pyproject.toml")
+    .argument("--overlay")
+    .argument("ci.toml")
+    .expected_status(1)
     .expected_stdout(indoc! {
       r#"
-      warning[project-name]: invalid value for `project.name`
-         ╭─[ pyproject.toml:2:8 ]
+      error[project-version]: invalid `project.version` value
+         ╭─[ ci.toml:2:11 ]
          │
-       2 │ name = "Foo!Bar"
-         │        ────┬────
-         │            ╰────── `project.name` must be a valid distribution name
+       2 │ version = "not a version"
+         │           ───────┬───────
+         │                  ╰───────── expected version to start with a number, but no leading ASCII digits were found
+      ───╯
+      error[project-classifiers]: invalid `project.classifiers` configuration
+         ╭─[ pyproject.toml:4:16 ]
+         │
+       4 │ classifiers = ["Bogus :: Thing"]
+         │                ────────┬───────
+         │                        ╰───────── `project.classifiers` contains an unknown classifier `Bogus :: Thing`
       ───╯
       "#
     })
@@ -388,82 +660,748 @@ fn check_reports_warnings_without_failing() -> Result {
 }
 
 #[test]
-fn format_check_errors_for_unformatted_file() -> Result {
+fn check_overlay_merges_into_tables_without_headers() -> Result {
+  for urls in [
+    "urls = { Homepage = \"https://example.com\" }",
+    "urls.Homepage = \"https://example.com\"",
+  ] {
+    Test::new()?
+      .file(
+        "pyproject.toml",
+        &format!("[project]\nname = \"demo\"\nversion = \"1.0.0\"\n{urls}\n"),
+      )
+      .file(
+        "ci.toml",
+        indoc! {
+          r#"
+          [project.urls]
+          Docs = "not a url"
+          Homepage = "https://example.org"
+          "#
+        },
+      )
+      .argument("pyproject.toml")
+      .argument("--overlay")
+      .argument("ci.toml")
+      .argument("--output-format")
+      .argument("grep")
+      .expected_status(1)
+      .expected_stdout(
+        "ci.toml:2:8: [error] [project-urls] `project.urls` entry `Docs` must be a valid URL: relative URL without a base\n",
+      )
+      .run()?;
+  }
+
+  Ok(())
+}
+
+#[test]
+fn check_overlay_rejects_invalid_toml() -> Result {
   Test::new()?
-    .subcommand("format")
     .file(
       "pyproject.toml",
       indoc! {
         r#"
         [project]
-        name="foo"
-        version="1.0.0"
+        name = "demo"
+        version = "1.0.0"
         "#
       },
     )
-    .argument("--check")
+    .file("ci.toml", "[project\n")
+    .argument("pyproject.toml")
+    .argument("--overlay")
+    .argument("ci.toml")
     .expected_status(1)
-    .expected_stdout(concat!(
-      "--- [ROOT]/pyproject.toml\n",
-      "+++ [ROOT]/pyproject.toml (formatted)\n",
-      "@@ -1,3 +1,3 @@\n",
-      " [project]\n",
-      "-name=\"foo\"\n",
-      "-version=\"1.0.0\"\n",
-      "+name = \"foo\"\n",
-      "+version = \"1.0.0\"\n",
-    ))
+    .expected_stderr(
+      "error: failed to parse overlay `ci.toml`: expected \"]\"\n",
+    )
     .run()
 }
 
 #[test]
-fn format_prints_formatted_file() -> Result {
+fn check_profile_prints_rule_timings() -> Result {
+  let test = Test::new()?;
+
+  fs::write(
+    test.tempdir.path().join("pyproject.toml"),
+    indoc! {
+      r#"
+      [project]
+      name = "Foo!Bar"
+      version = "1.0.0"
+      "#
+    },
+  )?;
+
+  let plain = test.command().arg("pyproject.toml").output()?;
+
+  let profiled = test
+    .command()
+    .args(["--profile", "pyproject.toml"])
+    .output()?;
+
+  assert_eq!(profiled.status.code(), plain.status.code());
+
+  assert_eq!(profiled.stdout, plain.stdout);
+
+  let stderr = str::from_utf8(&profiled.stderr)?;
+
+  let ids = stderr
+    .lines()
+    .map(|line| {
+      let (elapsed, id) = line.trim_start().split_once("  ").unwrap();
+
+      assert!(
+        elapsed.ends_with('s')
+          && elapsed.starts_with(|c: char| c.is_ascii_digit()),
+        "{line}"
+      );
+
+      id
+    })
+    .collect::<Vec<_>>();
+
+  assert!(ids.contains(&"project-name"), "{stderr}");
+
+  assert!(!ids.contains(&"project-dependencies-count"), "{stderr}");
+
+  Ok(())
+}
+
+#[test]
+fn check_quiet_only_reports_errors() -> Result {
   Test::new()?
-    .subcommand("format")
     .file(
       "pyproject.toml",
       indoc! {
         r#"
         [project]
-        name="foo"
-        version="1.0.0"
+        name = "Foo!Bar"
+        version = "foo"
+
+        [tool.pyproject.rules]
+        project-name = "warning"
         "#
       },
     )
+    .argument("--quiet")
+    .argument("pyproject.toml")
+    .expected_status(1)
     .expected_stdout(indoc! {
       r#"
-      [project]
-      name = "foo"
-      version = "1.0.0"
+      error[project-version]: invalid `project.version` value
+         ╭─[ pyproject.toml:3:11 ]
+         │
+       3 │ version = "foo"
+         │           ──┬──
+         │             ╰──── expected version to start with a number, but no leading ASCII digits were found
+      ───╯
       "#
     })
     .run()
 }
 
 #[test]
-fn format_write_formats_file() -> Result {
+fn check_quiet_suppresses_warnings() -> Result {
   Test::new()?
-    .subcommand("format")
     .file(
       "pyproject.toml",
       indoc! {
         r#"
         [project]
-        name="foo"
-        version="1.0.0"
+        name = "Foo!Bar"
+        version = "1.0.0"
+
+        [tool.pyproject.rules]
+        project-name = "warning"
         "#
       },
     )
-    .argument("--write")
-    .expected_file(
+    .argument("-q")
+    .argument("pyproject.toml")
+    .run()
+}
+
+#[test]
+fn check_reports_errors_and_fails() -> Result {
+  Test::new()?
+    .file(
       "pyproject.toml",
       indoc! {
         r#"
         [project]
-        name = "foo"
+        name = "Foo!Bar"
         version = "1.0.0"
         "#
       },
     )
+    .argument("pyproject.toml")
+    .expected_status(1)
+    .expected_stdout(indoc! {
+      r#"
+      error[project-name]: invalid value for `project.name`
+         ╭─[ pyproject.toml:2:8 ]
+         │
+       2 │ name = "Foo!Bar"
+         │        ────┬────
+         │            ╰────── `project.name` must be a valid distribution name
+      ───╯
+      "#
+    })
+    .run()
+}
+
+#[test]
+fn check_reports_warnings_without_failing() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "Foo!Bar"
+        version = "1.0.0"
+
+        [tool.pyproject.rules]
+        project-name = "warning"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .expected_stdout(indoc! {
+      r#"
+      warning[project-name]: invalid value for `project.name`
+         ╭─[ pyproject.toml:2:8 ]
+         │
+       2 │ name = "Foo!Bar"
+         │        ────┬────
+         │            ╰────── `project.name` must be a valid distribution name
+      ───╯
+      "#
+    })
+    .run()
+}
+
+#[test]
+fn check_select_and_ignore_filter_rules() -> Result {
+  #[track_caller]
+  fn case(arguments: &[&str], status: i32, stdout: &str) -> Result {
+    let mut test = Test::new()?
+      .file(
+        "pyproject.toml",
+        indoc! {
+          r#"
+          [project]
+          name = "Foo!Bar"
+          version = "foo"
+          "#
+        },
+      )
+      .argument("pyproject.toml")
+      .argument("--output-format")
+      .argument("grep");
+
+    for argument in arguments {
+      test = test.argument(argument);
+    }
+
+    test.expected_status(status).expected_stdout(stdout).run()
+  }
+
+  let name = "pyproject.toml:2:8: [error] [project-name] `project.name` must be a valid distribution name\n";
+
+  let version = "pyproject.toml:3:11: [error] [project-version] expected version to start with a number, but no leading ASCII digits were found\n";
+
+  case(&["--select", "project-name"], 1, name)?;
+  case(&["--ignore", "project-name"], 1, version)?;
+  case(
+    &["--select", "project-name,project-version"],
+    1,
+    &format!("{name}{version}"),
+  )?;
+  case(
+    &["--select", "project-name", "--ignore", "project-name"],
+    0,
+    "",
+  )?;
+
+  Ok(())
+}
+
+#[test]
+fn check_select_rejects_unknown_rules() -> Result {
+  let output = Test::new()?
+    .argument("--select")
+    .argument("project-nam")
+    .command()
+    .output()?;
+
+  assert_eq!(output.status.code(), Some(1));
+
+  let stderr = str::from_utf8(&output.stderr)?;
+
+  assert!(
+    stderr.starts_with("error: unknown rule `project-nam`, valid rules are: "),
+    "{stderr}"
+  );
+
+  assert!(stderr.contains("project-name, "), "{stderr}");
+
+  Ok(())
+}
+
+#[test]
+fn check_verbose_reports_information() -> Result {
+  let test = Test::new()?;
+
+  fs::write(
+    test.tempdir.path().join("pyproject.toml"),
+    indoc! {
+      r#"
+      [project]
+      name = "Foo!Bar"
+      version = "1.0.0"
+
+      [tool.pyproject.rules]
+      project-name = "info"
+      "#
+    },
+  )?;
+
+  let test = test.argument("-v").argument("pyproject.toml");
+
+  let output = test.command().output()?;
+
+  assert_eq!(output.status.code(), Some(0));
+
+  assert_eq!(
+    test.normalize(str::from_utf8(&output.stdout)?)?,
+    indoc! {
+      r#"
+      info[project-name]: invalid value for `project.name`
+         ╭─[ pyproject.toml:2:8 ]
+         │
+       2 │ name = "Foo!Bar"
+         │        ────┬────
+         │            ╰────── `project.name` must be a valid distribution name
+      ───╯
+      "#
+    }
+  );
+
+  let stderr = str::from_utf8(&output.stderr)?;
+
+  assert!(stderr.starts_with("checked pyproject.toml in "), "{stderr}");
+
+  Ok(())
+}
+
+#[test]
+fn check_warnings_as_errors_fails_on_warnings() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "Foo!Bar"
+        version = "1.0.0"
+
+        [tool.pyproject.rules]
+        project-name = "warning"
+        "#
+      },
+    )
+    .argument("--warnings-as-errors")
+    .argument("pyproject.toml")
+    .expected_status(1)
+    .expected_stdout(indoc! {
+      r#"
+      warning[project-name]: invalid value for `project.name`
+         ╭─[ pyproject.toml:2:8 ]
+         │
+       2 │ name = "Foo!Bar"
+         │        ────┬────
+         │            ╰────── `project.name` must be a valid distribution name
+      ───╯
+      "#
+    })
+    .expected_stderr("error: 1 warning(s) reported, exceeding the limit of 0\n")
+    .run()
+}
+
+#[test]
+fn check_uses_standalone_configuration() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "Foo!Bar"
+        version = "foo"
+
+        [tool.pyproject.rules]
+        project-version = "warning"
+        "#
+      },
+    )
+    .file(
+      "pyproject-lint.toml",
+      indoc! {
+        r#"
+        [rules]
+        project-name = "off"
+        project-version = "off"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .expected_stdout(indoc! {
+      r#"
+      warning[project-version]: invalid `project.version` value
+         ╭─[ pyproject.toml:3:11 ]
+         │
+       3 │ version = "foo"
+         │           ──┬──
+         │             ╰──── expected version to start with a number, but no leading ASCII digits were found
+      ───╯
+      "#
+    })
+    .run()
+}
+
+#[test]
+fn check_write_baseline_records_diagnostics() -> Result {
+  Test::new()?
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "Foo!Bar"
+        version = "1.0.0"
+        "#
+      },
+    )
+    .argument("--write-baseline")
+    .argument("baseline.json")
+    .argument("pyproject.toml")
+    .expected_file(
+      "baseline.json",
+      indoc! {
+        r#"
+        {
+          "diagnostics": [
+            {
+              "context": "\"Foo!Bar\"",
+              "id": "project-name",
+              "message": "`project.name` must be a valid distribution name",
+              "path": "pyproject.toml"
+            }
+          ]
+        }
+        "#
+      },
+    )
+    .run()
+}
+
+#[test]
+fn fix_dry_run_prints_diff() -> Result {
+  Test::new()?
+    .subcommand("fix")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "demo"
+        version = "1.0.0"
+        license = "mit"
+        "#
+      },
+    )
+    .argument("--dry-run")
+    .expected_stdout(concat!(
+      "--- [ROOT]/pyproject.toml\n",
+      "+++ [ROOT]/pyproject.toml (fixed)\n",
+      "@@ -1,4 +1,4 @@\n",
+      " [project]\n",
+      " name = \"demo\"\n",
+      " version = \"1.0.0\"\n",
+      "-license = \"mit\"\n",
+      "+license = \"MIT\"\n",
+    ))
+    .expected_file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "demo"
+        version = "1.0.0"
+        license = "mit"
+        "#
+      },
+    )
+    .run()
+}
+
+#[test]
+fn fix_preserves_byte_order_mark() -> Result {
+  Test::new()?
+    .subcommand("fix")
+    .file(
+      "pyproject.toml",
+      "\u{feff}[project]\nname = \"demo\"\nversion = \"1.0.0\"\nlicense = \"mit\"\n",
+    )
+    .argument("pyproject.toml")
+    .expected_file(
+      "pyproject.toml",
+      "\u{feff}[project]\nname = \"demo\"\nversion = \"1.0.0\"\nlicense = \"MIT\"\n",
+    )
+    .run()?;
+
+  Test::new()?
+    .subcommand("fix")
+    .file(
+      "pyproject.toml",
+      "\u{feff}[project]\nname = \"demo\"\nversion = \"1.0.0\"\n",
+    )
+    .argument("--dry-run")
+    .expected_file(
+      "pyproject.toml",
+      "\u{feff}[project]\nname = \"demo\"\nversion = \"1.0.0\"\n",
+    )
+    .run()
+}
+
+#[test]
+fn fix_writes_fixes_idempotently() -> Result {
+  let fixed = indoc! {
+    r#"
+    [project]
+    name = "my-package"
+    version = "1.0.0"
+    license = "MIT"
+
+    [tool.pyproject.rules]
+    project-name-normalization = "warning"
+    "#
+  };
+
+  Test::new()?
+    .subcommand("fix")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "My_Package"
+        version = "1.0.0"
+        license = "mit"
+
+        [tool.pyproject.rules]
+        project-name-normalization = "warning"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .expected_file("pyproject.toml", fixed)
+    .run()?;
+
+  Test::new()?
+    .subcommand("fix")
+    .file("pyproject.toml", fixed)
+    .argument("--dry-run")
+    .expected_file("pyproject.toml", fixed)
+    .run()
+}
+
+#[test]
+fn format_check_errors_for_unformatted_file() -> Result {
+  Test::new()?
+    .subcommand("format")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name="foo"
+        version="1.0.0"
+        "#
+      },
+    )
+    .argument("--check")
+    .expected_status(1)
+    .expected_stdout(concat!(
+      "--- [ROOT]/pyproject.toml\n",
+      "+++ [ROOT]/pyproject.toml (formatted)\n",
+      "@@ -1,3 +1,3 @@\n",
+      " [project]\n",
+      "-name=\"foo\"\n",
+      "-version=\"1.0.0\"\n",
+      "+name = \"foo\"\n",
+      "+version = \"1.0.0\"\n",
+    ))
+    .run()
+}
+
+#[test]
+fn format_check_colors_diff_when_forced() -> Result {
+  Test::new()?
+    .subcommand("format")
+    .file("pyproject.toml", "[project]\nname=\"foo\"\n")
+    .argument("--check")
+    .argument("--color")
+    .argument("always")
+    .expected_status(1)
+    .expected_stdout(concat!(
+      "\u{1b}[31m--- [ROOT]/pyproject.toml\u{1b}[39m\n",
+      "\u{1b}[32m+++ [ROOT]/pyproject.toml (formatted)\u{1b}[39m\n",
+      "\u{1b}[34m@@ -1,2 +1,2 @@\u{1b}[39m\n",
+      "\u{1b}[2m [project]\u{1b}[0m\n",
+      "\u{1b}[31m-name=\"foo\"\u{1b}[39m\n",
+      "\u{1b}[32m+name = \"foo\"\u{1b}[39m\n",
+    ))
+    .run()
+}
+
+#[test]
+fn format_prints_formatted_file() -> Result {
+  Test::new()?
+    .subcommand("format")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name="foo"
+        version="1.0.0"
+        "#
+      },
+    )
+    .expected_stdout(indoc! {
+      r#"
+      [project]
+      name = "foo"
+      version = "1.0.0"
+      "#
+    })
+    .run()
+}
+
+#[test]
+fn format_write_formats_file() -> Result {
+  Test::new()?
+    .subcommand("format")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name="foo"
+        version="1.0.0"
+        "#
+      },
+    )
+    .argument("--write")
+    .expected_file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        "#
+      },
+    )
+    .run()
+}
+
+#[test]
+fn migrate_errors_for_existing_project() -> Result {
+  Test::new()?
+    .subcommand("migrate")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "foo"
+
+        [tool.poetry]
+        name = "foo"
+        "#
+      },
+    )
+    .argument("pyproject.toml")
+    .expected_status(1)
+    .expected_stderr(
+      "error: failed to migrate `pyproject.toml`: document already has a `[project]` table\n",
+    )
+    .run()
+}
+
+#[test]
+fn migrate_prints_project_table() -> Result {
+  Test::new()?
+    .subcommand("migrate")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [tool.poetry]
+        name = "foo"
+        version = "1.0.0"
+
+        [tool.poetry.dependencies]
+        python = "^3.10"
+        requests = "^2.31"
+        "#
+      },
+    )
+    .expected_stdout(indoc! {
+      r#"
+      [project]
+      name = "foo"
+      version = "1.0.0"
+      requires-python = ">=3.10,<4"
+      dependencies = [
+        "requests>=2.31,<3",
+      ]
+      "#
+    })
+    .run()
+}
+
+#[test]
+fn migrate_write_updates_file() -> Result {
+  Test::new()?
+    .subcommand("migrate")
+    .file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [tool.poetry]
+        name = "foo"
+        packages = [{ include = "foo" }]
+        "#
+      },
+    )
+    .argument("--write")
+    .expected_file(
+      "pyproject.toml",
+      indoc! {
+        r#"
+        [project]
+        name = "foo"
+
+        [tool.poetry]
+        packages = [{ include = "foo" }]
+        "#
+      },
+    )
     .run()
 }